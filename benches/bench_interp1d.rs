@@ -36,6 +36,12 @@ fn bench_interp1d_scalar(c: &mut Criterion) {
         })
     });
 
+    c.bench_function("1D scalar `interp_array_unchecked` 1D-long", |b| {
+        b.iter(|| {
+            interp.interp_array_unchecked(&query).unwrap();
+        })
+    });
+
     let mut buffer = Array::zeros(1).remove_axis(Axis(0));
     c.bench_function("1D scalar `interp_into`", |b| {
         b.iter(|| {
@@ -130,10 +130,48 @@ fn bench_interp2d_array(c: &mut Criterion) {
     });
 }
 
+fn bench_interp2d_scanline(c: &mut Criterion) {
+    let data = Array::from_rand(10_000, (0.0, 1.0), 42)
+        .into_shape((100, 100))
+        .unwrap();
+    let interp = Interp2D::builder(data).build().unwrap();
+    let query_x = Array::from_rand(100, (0.0, 99.0), 123);
+    let query_y = Array::from_rand(100, (0.0, 99.0), 96);
+
+    let mut buffer = Array::zeros(100);
+    c.bench_function("2D scanline per point `interp_into`", |b| {
+        b.iter(|| {
+            query_x.iter().for_each(|&x| {
+                query_y
+                    .iter()
+                    .zip(buffer.axis_iter_mut(Axis(0)))
+                    .for_each(|(&y, z)| {
+                        interp.interp_into(x, y, z).unwrap();
+                    });
+            });
+        })
+    });
+
+    c.bench_function("2D scanline `scanline_x`", |b| {
+        b.iter(|| {
+            query_x.iter().for_each(|&x| {
+                let scanline = interp.scanline_x(x).unwrap();
+                query_y
+                    .iter()
+                    .zip(buffer.axis_iter_mut(Axis(0)))
+                    .for_each(|(&y, z)| {
+                        scanline.interp_into(y, z).unwrap();
+                    });
+            });
+        })
+    });
+}
+
 criterion_group!(
     benches,
     bench_interp2d_scalar,
     bench_interp2d_array,
-    bench_interp2d_scalar_multithread
+    bench_interp2d_scalar_multithread,
+    bench_interp2d_scanline
 );
 criterion_main!(benches);
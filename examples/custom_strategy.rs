@@ -1,7 +1,7 @@
 use approx::assert_abs_diff_eq;
 use ndarray::{array, Array, ArrayViewMut, Data, Dimension, RemoveAxis};
 use ndarray_interp::{
-    interp1d::{Interp1D, Interp1DStrategy, Interp1DStrategyBuilder},
+    interp1d::{ExtrapolateMode, Interp1D, Interp1DStrategy, Interp1DStrategyBuilder},
     InterpolateError,
 };
 
@@ -23,7 +23,7 @@ where
         _data: &ndarray::ArrayBase<Sd, D>,
     ) -> Result<Self::FinishedStrat, ndarray_interp::BuilderError>
     where
-        Sx2: ndarray::Data<Elem = Sd::Elem>,
+        Sx2: ndarray::Data<Elem = Sx::Elem>,
     {
         Ok(self)
     }
@@ -35,6 +35,12 @@ where
     Sx: Data<Elem = Sd::Elem>,
     D: Dimension + RemoveAxis,
 {
+    const NAME: &'static str = "StepInterpolator";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        ExtrapolateMode::Yes
+    }
+
     fn interp_into(
         &self,
         interpolator: &Interp1D<Sd, Sx, D, Self>,
@@ -62,6 +68,7 @@ fn main() {
         .build()
         .unwrap();
 
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::Yes);
     let result = interp.interp_array(&query).unwrap();
     let expect = array![2.0, 2.0, 4.0, 4.0, 5.0, 5.0];
     assert_abs_diff_eq!(result, expect, epsilon = f64::EPSILON);
@@ -0,0 +1,215 @@
+use std::{cmp::Ordering, fmt::Debug};
+
+use ndarray::{Array, Array1, Axis, Data, Dimension, RemoveAxis, Zip};
+use num_traits::{cast, Num, NumCast, Zero};
+
+use crate::{BuilderError, InterpolateError};
+
+use super::{Interp1D, Interp1DStrategy};
+
+/// the maximum depth of the subdivision, limits the samples around discontinuities
+const MAX_DEPTH: u32 = 32;
+
+/// deviations relative to the largest absolute data value which are accepted as rounding
+const ROUNDING: f64 = 16.0 * f64::EPSILON;
+
+impl<Sd, Sx, D, Strat> Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    /// Sample the interpolant with as few points as possible, so the linear interpolation
+    /// of the samples deviates from the interpolant by at most `tol`, e.g. to export
+    /// a curve to a vector format.
+    ///
+    /// **returns** `(x, data)` of the samples, `data` has the shape of the data with
+    /// one entry for each sample along the first axis.
+    ///
+    /// Starting with the first and the last knot, the samples are subdivided at the point
+    /// with the largest deviation from the straight line between them until all series
+    /// are within `tol`. The deviation is checked at the knots between two samples and at
+    /// a quarter, half and three quarters between them. For linear interpolation this
+    /// bounds the deviation everywhere, other strategies can deviate more between the
+    /// checked points. Flat regions need few samples, curved regions many.
+    ///
+    /// The first and the last knot and the knots next to [masked gaps](super::Interp1DBuilder::mask)
+    /// are sampled with their data, gaps are not sampled.
+    /// The subdivision is at most 32 levels deep, which limits the samples around a
+    /// discontinuity to a few per level.
+    ///
+    /// The total number of samples is not bounded, it grows with the curvature of the
+    /// interpolant and a smaller `tol`. Deviations below `16 * f64::EPSILON` times the
+    /// largest absolute data value are accepted as rounding, so a `tol` below the
+    /// rounding of the data does not subdivide the straight segments without end.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 2.0, 3.0, 1.5, 0.0, 0.0, 0.0])
+    ///     .build()
+    ///     .unwrap();
+    /// let (x, data) = interp.adaptive_samples(1e-12).unwrap();
+    /// assert_eq!(x, array![0.0, 3.0, 5.0, 7.0]);
+    /// assert_eq!(data, array![0.0, 3.0, 0.0, 0.0]);
+    /// ```
+    ///
+    /// # Errors
+    ///  - When `tol` is not positive
+    ///  - When the strategy can not interpolate between the knots
+    #[allow(clippy::type_complexity)]
+    pub fn adaptive_samples(
+        &self,
+        tol: Sd::Elem,
+    ) -> Result<(Array1<Sx::Elem>, Array<Sd::Elem, D>), InterpolateError> {
+        let two: Sx::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let zero = Sd::Elem::zero();
+        if tol.partial_cmp(&zero) != Some(Ordering::Greater) {
+            return Err(
+                BuilderError::ValueError(format!("the tolerance {tol:?} is not positive")).into(),
+            );
+        }
+        let largest = self.data.fold(zero, |max, &y| {
+            let abs = if y < zero { zero - y } else { y };
+            if abs > max {
+                abs
+            } else {
+                max
+            }
+        });
+        let rounding = largest * cast(ROUNDING).unwrap_or_else(Sd::Elem::zero);
+        let tol = if rounding > tol { rounding } else { tol };
+        let last = self.x.len() - 1;
+        let knot = |idx: usize| (self.x[idx], self.data.index_axis(Axis(0), idx).to_owned());
+
+        let mut samples = vec![knot(0)];
+        let mut start = 0;
+        for idx in 0..last {
+            let (left, right) = (self.x[idx], self.x[idx + 1]);
+            if self.check_gaps((left + right) / two).is_ok() {
+                continue;
+            }
+            // the run of knots ends at the gap and a new run starts after it
+            if idx > start {
+                self.subdivide(knot(start), knot(idx), start + 1..idx, tol, 0, &mut samples)?;
+                samples.push(knot(idx));
+            }
+            samples.push(knot(idx + 1));
+            start = idx + 1;
+        }
+        if last > start {
+            self.subdivide(
+                knot(start),
+                knot(last),
+                start + 1..last,
+                tol,
+                0,
+                &mut samples,
+            )?;
+            samples.push(knot(last));
+        }
+
+        let mut dim = self.data.raw_dim();
+        dim[0] = samples.len();
+        let mut data = Array::zeros(dim);
+        for ((_, sample), mut row) in samples.iter().zip(data.axis_iter_mut(Axis(0))) {
+            row.assign(sample);
+        }
+        let x = samples.into_iter().map(|(x, _)| x).collect();
+        Ok((x, data))
+    }
+
+    /// push the samples between `left` and `right`, excluding both,
+    /// `knots` are the indices of the knots between them
+    #[allow(clippy::type_complexity)]
+    fn subdivide(
+        &self,
+        left: (Sx::Elem, Array<Sd::Elem, D::Smaller>),
+        right: (Sx::Elem, Array<Sd::Elem, D::Smaller>),
+        knots: std::ops::Range<usize>,
+        tol: Sd::Elem,
+        depth: u32,
+        samples: &mut Vec<(Sx::Elem, Array<Sd::Elem, D::Smaller>)>,
+    ) -> Result<(), InterpolateError> {
+        let quarter: Sx::Elem = cast(0.25).unwrap_or_else(|| unimplemented!());
+        let zero = Sd::Elem::zero();
+        let abs = |value: Sd::Elem| if value < zero { zero - value } else { value };
+
+        let bounds: Vec<Sx::Elem> = std::iter::once(left.0)
+            .chain(knots.clone().map(|idx| self.x[idx]))
+            .chain([right.0])
+            .collect();
+        let probes = bounds[1..bounds.len() - 1]
+            .iter()
+            .copied()
+            .chain(bounds.windows(2).flat_map(|pair| {
+                let step = (pair[1] - pair[0]) * quarter;
+                [pair[0] + step, pair[0] + step + step, pair[1] - step]
+            }));
+
+        // the probe with the largest deviation from the straight line
+        let mut worst: Option<(Sd::Elem, Sx::Elem, Array<Sd::Elem, D::Smaller>)> = None;
+        for x in probes {
+            let value = self.interp(x)?;
+            let weight: Sd::Elem =
+                cast((x - left.0) / (right.0 - left.0)).unwrap_or_else(|| unimplemented!());
+            let deviation = Zip::from(&value).and(&left.1).and(&right.1).fold(
+                zero,
+                |max, &value, &y_left, &y_right| {
+                    let deviation = abs(value - (y_left + (y_right - y_left) * weight));
+                    if deviation > max {
+                        deviation
+                    } else {
+                        max
+                    }
+                },
+            );
+            let larger = match &worst {
+                Some((max, ..)) => deviation > *max,
+                None => true,
+            };
+            if larger {
+                worst = Some((deviation, x, value));
+            }
+        }
+        let (deviation, x, value) = match worst {
+            Some(worst) => worst,
+            None => return Ok(()),
+        };
+        if deviation <= tol || depth >= MAX_DEPTH {
+            return Ok(());
+        }
+
+        // the knots left of the split and right of it, a knot at the split is the sample
+        let split = knots
+            .clone()
+            .find(|&idx| self.x[idx] >= x)
+            .unwrap_or(knots.end);
+        let right_start = if split < knots.end && self.x[split] == x {
+            split + 1
+        } else {
+            split
+        };
+        let sample = (x, value);
+        self.subdivide(
+            left,
+            sample.clone(),
+            knots.start..split,
+            tol,
+            depth + 1,
+            samples,
+        )?;
+        samples.push(sample.clone());
+        self.subdivide(
+            sample,
+            right,
+            right_start..knots.end,
+            tol,
+            depth + 1,
+            samples,
+        )
+    }
+}
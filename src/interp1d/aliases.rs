@@ -1,4 +1,4 @@
-use ndarray::{Ix1, Ix2, OwnedRepr, ViewRepr};
+use ndarray::{CowRepr, Ix1, Ix2, OwnedRepr, ViewRepr};
 
 use super::Interp1D;
 
@@ -8,6 +8,9 @@ pub type Interp1DOwned<A, D, S> = Interp1D<OwnedRepr<A>, OwnedRepr<A>, D, S>;
 pub type Interp1DView<A, D, S> = Interp1D<ViewRepr<A>, ViewRepr<A>, D, S>;
 /// one-dimensional interpolant for data views and owned axis
 pub type Interp1DDataView<A, D, S> = Interp1D<ViewRepr<A>, OwnedRepr<A>, D, S>;
+/// one-dimensional interpolant for data and axis which are either borrowed or owned,
+/// see [`CowArray`](ndarray::CowArray)
+pub type Interp1DCow<'a, A, D, S> = Interp1D<CowRepr<'a, A>, CowRepr<'a, A>, D, S>;
 /// one-dimensional interpolant for scalar, owned data
 pub type Interp1DScalar<A, S> = Interp1DOwned<A, Ix1, S>;
 /// one-dimensional interpolant for vectroized, owned data
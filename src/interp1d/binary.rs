@@ -0,0 +1,206 @@
+use std::io::{self, Read, Write};
+
+use ndarray::{Array, Array1, Data, Dimension, OwnedRepr, RemoveAxis};
+
+use super::{Interp1D, Interp1DBuilder, Interp1DStrategy, Interp1DStrategyBuilder};
+
+const MAGIC: &[u8; 4] = b"NDIP";
+const VERSION: u16 = 1;
+/// the largest number of data dimensions which is read, for dynamic dimensions
+const MAX_NDIM: usize = 32;
+
+impl<Sd, Sx, D, Strat> Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data<Elem = f64>,
+    Sx: Data<Elem = f64>,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    /// Write the interpolator in a compact binary format, which is read by
+    /// [`read_binary`](Interp1D::read_binary).
+    ///
+    /// The x axis, the raw data, the [masked gaps](super::Interp1DBuilder::mask),
+    /// [`closed_upper`](super::Interp1DBuilder::closed_upper) and the
+    /// [output transform](super::Interp1DBuilder::output_transform) are stored together
+    /// with the [name](Interp1DStrategy::NAME) of the strategy. The configuration of the strategy
+    /// (e.g. extrapolation or boundary conditions) is not stored, it is given to
+    /// [`read_binary`](Interp1D::read_binary) and the strategy is built again.
+    ///
+    /// # Layout
+    /// All numbers are little endian, the data is stored in row major order
+    /// ```text
+    /// bytes        content
+    /// 4            magic "NDIP"
+    /// 2            version, u16 = 1
+    /// 2            length of the strategy name, u16
+    /// name length  strategy name, UTF-8
+    /// 1            closed upper bound, u8 0 or 1
+    /// 1            output transform, u8 0 or 1
+    /// 16 if set    scale and offset of the output transform, f64
+    /// 4            number of data dimensions, u32
+    /// 8 each       length of each data dimension, u64
+    /// 8            number of gaps, u64
+    /// 16 each      left and right end of each gap, f64
+    /// 8 each       x values, f64
+    /// 8 each       data values, f64
+    /// ```
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 3.0]).build().unwrap();
+    /// let mut bytes = Vec::new();
+    /// interp.write_binary(&mut bytes).unwrap();
+    /// assert_eq!(bytes.len(), 4 + 2 + 2 + 6 + 1 + 4 + 8 + 8 + 3 * 8 + 3 * 8 + 1);
+    ///
+    /// let read = Interp1D::read_binary(bytes.as_slice(), Linear::new()).unwrap();
+    /// assert_eq!(read.interp_scalar(1.5).unwrap(), 2.0);
+    /// ```
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let name = Strat::NAME.as_bytes();
+        let name_len = u16::try_from(name.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "strategy name too long"))?;
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&name_len.to_le_bytes())?;
+        writer.write_all(name)?;
+        writer.write_all(&[u8::from(self.closed_upper)])?;
+        writer.write_all(&[u8::from(self.output_transform.is_some())])?;
+        if let Some(transform) = self.output_transform {
+            writer.write_all(&transform.scale.to_le_bytes())?;
+            writer.write_all(&transform.offset.to_le_bytes())?;
+        }
+
+        writer.write_all(&(self.data.ndim() as u32).to_le_bytes())?;
+        for &len in self.data.shape() {
+            writer.write_all(&(len as u64).to_le_bytes())?;
+        }
+        writer.write_all(&(self.gaps.len() as u64).to_le_bytes())?;
+        for &(left, right) in &self.gaps {
+            writer.write_all(&left.to_le_bytes())?;
+            writer.write_all(&right.to_le_bytes())?;
+        }
+        for value in self.x.iter().chain(self.data.iter()) {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl<D, Strat> Interp1D<OwnedRepr<f64>, OwnedRepr<f64>, D, Strat>
+where
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<OwnedRepr<f64>, OwnedRepr<f64>, D>,
+{
+    /// Read an interpolator written by [`write_binary`](Interp1D::write_binary)
+    /// and build it with `strategy`.
+    ///
+    /// # Errors
+    /// An error of the kind [`InvalidData`](io::ErrorKind::InvalidData) is returned when
+    ///  - the magic header or the version does not match
+    ///  - the data was written by another strategy
+    ///  - the number of data dimensions does not match `D`, or is larger than 32
+    ///  - the data is rejected by the builder, the [`BuilderError`](crate::BuilderError)
+    ///    is the inner error
+    ///
+    /// Errors of the reader are returned as they are.
+    pub fn read_binary<R, Sb>(mut reader: R, strategy: Sb) -> io::Result<Self>
+    where
+        R: Read,
+        Sb: Interp1DStrategyBuilder<OwnedRepr<f64>, OwnedRepr<f64>, D, FinishedStrat = Strat>,
+    {
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid(format!("unknown magic header {magic:?}")));
+        }
+        let version = u16::from_le_bytes(read_bytes(&mut reader)?);
+        if version != VERSION {
+            return Err(invalid(format!(
+                "unsupported version {version}, expected {VERSION}"
+            )));
+        }
+        let mut name = vec![0; u16::from_le_bytes(read_bytes(&mut reader)?) as usize];
+        reader.read_exact(&mut name)?;
+        if name != Sb::NAME.as_bytes() {
+            return Err(invalid(format!(
+                "the data was written by the strategy {:?}, not {:?}",
+                String::from_utf8_lossy(&name),
+                Sb::NAME
+            )));
+        }
+        let [closed_upper] = read_bytes(&mut reader)?;
+        let [has_transform] = read_bytes(&mut reader)?;
+        let output_transform = match has_transform {
+            0 => None,
+            _ => Some((
+                f64::from_le_bytes(read_bytes(&mut reader)?),
+                f64::from_le_bytes(read_bytes(&mut reader)?),
+            )),
+        };
+
+        let ndim = u32::from_le_bytes(read_bytes(&mut reader)?) as usize;
+        if ndim == 0 || ndim > MAX_NDIM || D::NDIM.is_some_and(|expected| expected != ndim) {
+            return Err(invalid(format!(
+                "the data has {ndim} dimensions, expected {:?}",
+                D::NDIM
+            )));
+        }
+        let mut dim = D::zeros(ndim);
+        for len in dim.slice_mut() {
+            *len = read_len(&mut reader)?;
+        }
+        let num_gaps = read_len(&mut reader)?;
+        let gaps = read_f64s(&mut reader, num_gaps.saturating_mul(2))?
+            .chunks(2)
+            .map(|gap| (gap[0], gap[1]))
+            .collect();
+        let x = Array1::from(read_f64s(&mut reader, dim[0])?);
+        let size = dim
+            .size_checked()
+            .ok_or_else(|| invalid(format!("the data shape {:?} is too large", dim.slice())))?;
+        let data = read_f64s(&mut reader, size)?;
+        let data = Array::from_shape_vec(dim, data).map_err(|err| invalid(err.to_string()))?;
+
+        let mut builder = Interp1DBuilder::new_with_x(data, x)
+            .strategy(strategy)
+            .closed_upper(closed_upper != 0);
+        // the stored gaps are already filtered, keep all of them
+        builder.gaps = gaps;
+        builder.max_gap = Some(0.0);
+        if let Some((scale, offset)) = output_transform {
+            builder = builder.output_transform(scale, offset);
+        }
+        builder
+            .build()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+fn read_bytes<R: Read, const N: usize>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// read `len` values, the memory grows with the values which are actually read,
+/// so a corrupt length can not allocate more than twice the input
+fn read_f64s<R: Read>(reader: &mut R, len: usize) -> io::Result<Vec<f64>> {
+    let mut values = Vec::new();
+    for _ in 0..len {
+        values.push(read_bytes(reader).map(f64::from_le_bytes)?);
+    }
+    Ok(values)
+}
+
+fn read_len<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let len = u64::from_le_bytes(read_bytes(reader)?);
+    usize::try_from(len).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("length {len} does not fit into usize"),
+        )
+    })
+}
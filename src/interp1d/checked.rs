@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+
+use ndarray::{Data, Dimension, RemoveAxis};
+use num_traits::{cast, Num, NumCast, Zero};
+
+use crate::BuilderError;
+
+use super::{Interp1D, Interp1DBuilder, Interp1DStrategyBuilder};
+
+/// A non fatal issue of the input data found by [`Interp1DBuilder::build_checked`]
+///
+/// The interpolator is still built, but the results may be less accurate than expected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning<X> {
+    /// Two neighbouring knots differ by less than `1e-9` times their magnitude,
+    /// so their difference has lost most of its significant digits
+    NearDuplicateX {
+        /// the index of the left knot
+        index: usize,
+        left: X,
+        right: X,
+    },
+    /// A segment is narrower than `1e-6` times the x range,
+    /// the division by its width amplifies noise in the data
+    NarrowSegment {
+        /// the index of the left knot
+        index: usize,
+        width: X,
+    },
+    /// The widest segment is more than `1e3` times wider than the narrowest segment.
+    /// Splines tend to oscillate in the wide segments.
+    SpacingRatio {
+        /// the width of the widest segment divided by the width of the narrowest segment
+        ratio: f64,
+        /// the index of the left knot of the narrowest segment
+        narrowest: usize,
+        /// the index of the left knot of the widest segment
+        widest: usize,
+    },
+    /// The largest magnitude of the data is more than `1e12` times the smallest
+    /// non zero magnitude, small values lose their precision when they are combined
+    /// with large values
+    DataMagnitudes { smallest: f64, largest: f64 },
+}
+
+impl<Sd, Sx, D, Strat> Interp1DBuilder<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategyBuilder<Sd, Sx, D>,
+{
+    /// Same as [`build`](Interp1DBuilder::build), but also collects non fatal issues
+    /// of the input data as [`Warning`]s, e.g. for data quality checks.
+    ///
+    /// Errors are returned as usual, the warnings only describe data which is accepted.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let (interp, warnings) = Interp1DBuilder::new(array![0.0, 1.0, 3.0])
+    ///     .x(array![0.0, 0.001, 10.0])
+    ///     .build_checked()
+    ///     .unwrap();
+    /// assert!(matches!(warnings[..], [Warning::SpacingRatio { narrowest: 0, widest: 1, .. }]));
+    /// # assert_eq!(interp.interp_scalar(10.0).unwrap(), 3.0);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn build_checked(
+        self,
+    ) -> Result<
+        (
+            Interp1D<Sd, Sx, D, Strat::FinishedStrat>,
+            Vec<Warning<Sx::Elem>>,
+        ),
+        BuilderError,
+    >
+    where
+        Sd::Elem: NumCast + Copy,
+    {
+        self.validate()?;
+        let warnings = self.warnings();
+        let interp = self.finish()?;
+        Ok((interp, warnings))
+    }
+
+    /// collect the warnings of the validated data
+    fn warnings(&self) -> Vec<Warning<Sx::Elem>>
+    where
+        Sd::Elem: NumCast + Copy,
+    {
+        let to_f64 = |value: Sx::Elem| cast::<_, f64>(value).unwrap_or(f64::NAN);
+        let abs = |value: Sx::Elem| {
+            if value < Sx::Elem::zero() {
+                Sx::Elem::zero() - value
+            } else {
+                value
+            }
+        };
+        let x = &self.x;
+        let range = to_f64(x[x.len() - 1] - x[0]);
+        let mut warnings = Vec::new();
+
+        let (mut narrowest, mut widest) = ((0, f64::INFINITY), (0, 0.0));
+        for (index, knots) in x.windows(2).into_iter().enumerate() {
+            let (left, right) = (knots[0], knots[1]);
+            let width = to_f64(right - left);
+            let magnitude = to_f64(abs(left)).max(to_f64(abs(right)));
+            if width <= 1e-9 * magnitude {
+                warnings.push(Warning::NearDuplicateX { index, left, right });
+            } else if width < 1e-6 * range {
+                warnings.push(Warning::NarrowSegment {
+                    index,
+                    width: right - left,
+                });
+            }
+            if width < narrowest.1 {
+                narrowest = (index, width);
+            }
+            if width > widest.1 {
+                widest = (index, width);
+            }
+        }
+        let ratio = widest.1 / narrowest.1;
+        if ratio > 1e3 {
+            warnings.push(Warning::SpacingRatio {
+                ratio,
+                narrowest: narrowest.0,
+                widest: widest.0,
+            });
+        }
+
+        let (smallest, largest) = self
+            .data
+            .iter()
+            .filter_map(|&value| cast::<_, f64>(value))
+            .map(f64::abs)
+            .filter(|value| *value > 0.0 && value.is_finite())
+            .fold((f64::INFINITY, 0.0_f64), |(smallest, largest), value| {
+                (smallest.min(value), largest.max(value))
+            });
+        if largest > 1e12 * smallest {
+            warnings.push(Warning::DataMagnitudes { smallest, largest });
+        }
+        warnings
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Debug;
+
+use ndarray::{
+    parallel::prelude::*, Array, ArrayBase, Axis, Data, Dimension, Ix1, OwnedRepr, RemoveAxis,
+};
+use num_traits::{Num, NumCast, Zero};
+
+use crate::{BuilderError, InterpolateError};
+
+use super::{Interp1D, Interp1DBuilder, Interp1DStrategy, Interp1DStrategyBuilder};
+
+/// One dimensional interpolator for stacked series, built and queried in parallel
+///
+/// The data is interpolated along `Axis(0)` just like [`Interp1D`], but it is split
+/// into chunks along `Axis(1)`. Each chunk is an independent [`Interp1D`],
+/// so the strategies are built and queried for all chunks in parallel.
+/// The results are identical to a single [`Interp1D`] over the full data.
+///
+/// This is useful when many independent series are stacked in the trailing axes,
+/// it does not parallelize the individual points of a query.
+///
+/// This is created by [`Interp1DBuilder::build_par_chunks`].
+#[derive(Debug)]
+pub struct ChunkedInterp1D<T, X, D, Strat>
+where
+    T: Debug + Send,
+    X: Num + Debug + Send,
+    D: Dimension,
+    Strat: Interp1DStrategy<OwnedRepr<T>, OwnedRepr<X>, D>,
+{
+    /// the chunks in order of `Axis(1)`
+    chunks: Vec<Interp1D<OwnedRepr<T>, OwnedRepr<X>, D, Strat>>,
+    /// lenght of the chunks along `Axis(1)`, the last chunk may be shorter
+    chunk_len: usize,
+    /// shape of the full data
+    shape: D,
+}
+
+impl<Sd, Sx, D, StratBuilder> Interp1DBuilder<Sd, Sx, D, StratBuilder>
+where
+    Sd: Data,
+    Sd::Elem: Clone + Debug + Send + Sync,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send + Sync,
+    D: Dimension + RemoveAxis,
+    StratBuilder:
+        Interp1DStrategyBuilder<OwnedRepr<Sd::Elem>, OwnedRepr<Sx::Elem>, D> + Clone + Send + Sync,
+    StratBuilder::FinishedStrat: Send,
+{
+    /// Split the data into chunks of `chunk_len` along `Axis(1)` and build
+    /// an interpolator for each chunk in parallel.
+    ///
+    /// The last chunk may be shorter. All other settings of the builder apply to every chunk.
+    /// Settings of the strategy in the shape of the data, like individual boundary conditions
+    /// of a [`CubicSpline`](super::cubic_spline::CubicSpline), are split into the chunks as
+    /// well, see [`Interp1DStrategyBuilder::select_columns`].
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let data = Array::from_shape_fn((4, 6, 2), |(i, j, k)| (i * j + k) as f64);
+    /// let interp = Interp1DBuilder::new(data.clone())
+    ///     .build_par_chunks(4)
+    ///     .unwrap();
+    /// let serial = Interp1DBuilder::new(data).build().unwrap();
+    ///
+    /// let query = array![0.5, 1.0, 2.75];
+    /// assert_eq!(interp.interp_array(&query).unwrap(), serial.interp_array(&query).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///  - When the data has less than two dimensions or `chunk_len` is zero
+    ///  - When a chunk can not be built, see [`build`](Interp1DBuilder::build)
+    #[allow(clippy::type_complexity)]
+    pub fn build_par_chunks(
+        self,
+        chunk_len: usize,
+    ) -> Result<ChunkedInterp1D<Sd::Elem, Sx::Elem, D, StratBuilder::FinishedStrat>, BuilderError>
+    {
+        if self.data.ndim() < 2 {
+            return Err(BuilderError::ShapeError(
+                "data dimension needs to be at least 2 to be split into chunks".into(),
+            ));
+        }
+        if chunk_len == 0 {
+            return Err(BuilderError::ValueError(
+                "chunk length must be greater than zero".into(),
+            ));
+        }
+
+        let Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        } = self;
+        let x = x.to_owned();
+        let chunks: Vec<_> = data.axis_chunks_iter(Axis(1), chunk_len).collect();
+        let chunks = chunks
+            .into_par_iter()
+            .enumerate()
+            .map(|(idx, chunk)| {
+                let start = idx * chunk_len;
+                let columns = start..start + chunk.len_of(Axis(1));
+                Interp1DBuilder {
+                    x: x.clone(),
+                    data: chunk.to_owned(),
+                    strategy: strategy.clone().select_columns(columns)?,
+                    gaps: gaps.clone(),
+                    max_gap,
+                    min_segment_width,
+                    closed_upper,
+                    output_transform: output_transform.clone(),
+                    masked: masked.clone(),
+                }
+                .build()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ChunkedInterp1D {
+            chunks,
+            chunk_len,
+            shape: data.raw_dim(),
+        })
+    }
+}
+
+impl<T, X, D, Strat> ChunkedInterp1D<T, X, D, Strat>
+where
+    T: Zero + Copy + Debug + Send + Sync,
+    X: Num + PartialOrd + NumCast + Copy + Debug + Send + Sync,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<OwnedRepr<T>, OwnedRepr<X>, D> + Sync,
+{
+    /// Calculate the interpolated values at all points in `xs`, all chunks in parallel
+    ///
+    /// The result has the shape of the data with the first axis replaced by the lenght of `xs`.
+    pub fn interp_array<Sq>(&self, xs: &ArrayBase<Sq, Ix1>) -> Result<Array<T, D>, InterpolateError>
+    where
+        Sq: Data<Elem = X> + Sync,
+    {
+        let mut dim = self.shape.clone();
+        dim[0] = xs.len();
+        let mut ys = Array::zeros(dim);
+        let targets: Vec<_> = ys.axis_chunks_iter_mut(Axis(1), self.chunk_len).collect();
+        self.chunks
+            .par_iter()
+            .zip(targets)
+            .try_for_each(|(interp, mut target)| {
+                for (&x, row) in xs.iter().zip(target.axis_iter_mut(Axis(0))) {
+                    interp.interp_into(x, row)?;
+                }
+                Ok::<_, InterpolateError>(())
+            })?;
+        Ok(ys)
+    }
+
+    /// The interpolators of the individual chunks, in order of `Axis(1)`
+    pub fn chunks(&self) -> &[Interp1D<OwnedRepr<T>, OwnedRepr<X>, D, Strat>] {
+        &self.chunks
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+
+use ndarray::{Array, Array1, Data, Dimension, RemoveAxis};
+use num_traits::{Num, NumCast, Zero};
+
+use crate::InterpolateError;
+
+use super::{Interp1D, Interp1DStrategy};
+
+/// Resample two interpolators onto the union of their x axes, e.g. to compare two datasets.
+///
+/// **returns** `(data_a, data_b, x)` where `x` is the sorted union of both x axes
+/// without duplicates. `data_a` and `data_b` are the interpolants evaluated at `x`,
+/// with the shape of their data and the lenght of `x` along the first axis.
+/// At the knots of an interpolator its own data is reproduced by interpolating strategies.
+///
+/// Queries outside of the range of an interpolator are handled by its strategy:
+/// they are extrapolated when it is configured to extrapolate, otherwise
+/// [`InterpolateError::OutOfBounds`] is returned.
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// let a = Interp1DBuilder::new(array![0.0, 2.0, 4.0])
+///     .x(array![0.0, 1.0, 2.0])
+///     .build()
+///     .unwrap();
+/// let b = Interp1DBuilder::new(array![1.0, 1.0])
+///     .x(array![0.5, 2.0])
+///     .build()
+///     .unwrap();
+///
+/// // `b` does not extrapolate, so the grid must not start below 0.5
+/// assert!(common_grid(&a, &b).is_err());
+///
+/// let b = Interp1DBuilder::new(array![1.0, 1.0])
+///     .x(array![0.5, 2.0])
+///     .strategy(Linear::new().extrapolate(true))
+///     .build()
+///     .unwrap();
+/// let (data_a, data_b, x) = common_grid(&a, &b).unwrap();
+/// assert_eq!(x, array![0.0, 0.5, 1.0, 2.0]);
+/// assert_eq!(data_a, array![0.0, 1.0, 2.0, 4.0]);
+/// assert_eq!(data_b, array![1.0, 1.0, 1.0, 1.0]);
+/// ```
+///
+/// # Errors
+///  - [`InterpolateError::OutOfBounds`] when the union is outside of the range of an
+///    interpolator which does not extrapolate, or inside a masked gap
+#[allow(clippy::type_complexity)]
+pub fn common_grid<Sda, Sdb, Sxa, Sxb, Da, Db, Sa, Sb>(
+    interp_a: &Interp1D<Sda, Sxa, Da, Sa>,
+    interp_b: &Interp1D<Sdb, Sxb, Db, Sb>,
+) -> Result<
+    (
+        Array<Sda::Elem, Da>,
+        Array<Sdb::Elem, Db>,
+        Array1<Sxa::Elem>,
+    ),
+    InterpolateError,
+>
+where
+    Sda: Data,
+    Sda::Elem: Zero + Copy + Debug + Send,
+    Sdb: Data,
+    Sdb::Elem: Zero + Copy + Debug + Send,
+    Sxa: Data,
+    Sxa::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    Sxb: Data<Elem = Sxa::Elem>,
+    Da: Dimension + RemoveAxis,
+    Db: Dimension + RemoveAxis,
+    Sa: Interp1DStrategy<Sda, Sxa, Da>,
+    Sb: Interp1DStrategy<Sdb, Sxb, Db>,
+{
+    // both axes are strictly monotonic rising, merge them and keep shared values once
+    let (x_a, x_b) = (&interp_a.x, &interp_b.x);
+    let mut x = Vec::with_capacity(x_a.len() + x_b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < x_a.len() || j < x_b.len() {
+        let next = match (x_a.get(i), x_b.get(j)) {
+            (Some(&a), Some(&b)) if a == b => {
+                i += 1;
+                j += 1;
+                a
+            }
+            (Some(&a), Some(&b)) if a < b => {
+                i += 1;
+                a
+            }
+            (Some(&a), None) => {
+                i += 1;
+                a
+            }
+            (_, Some(&b)) => {
+                j += 1;
+                b
+            }
+            (None, None) => unreachable!(),
+        };
+        x.push(next);
+    }
+    let x = Array1::from(x);
+
+    let mut dim_a = interp_a.data.raw_dim();
+    dim_a[0] = x.len();
+    let mut data_a = Array::zeros(dim_a);
+    interp_a.interp_array_into_1d(&x, data_a.view_mut())?;
+
+    let mut dim_b = interp_b.data.raw_dim();
+    dim_b[0] = x.len();
+    let mut data_b = Array::zeros(dim_b);
+    interp_b.interp_array_into_1d(&x, data_b.view_mut())?;
+
+    Ok((data_a, data_b, x))
+}
@@ -0,0 +1,76 @@
+use std::fmt::Debug;
+
+use ndarray::{ArrayD, Data, Dimension, RemoveAxis};
+use num_traits::{Num, NumCast, Zero};
+
+use crate::InterpolateError;
+
+use super::{Interp1D, Interp1DStrategy};
+
+/// Object safe interface of [`Interp1D`]
+///
+/// [`Interp1D`] is generic over its strategy and dimension, so interpolators with
+/// different strategies have different types. This trait erases those types,
+/// which allows to store them together, e.g. in a `Vec<Box<dyn DynInterp1D<f64>>>`.
+/// Use [`Interp1D::boxed`] to create the trait object.
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray_interp::interp1d::cubic_spline::CubicSpline;
+/// # use ndarray::*;
+/// let data = array![0.0, 1.0, 4.0, 9.0];
+/// let interpolators: Vec<Box<dyn DynInterp1D<f64>>> = vec![
+///     Interp1DBuilder::new(data.clone()).build().unwrap().boxed(),
+///     Interp1DBuilder::new(data)
+///         .strategy(CubicSpline::new())
+///         .build()
+///         .unwrap()
+///         .boxed(),
+/// ];
+/// for interp in &interpolators {
+///     println!("{}: {}", interp.strategy_name(), interp.interp_scalar(1.5).unwrap());
+/// }
+/// ```
+pub trait DynInterp1D<T, X = T> {
+    /// Calculate the interpolated values at `x`, see [`Interp1D::interp`].
+    ///
+    /// The result has the shape of the data with the first axis removed,
+    /// for one dimensional data this is a zero dimensional array.
+    fn interp_scalar(&self, x: X) -> Result<ArrayD<T>, InterpolateError>;
+
+    /// The name of the interpolation strategy, see [`Interp1D::strategy_name`]
+    fn strategy_name(&self) -> &'static str;
+}
+
+impl<Sd, Sx, D, Strat> DynInterp1D<Sd::Elem, Sx::Elem> for Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    fn interp_scalar(&self, x: Sx::Elem) -> Result<ArrayD<Sd::Elem>, InterpolateError> {
+        self.interp(x).map(|values| values.into_dyn())
+    }
+
+    fn strategy_name(&self) -> &'static str {
+        Strat::NAME
+    }
+}
+
+impl<Sd, Sx, D, Strat> Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data + 'static,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data + 'static,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis + 'static,
+    Strat: Interp1DStrategy<Sd, Sx, D> + 'static,
+{
+    /// Box the interpolator as a [`DynInterp1D`] trait object
+    pub fn boxed(self) -> Box<dyn DynInterp1D<Sd::Elem, Sx::Elem>> {
+        Box::new(self)
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Debug;
+
+use ndarray::{Array, ArrayView, Data, Dimension, RemoveAxis};
+use num_traits::{Num, NumCast, Zero};
+
+use crate::InterpolateError;
+
+use super::{Interp1D, Interp1DStrategy};
+
+/// One dimensional interpolator for a stream of queries, which holds the last
+/// in range result while the queries are out of range
+///
+/// Queries inside the x range are interpolated as usual and the result is remembered.
+/// Queries outside of the range return the remembered result instead of an error or
+/// a clamped value, even when the strategy extrapolates. This suits real time
+/// processing where a query may leave the range for a moment.
+///
+/// Nothing is remembered until the first query inside the range,
+/// until then out of range queries return [`InterpolateError::OutOfBounds`].
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// let interp = Interp1DBuilder::new(array![0.0, 1.0, 4.0]).build().unwrap();
+/// let mut stream = interp.hold_last();
+///
+/// assert!(stream.next(-1.0).is_err());
+/// assert_eq!(stream.next(1.5).unwrap().into_scalar(), 2.5);
+/// assert_eq!(stream.next(7.0).unwrap().into_scalar(), 2.5);
+/// assert_eq!(stream.next(0.5).unwrap().into_scalar(), 0.5);
+/// ```
+///
+/// This is created by [`Interp1D::hold_last`].
+#[derive(Debug)]
+pub struct HoldLastInterp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    interp: Interp1D<Sd, Sx, D, Strat>,
+    /// the result of the last query inside the range
+    last: Option<Array<Sd::Elem, D::Smaller>>,
+}
+
+impl<Sd, Sx, D, Strat> Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    /// Wrap the interpolator into a [`HoldLastInterp1D`], which returns the last
+    /// in range result for queries outside of the range.
+    pub fn hold_last(self) -> HoldLastInterp1D<Sd, Sx, D, Strat> {
+        HoldLastInterp1D {
+            interp: self,
+            last: None,
+        }
+    }
+}
+
+impl<Sd, Sx, D, Strat> HoldLastInterp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    /// Calculate the interpolated values at the next query `x`.
+    ///
+    /// **returns** the interpolated values when `x` is inside the range,
+    /// otherwise the result of the last query inside the range.
+    ///
+    /// # Errors
+    ///  - When `x` is out of range and there was no query inside the range yet
+    ///  - When `x` is inside a masked gap, see [`Interp1DBuilder::mask`](super::Interp1DBuilder::mask).
+    ///    The held result is not changed.
+    pub fn next(&mut self, x: Sx::Elem) -> Result<Array<Sd::Elem, D::Smaller>, InterpolateError> {
+        if self.interp.is_in_range(x) {
+            let values = self.interp.interp(x)?;
+            self.last = Some(values.clone());
+            return Ok(values);
+        }
+        self.last.clone().ok_or_else(|| {
+            InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is not in range and there is no previous result to hold"
+            ))
+        })
+    }
+
+    /// The held result of the last query inside the range, if there was one
+    pub fn last(&self) -> Option<ArrayView<'_, Sd::Elem, D::Smaller>> {
+        self.last.as_ref().map(|last| last.view())
+    }
+
+    /// Forget the held result, out of range queries fail until the next query inside the range
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// The wrapped interpolator
+    pub fn interpolator(&self) -> &Interp1D<Sd, Sx, D, Strat> {
+        &self.interp
+    }
+}
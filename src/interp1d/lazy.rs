@@ -0,0 +1,80 @@
+use std::{
+    fmt::Debug,
+    sync::{Mutex, OnceLock},
+};
+
+use ndarray::{Data, Dimension, RemoveAxis};
+use num_traits::{Num, NumCast};
+
+use crate::BuilderError;
+
+use super::{Interp1D, Interp1DBuilder, Interp1DStrategyBuilder};
+
+/// One dimensional interpolator which builds its strategy on first access
+///
+/// The data is validated when the [`LazyInterp1D`] is created, but the strategy
+/// build (e.g. solving for the coefficients of a [`CubicSpline`](super::cubic_spline::CubicSpline))
+/// is deferred until [`get`](LazyInterp1D::get) is called for the first time.
+/// This avoids the cost of the build for interpolators which are never queried.
+///
+/// The build runs exactly once, even when the first accesses happen concurrently
+/// from multiple threads. All later accesses reuse the built [`Interp1D`].
+///
+/// This is created by [`Interp1DBuilder::build_lazy`].
+#[derive(Debug)]
+pub struct LazyInterp1D<Sd, Sx, D, StratBuilder>
+where
+    Sd: Data,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    StratBuilder: Interp1DStrategyBuilder<Sd, Sx, D>,
+{
+    /// the validated builder, taken by the first access
+    builder: Mutex<Option<Interp1DBuilder<Sd, Sx, D, StratBuilder>>>,
+    #[allow(clippy::type_complexity)]
+    interp: OnceLock<Result<Interp1D<Sd, Sx, D, StratBuilder::FinishedStrat>, BuilderError>>,
+}
+
+impl<Sd, Sx, D, StratBuilder> LazyInterp1D<Sd, Sx, D, StratBuilder>
+where
+    Sd: Data,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    StratBuilder: Interp1DStrategyBuilder<Sd, Sx, D>,
+{
+    pub(super) fn new(builder: Interp1DBuilder<Sd, Sx, D, StratBuilder>) -> Self {
+        LazyInterp1D {
+            builder: Mutex::new(Some(builder)),
+            interp: OnceLock::new(),
+        }
+    }
+
+    /// Get the interpolator, building the strategy if this is the first access
+    ///
+    /// # Errors
+    /// When the strategy build fails. The error is stored and returned on every access.
+    #[allow(clippy::type_complexity)]
+    pub fn get(&self) -> Result<&Interp1D<Sd, Sx, D, StratBuilder::FinishedStrat>, BuilderError> {
+        self.interp
+            .get_or_init(|| {
+                let builder = self
+                    .builder
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .take()
+                    .unwrap_or_else(|| unreachable!("the strategy is only built once"));
+                builder.finish()
+            })
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// Has the strategy been built yet?
+    pub fn is_built(&self) -> bool {
+        self.interp.get().is_some()
+    }
+}
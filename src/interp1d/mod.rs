@@ -3,6 +3,8 @@
 //! # Interpolator
 //!  - [`Interp1D`] The interpolator used with any strategy
 //!  - [`Interp1DBuilder`] Configure the interpolator
+//!  - [`SegmentedInterp1D`] Interpolate data with gaps, see [`Interp1DBuilder::new_with_gaps`]
+//!  - [`HoldLastInterp1D`] Hold the last in range result for a stream of queries, see [`Interp1D::hold_last`]
 //!
 //! # Traits
 //!  - [`Interp1DStrategy`] The trait used to specialize [`Interp1D`] with the correct strategy
@@ -11,36 +13,79 @@
 //! # Strategies
 //!  - [`Linear`] Linear interpolation strategy
 //!  - [`cubic_spline`] Cubic spline interpolation strategy
+//!  - [`TensionSpline`] Blend between linear and Catmull-Rom interpolation
+//!  - [`LocalSpline`] Blend cubic splines fitted to overlapping windows of knots
+//!  - [`Nearest`] Snap to the closest knot, e.g. for lookup tables with sparse integer keys
+//!  - [`Angular`] Linear interpolation of angles along the shorter way around the circle
 
-use std::{any::TypeId, fmt::Debug, ops::Sub};
+use std::{
+    any::TypeId,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt::{Debug, Display},
+    ops::Sub,
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
 
 use ndarray::{
-    Array, ArrayBase, ArrayView, ArrayViewMut, ArrayViewMut1, Axis, AxisDescription, Data, DimAdd,
-    Dimension, IntoDimension, Ix1, OwnedRepr, RemoveAxis, Slice, Zip,
+    Array, Array1, ArrayBase, ArrayView, ArrayView1, ArrayViewMut, ArrayViewMut1, Axis,
+    AxisDescription, Data, DimAdd, Dimension, IntoDimension, Ix1, OwnedRepr, RemoveAxis, Slice,
+    Zip,
 };
-use num_traits::{cast, Num, NumCast};
+use num_traits::{cast, Num, NumCast, Zero};
 
 use crate::{
     cast_unchecked,
     dim_extensions::DimExtension,
+    linalg::Scratch,
     vector_extensions::{Monotonic, VectorExtensions},
     BuilderError, InterpolateError,
 };
 
+mod adaptive;
 mod aliases;
+mod binary;
+mod checked;
+#[cfg(feature = "rayon")]
+mod chunked;
+mod common_grid;
+mod dynamic;
+mod hold_last;
+mod lazy;
+mod ragged;
+mod segmented;
 mod strategies;
+mod table;
 pub use aliases::*;
+pub use checked::Warning;
+#[cfg(feature = "rayon")]
+pub use chunked::ChunkedInterp1D;
+pub use common_grid::common_grid;
+pub use dynamic::DynInterp1D;
+pub use hold_last::HoldLastInterp1D;
+pub use lazy::LazyInterp1D;
+pub use ragged::interp_ragged;
+pub use segmented::{SegmentedInterp1D, SegmentedInterp1DBuilder};
+pub use strategies::angular::Angular;
+pub use strategies::blend::{Blend, BlendStrategy};
 pub use strategies::cubic_spline;
-pub use strategies::linear::Linear;
+pub use strategies::hermite;
+pub use strategies::linear::{Linear, LinearData};
+pub use strategies::local_spline::{LocalSpline, LocalSplineStrategy};
+pub use strategies::nearest::Nearest;
+pub use strategies::tension_spline::{TensionSpline, TensionSplineStrategy};
 pub use strategies::{Interp1DStrategy, Interp1DStrategyBuilder};
+pub use table::TableInterp1D;
 
 /// One dimensional interpolator
 #[derive(Debug)]
 pub struct Interp1D<Sd, Sx, D, Strat>
 where
     Sd: Data,
-    Sd::Elem: Num + Debug + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + Debug + Send,
     D: Dimension,
     Strat: Interp1DStrategy<Sd, Sx, D>,
 {
@@ -48,6 +93,90 @@ where
     x: ArrayBase<Sx, Ix1>,
     data: ArrayBase<Sd, D>,
     strategy: Strat,
+    /// `(left, right)` x values of masked out gaps which must not be interpolated
+    gaps: Vec<(Sx::Elem, Sx::Elem)>,
+    /// is the last knot part of the range, see [`Interp1DBuilder::closed_upper`]
+    closed_upper: bool,
+    /// applied to the interpolated values, see [`Interp1DBuilder::output_transform`]
+    output_transform: Option<OutputTransform<Sd::Elem>>,
+    /// monotonic property of one dimensional data, computed by the first
+    /// [`value_to_index`](Interp1D::value_to_index)
+    data_monotonic: OnceLock<Monotonic>,
+}
+
+/// How [`Interp1DBuilder::nan_policy`] handles `NaN` values in the data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// keep the data as it is, `NaN` propagates into the interpolated values
+    /// of the affected series
+    Propagate,
+    /// return a [`BuilderError::ValueError`] when the data contains `NaN`
+    Error,
+    /// remove each entry of the last data axis (e.g. each column of two dimensional
+    /// data) which contains `NaN`, so it is not part of the interpolated values
+    SkipSeries,
+}
+
+/// The extrapolation behaviour of an interpolator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtrapolateMode {
+    /// queries outside of the x range return [`InterpolateError::OutOfBounds`]
+    No,
+    /// queries outside of the x range are extrapolated
+    Yes,
+    /// queries below the x range are extrapolated,
+    /// queries above return [`InterpolateError::OutOfBounds`]
+    Low,
+    /// queries above the x range are extrapolated,
+    /// queries below return [`InterpolateError::OutOfBounds`]
+    High,
+    /// queries outside of the x range are mapped periodically into the range
+    Periodic,
+}
+
+impl ExtrapolateMode {
+    /// the mode of a strategy which extrapolates `low` below and `high` above the range
+    pub(crate) fn from_sides(low: bool, high: bool) -> Self {
+        match (low, high) {
+            (true, true) => ExtrapolateMode::Yes,
+            (true, false) => ExtrapolateMode::Low,
+            (false, true) => ExtrapolateMode::High,
+            (false, false) => ExtrapolateMode::No,
+        }
+    }
+}
+
+/// The affine transform `scale * y + offset` of [`Interp1DBuilder::output_transform`]
+#[derive(Debug, Clone, Copy)]
+struct OutputTransform<T> {
+    scale: T,
+    offset: T,
+    /// the arithmetic is captured when the transform is set, the
+    /// element bounds of the interpolator do not include it
+    affine: fn(T, T, T) -> T,
+}
+
+impl<T: Copy> OutputTransform<T> {
+    fn new(scale: T, offset: T) -> Self
+    where
+        T: Num,
+    {
+        OutputTransform {
+            scale,
+            offset,
+            affine: |y, scale, offset| scale * y + offset,
+        }
+    }
+
+    fn apply(&self, y: T) -> T {
+        (self.affine)(y, self.scale, self.offset)
+    }
+}
+
+impl<T: PartialEq> PartialEq for OutputTransform<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale && self.offset == other.offset
+    }
 }
 
 /// Create and configure a [Interp1D] Interpolator.
@@ -60,13 +189,33 @@ where
 pub struct Interp1DBuilder<Sd, Sx, D, Strat>
 where
     Sd: Data,
-    Sd::Elem: Num + Debug,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: Debug,
+    Sx: Data,
+    Sx::Elem: Num + Debug,
     D: Dimension,
 {
     x: ArrayBase<Sx, Ix1>,
     data: ArrayBase<Sd, D>,
     strategy: Strat,
+    gaps: Vec<(Sx::Elem, Sx::Elem)>,
+    max_gap: Option<Sx::Elem>,
+    /// `None` for the default relative to the x range
+    min_segment_width: Option<Sx::Elem>,
+    closed_upper: bool,
+    output_transform: Option<OutputTransform<Sd::Elem>>,
+    /// the segments which span knots removed by [`mask`](Interp1DBuilder::mask),
+    /// as the index of their left knot. They are converted to gaps with the x axis
+    /// of `build`, so the x axis can be replaced after masking.
+    masked: Vec<usize>,
+}
+
+/// Time spent in the individual steps of [`Interp1DBuilder::build_timed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BuildTiming {
+    /// checking that the x axis is strictly monotonic rising
+    pub monotonic_check: Duration,
+    /// building the strategy, e.g. solving for the spline coefficients
+    pub strategy_build: Duration,
 }
 
 impl<Sd, D> Interp1D<Sd, OwnedRepr<Sd::Elem>, D, Linear>
@@ -84,8 +233,9 @@ where
 impl<Sd, Sx, Strat> Interp1D<Sd, Sx, Ix1, Strat>
 where
     Sd: Data,
-    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
     Strat: Interp1DStrategy<Sd, Sx, Ix1>,
 {
     /// convinient interpolation function for interpolation at one point
@@ -106,19 +256,23 @@ where
     /// # assert_eq!(result, expected);
     /// ```
     pub fn interp_scalar(&self, x: Sx::Elem) -> Result<Sd::Elem, InterpolateError> {
-        let mut buffer: [Sd::Elem; 1] = [cast(0.0).unwrap_or_else(|| unimplemented!())];
+        let mut buffer: [Sd::Elem; 1] = [Zero::zero()];
         let buf_view = ArrayViewMut1::from(buffer.as_mut_slice()).remove_axis(Axis(0));
-        self.strategy
-            .interp_into(self, buf_view, x)
-            .map(|_| buffer[0])
+        self.check_gaps(x)?;
+        self.strategy.interp_into(self, buf_view, x)?;
+        Ok(match self.output_transform {
+            Some(transform) => transform.apply(buffer[0]),
+            None => buffer[0],
+        })
     }
 }
 
 impl<Sd, Sx, D, Strat> Interp1D<Sd, Sx, D, Strat>
 where
     Sd: Data,
-    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
     D: Dimension + RemoveAxis,
     Strat: Interp1DStrategy<Sd, Sx, D>,
 {
@@ -150,9 +304,53 @@ where
     pub fn interp(&self, x: Sx::Elem) -> Result<Array<Sd::Elem, D::Smaller>, InterpolateError> {
         let dim = self.data.raw_dim().remove_axis(Axis(0));
         let mut target: Array<Sd::Elem, _> = Array::zeros(dim);
-        self.strategy
-            .interp_into(self, target.view_mut(), x)
-            .map(|_| target)
+        self.check_gaps(x)?;
+        self.strategy.interp_into(self, target.view_mut(), x)?;
+        self.transform_output(target.view_mut());
+        Ok(target)
+    }
+
+    /// Calculate the interpolated values at `x`, holding the closest
+    /// data row when `x` is out of range.
+    ///
+    /// **returns** the values and a staleness flag, which is `true` when `x` was out
+    /// of range and the first or last data row was returned instead of interpolating.
+    /// The strategy is not used for those queries, so this also works for
+    /// strategies which do not extrapolate.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interpolator = Interp1DBuilder::new(array![[0.0, 1.0], [2.0, 3.0]])
+    ///     .build()
+    ///     .unwrap();
+    /// let (values, stale) = interpolator.interp_with_staleness(0.5).unwrap();
+    /// assert_eq!((values, stale), (array![1.0, 2.0], false));
+    /// let (values, stale) = interpolator.interp_with_staleness(3.0).unwrap();
+    /// assert_eq!((values, stale), (array![2.0, 3.0], true));
+    /// ```
+    ///
+    /// # Errors
+    ///  - When `x` is inside a masked gap, see [`Interp1DBuilder::mask`]
+    ///  - When `x` can not be compared to the x axis, e.g. `NaN`
+    #[allow(clippy::type_complexity)]
+    pub fn interp_with_staleness(
+        &self,
+        x: Sx::Elem,
+    ) -> Result<(Array<Sd::Elem, D::Smaller>, bool), InterpolateError> {
+        if x.partial_cmp(&self.x[0]).is_none() {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is not ordered with respect to the x axis"
+            )));
+        }
+        if self.is_in_range(x) {
+            return self.interp(x).map(|values| (values, false));
+        }
+        let idx = if x < self.x[0] { 0 } else { self.x.len() - 1 };
+        let (_, values) = self.index_point(idx);
+        let mut values = values.to_owned();
+        self.transform_output(values.view_mut());
+        Ok((values, true))
     }
 
     /// Calculate the interpolated values at `x`.
@@ -169,14 +367,104 @@ where
     pub fn interp_into(
         &self,
         x: Sx::Elem,
-        buffer: ArrayViewMut<'_, Sd::Elem, D::Smaller>,
+        mut buffer: ArrayViewMut<'_, Sd::Elem, D::Smaller>,
     ) -> Result<(), InterpolateError> {
-        self.strategy.interp_into(self, buffer, x)
+        self.check_gaps(x)?;
+        self.strategy.interp_into(self, buffer.view_mut(), x)?;
+        self.transform_output(buffer);
+        Ok(())
+    }
+
+    /// Resample the data on a uniform grid of `n` points from `start` to `stop` (inclusive).
+    ///
+    /// The result has the shape of the data with the lenght of the first axis
+    /// replaced by `n`.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let data = array![0.0, 1.0, 3.0];
+    /// let x = array![0.0, 0.5, 2.0];
+    /// let interpolator = Interp1DBuilder::new(data).x(x).build().unwrap();
+    ///
+    /// let resampled = interpolator.to_uniform(0.0, 2.0, 5).unwrap();
+    /// let expect = array![0.0, 1.0, 1.6666666666666667, 2.3333333333333335, 3.0];
+    /// # assert_abs_diff_eq!(resampled, expect, epsilon=1e-15);
+    /// ```
+    pub fn to_uniform(
+        &self,
+        start: Sx::Elem,
+        stop: Sx::Elem,
+        n: usize,
+    ) -> Result<Array<Sd::Elem, D>, InterpolateError> {
+        let step = if n > 1 {
+            (stop - start)
+                / cast(n - 1).unwrap_or_else(|| {
+                    unimplemented!("casting from usize to a number should always work")
+                })
+        } else {
+            Sx::Elem::zero()
+        };
+        let xs = Array::from_iter((0..n).map(|idx| {
+            if n > 1 && idx + 1 == n {
+                // avoid rounding errors at the endpoint
+                return stop;
+            }
+            let idx: Sx::Elem = cast(idx).unwrap_or_else(|| {
+                unimplemented!("casting from usize to a number should always work")
+            });
+            start + step * idx
+        }));
+
+        let mut dim = self.data.raw_dim();
+        dim[0] = n;
+        let mut ys = Array::zeros(dim);
+        self.interp_array_into_1d(&xs, ys.view_mut()).map(|_| ys)
+    }
+
+    /// Lazily calculate the interpolated values for a stream of queries.
+    ///
+    /// Each item is the result of [`interp`](Interp1D::interp) for the next query,
+    /// so the iterator can be chained with other iterator adaptors without
+    /// materializing all results.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let data = array![0.0, 1.0, 0.5];
+    /// let interpolator = Interp1DBuilder::new(data).build().unwrap();
+    ///
+    /// let sum: f64 = interpolator
+    ///     .iter_interp([0.5, 1.0, 1.5])
+    ///     .map(|y| y.unwrap().into_scalar())
+    ///     .sum();
+    /// # assert_eq!(sum, 0.5 + 1.0 + 0.75);
+    /// ```
+    pub fn iter_interp<'a, I>(
+        &'a self,
+        xs: I,
+    ) -> impl Iterator<Item = Result<Array<Sd::Elem, D::Smaller>, InterpolateError>> + 'a
+    where
+        I: IntoIterator<Item = Sx::Elem>,
+        I::IntoIter: 'a,
+    {
+        xs.into_iter().map(move |x| self.interp(x))
     }
 
     /// Calculate the interpolated values at all points in `xs`
     /// See [`interp_array_into`](Interp1D::interp_array_into) for dimension information
     ///
+    /// `xs` can have any dimension, there is no need to flatten a query grid.
+    /// The leading dimensions of the result mirror `xs`, the trailing dimensions
+    /// are the ones of the data with the first axis removed.
+    ///
+    /// The points in `xs` do not need to be sorted. Every point is located on its own,
+    /// so descending or unsorted queries give the same result as interpolating
+    /// each point individually.
+    ///
     /// ```rust
     /// # use ndarray_interp::*;
     /// # use ndarray_interp::interp1d::*;
@@ -193,13 +481,18 @@ where
     ///     .build().unwrap();
     /// let result = interpolator.interp_array(&query).unwrap();
     /// # assert_abs_diff_eq!(result, expected, epsilon=f64::EPSILON);
+    ///
+    /// let grid = array![[0.5, 1.0], [1.5, 2.0]];
+    /// let result = interpolator.interp_array(&grid).unwrap();
+    /// assert_eq!(result.shape(), &[2, 2]);
+    /// # assert_abs_diff_eq!(result, array![[0.25, 0.5], [0.75, 1.0]], epsilon=f64::EPSILON);
     /// ```
     pub fn interp_array<Sq, Dq>(
         &self,
         xs: &ArrayBase<Sq, Dq>,
     ) -> Result<Array<Sd::Elem, <Dq as DimAdd<D::Smaller>>::Output>, InterpolateError>
     where
-        Sq: Data<Elem = Sd::Elem>,
+        Sq: Data<Elem = Sx::Elem>,
         Dq: Dimension + DimAdd<D::Smaller> + 'static,
         <Dq as DimAdd<D::Smaller>>::Output: DimExtension,
     {
@@ -275,7 +568,7 @@ where
         mut buffer: ArrayViewMut<Sd::Elem, <Dq as DimAdd<D::Smaller>>::Output>,
     ) -> Result<(), InterpolateError>
     where
-        Sq: Data<Elem = Sd::Elem>,
+        Sq: Data<Elem = Sx::Elem>,
         Dq: Dimension + DimAdd<D::Smaller> + 'static,
         <Dq as DimAdd<D::Smaller>>::Output: DimExtension,
     {
@@ -308,7 +601,7 @@ where
                     }
                 });
 
-            let subview = match subview.into_shape(self.data.raw_dim().remove_axis(Axis(0))) {
+            let mut subview = match subview.into_shape(self.data.raw_dim().remove_axis(Axis(0))) {
                 Ok(view) => view,
                 Err(err) => {
                     let expect = self.get_buffer_shape(xs.raw_dim()).into_pattern();
@@ -317,7 +610,195 @@ where
                 }
             };
 
-            self.strategy.interp_into(self, subview, x)?;
+            self.check_gaps(x)?;
+            self.strategy.interp_into(self, subview.view_mut(), x)?;
+            self.transform_output(subview);
+        }
+        Ok(())
+    }
+
+    /// Calculate the interpolated values at all points in `xs` like
+    /// [`interp_array`](Interp1D::interp_array), but check the range only once.
+    ///
+    /// The minimum and maximum of `xs` are checked against the range of the
+    /// interpolator before any value is calculated, then the points are evaluated
+    /// without a range check each. Strategies which do not support skipping
+    /// the range check (see [`Interp1DStrategy::interp_into_in_range`]) still check each point.
+    /// [Gaps](Interp1DBuilder::max_gap) are checked for each point.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 3.0]).build().unwrap();
+    /// let query = array![0.5, 1.0, 1.5];
+    /// assert_eq!(interp.interp_array_unchecked(&query).unwrap(), interp.interp_array(&query).unwrap());
+    /// assert!(interp.interp_array_unchecked(&array![0.5, 2.5]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// When any point of `xs` is out of range, even when the strategy extrapolates,
+    /// or in a gap
+    pub fn interp_array_unchecked<Sq>(
+        &self,
+        xs: &ArrayBase<Sq, Ix1>,
+    ) -> Result<Array<Sd::Elem, D>, InterpolateError>
+    where
+        Sq: Data<Elem = Sx::Elem>,
+    {
+        let mut dim = self.data.raw_dim();
+        dim[0] = xs.len();
+        let mut ys = Array::zeros(dim);
+        if let Some(&first) = xs.first() {
+            // a value which can not be ordered (e.g. NaN) is reported as out of range
+            let range = xs.iter().try_fold((first, first), |(min, max), &x| {
+                match (x.partial_cmp(&min), x.partial_cmp(&max)) {
+                    (Some(Ordering::Less), _) => Ok((x, max)),
+                    (_, Some(Ordering::Greater)) => Ok((min, x)),
+                    (Some(_), Some(_)) => Ok((min, max)),
+                    _ => Err(x),
+                }
+            });
+            let out_of_range = match range {
+                Ok((min, max)) => [min, max].into_iter().find(|&x| !self.is_in_range(x)),
+                Err(x) => Some(x),
+            };
+            if let Some(x) = out_of_range {
+                return Err(InterpolateError::OutOfBounds(format!(
+                    "x = {x:#?} is not in range",
+                )));
+            }
+        }
+        Zip::from(xs)
+            .and(ys.axis_iter_mut(Axis(0)))
+            .fold_while(Ok(()), |_, &x, buf| {
+                match self
+                    .check_gaps(x)
+                    .and_then(|_| self.strategy.interp_into_in_range(self, buf, x))
+                {
+                    Ok(_) => ndarray::FoldWhile::Continue(Ok(())),
+                    Err(e) => ndarray::FoldWhile::Done(Err(e)),
+                }
+            })
+            .into_inner()?;
+        self.transform_output(ys.view_mut());
+        Ok(ys)
+    }
+
+    /// Calculate the interpolated values at the queries `sorted_xs` and scatter
+    /// them back into the original order of the queries.
+    ///
+    /// `perm[i]` is the position of `sorted_xs[i]` in the original queries, so the result
+    /// is the same as [`interp_array`](Interp1D::interp_array) of the original queries.
+    /// When the same unsorted queries are used repeatedly, e.g. once per frame, the
+    /// permutation can be computed once instead of sorting the queries each time.
+    /// Sorted queries access the knots and the data in order, which is cache friendly.
+    /// The results are correct for any order of `sorted_xs`.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 3.0]).build().unwrap();
+    /// let xs: Array1<f64> = array![1.5, 0.5, 2.0, 1.0];
+    ///
+    /// // computed once
+    /// let mut perm: Vec<usize> = (0..xs.len()).collect();
+    /// perm.sort_by(|&a, &b| xs[a].total_cmp(&xs[b]));
+    /// let sorted_xs = perm.iter().map(|&idx| xs[idx]).collect::<Array1<_>>();
+    ///
+    /// let ys = interp.interp_array_with_permutation(&sorted_xs, &perm).unwrap();
+    /// assert_eq!(ys, interp.interp_array(&xs).unwrap());
+    /// ```
+    ///
+    /// # panics
+    /// When `perm` is not a permutation of `0..sorted_xs.len()`
+    pub fn interp_array_with_permutation<Sq>(
+        &self,
+        sorted_xs: &ArrayBase<Sq, Ix1>,
+        perm: &[usize],
+    ) -> Result<Array<Sd::Elem, D>, InterpolateError>
+    where
+        Sq: Data<Elem = Sx::Elem>,
+    {
+        let len = sorted_xs.len();
+        assert!(
+            perm.len() == len,
+            "the permutation has {} entries for {len} queries",
+            perm.len()
+        );
+        let mut seen = vec![false; len];
+        for &idx in perm {
+            assert!(
+                idx < len && !seen[idx],
+                "{perm:?} is not a permutation of 0..{len}"
+            );
+            seen[idx] = true;
+        }
+
+        let mut dim = self.data.raw_dim();
+        dim[0] = len;
+        let mut ys = Array::zeros(dim);
+        for (&x, &idx) in sorted_xs.iter().zip(perm) {
+            self.check_gaps(x)?;
+            self.strategy
+                .interp_into(self, ys.index_axis_mut(Axis(0), idx), x)?;
+        }
+        self.transform_output(ys.view_mut());
+        Ok(ys)
+    }
+
+    /// Calculate the interpolated values at all points in `xs` and add them,
+    /// scaled by `weight`, to `out`: `out[i] += weight * interp(xs[i])`.
+    ///
+    /// This accumulates a weighted sum of several interpolators, e.g. for ensembles,
+    /// without allocating the results of each interpolator.
+    /// A single row is allocated as scratch space and reused for all points.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let a = Interp1DBuilder::new(array![0.0, 2.0, 4.0]).build().unwrap();
+    /// let b = Interp1DBuilder::new(array![4.0, 4.0, 0.0]).build().unwrap();
+    /// let xs = array![0.0, 0.5, 1.5];
+    ///
+    /// let mut sum = Array1::zeros(xs.len());
+    /// a.interp_array_add_scaled(&xs, 0.25, sum.view_mut()).unwrap();
+    /// b.interp_array_add_scaled(&xs, 0.75, sum.view_mut()).unwrap();
+    /// assert_eq!(sum, array![3.0, 3.25, 2.25]);
+    /// ```
+    ///
+    /// # Errors
+    /// The same as [`interp_array`](Interp1D::interp_array). When an error is returned
+    /// `out` holds the sums of the points before the failing point.
+    ///
+    /// # panics
+    /// When the shape of `out` is not the shape of the data with `xs.len()` along the first axis
+    pub fn interp_array_add_scaled<Sq>(
+        &self,
+        xs: &ArrayBase<Sq, Ix1>,
+        weight: Sd::Elem,
+        mut out: ArrayViewMut<'_, Sd::Elem, D>,
+    ) -> Result<(), InterpolateError>
+    where
+        Sq: Data<Elem = Sx::Elem>,
+        Sd::Elem: Num,
+    {
+        let mut dim = self.data.raw_dim();
+        dim[0] = xs.len();
+        assert!(
+            out.raw_dim() == dim,
+            "expected an output of shape {:?}, got {:?}",
+            dim.slice(),
+            out.shape()
+        );
+
+        let mut scratch = Array::zeros(self.data.raw_dim().remove_axis(Axis(0)));
+        for (&x, mut row) in xs.iter().zip(out.axis_iter_mut(Axis(0))) {
+            self.check_gaps(x)?;
+            self.strategy.interp_into(self, scratch.view_mut(), x)?;
+            self.transform_output(scratch.view_mut());
+            Zip::from(&mut row)
+                .and(&scratch)
+                .for_each(|sum, &value| *sum = *sum + weight * value);
         }
         Ok(())
     }
@@ -328,12 +809,29 @@ where
         mut buffer: ArrayViewMut<'_, Sd::Elem, D>,
     ) -> Result<(), InterpolateError>
     where
-        Sq: Data<Elem = Sd::Elem>,
+        Sq: Data<Elem = Sx::Elem>,
+    {
+        self.interp_array_into_1d_raw(xs, buffer.view_mut())?;
+        self.transform_output(buffer);
+        Ok(())
+    }
+
+    /// like [`interp_array_into_1d`](Interp1D::interp_array_into_1d) without the output transform
+    fn interp_array_into_1d_raw<Sq>(
+        &self,
+        xs: &ArrayBase<Sq, Ix1>,
+        mut buffer: ArrayViewMut<'_, Sd::Elem, D>,
+    ) -> Result<(), InterpolateError>
+    where
+        Sq: Data<Elem = Sx::Elem>,
     {
         Zip::from(xs)
             .and(buffer.axis_iter_mut(Axis(0)))
             .fold_while(Ok(()), |_, &x, buf| {
-                match self.strategy.interp_into(self, buf, x) {
+                match self
+                    .check_gaps(x)
+                    .and_then(|_| self.strategy.interp_into(self, buf, x))
+                {
                     Ok(_) => ndarray::FoldWhile::Continue(Ok(())),
                     Err(e) => ndarray::FoldWhile::Done(Err(e)),
                 }
@@ -341,6 +839,13 @@ where
             .into_inner()
     }
 
+    /// apply the [output transform](Interp1DBuilder::output_transform) to interpolated values
+    fn transform_output<Dt: Dimension>(&self, mut values: ArrayViewMut<'_, Sd::Elem, Dt>) {
+        if let Some(transform) = self.output_transform {
+            values.map_inplace(|y| *y = transform.apply(*y));
+        }
+    }
+
     /// the required shape of the buffer when calling [`interp_array_into`]
     fn get_buffer_shape<Dq>(&self, dq: Dq) -> <Dq as DimAdd<D::Smaller>>::Output
     where
@@ -360,7 +865,15 @@ where
     ///  - `data.shape()[0] == x.len()`
     ///  - the `strategy` is porperly initialized with the data
     pub unsafe fn new_unchecked(x: ArrayBase<Sx, Ix1>, data: ArrayBase<Sd, D>, strategy: Strat) -> Self {
-        Interp1D { x, data, strategy }
+        Interp1D {
+            x,
+            data,
+            strategy,
+            gaps: Vec::new(),
+            closed_upper: true,
+            output_transform: None,
+            data_monotonic: OnceLock::new(),
+        }
     }
 
     /// get `(x, data)` coordinate at given index
@@ -376,12 +889,471 @@ where
     ///
     /// This will never return the right most index,
     /// so calling [`index_point(idx+1)`](Interp1D::index_point) is always safe.
+    /// At (or right of) the last knot `x.len() - 2` is returned, the last knot is the
+    /// right end of the last segment. This does not depend on
+    /// [`closed_upper`](Interp1DBuilder::closed_upper).
     pub fn get_index_left_of(&self, x: Sx::Elem) -> usize {
         self.x.get_lower_index(x)
     }
 
+    /// Is `x` inside the range of the x axis.
+    ///
+    /// The range is `[x[0], x[last]]`, or `[x[0], x[last])` when the
+    /// upper bound is open, see [`closed_upper`](Interp1DBuilder::closed_upper).
     pub fn is_in_range(&self, x: Sx::Elem) -> bool {
-        self.x[0] <= x && x <= self.x[self.x.len() - 1]
+        let last = self.x[self.x.len() - 1];
+        self.x[0] <= x && (x < last || self.closed_upper && x == last)
+    }
+
+    /// Errors when `x` is out of range on a side which is not extrapolated.
+    ///
+    /// Queries which are not below the range, e.g. `NaN`, count as above the range.
+    pub(crate) fn check_range(
+        &self,
+        x: Sx::Elem,
+        extrapolate_low: bool,
+        extrapolate_high: bool,
+    ) -> Result<(), InterpolateError> {
+        let extrapolate = if x < self.x[0] {
+            extrapolate_low
+        } else {
+            extrapolate_high
+        };
+        if !extrapolate && !self.is_in_range(x) {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is not in range",
+            )));
+        }
+        Ok(())
+    }
+
+    /// A view of the x axis, one value for each knot.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![[1.0, 0.0], [2.0, 0.5], [3.0, 1.5]])
+    ///     .x(array![0.0, 0.5, 2.0])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(interp.x().len(), 3);
+    /// assert_eq!(interp.x(), array![0.0, 0.5, 2.0]);
+    /// assert_eq!(interp.data().len_of(Axis(0)), interp.x().len());
+    /// ```
+    pub fn x(&self) -> ArrayView1<'_, Sx::Elem> {
+        self.x.view()
+    }
+
+    /// A view of the data, the first axis has one entry for each knot
+    pub fn data(&self) -> ArrayView<'_, Sd::Elem, D> {
+        self.data.view()
+    }
+
+    /// The number of independent series which are interpolated,
+    /// the product of the data shape without the first axis.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(Array::<f64, _>::zeros((4, 3, 2))).build().unwrap();
+    /// assert_eq!(interp.n_series(), 6);
+    /// ```
+    pub fn n_series(&self) -> usize {
+        self.data.shape()[1..].iter().product()
+    }
+
+    /// The name of the interpolation strategy, see [`Interp1DStrategy::NAME`]
+    pub fn strategy_name(&self) -> &'static str {
+        Strat::NAME
+    }
+
+    /// The extrapolation behaviour of the interpolator,
+    /// see [`Interp1DStrategy::extrapolation_mode`]
+    pub fn extrapolation_mode(&self) -> ExtrapolateMode {
+        self.strategy.extrapolation_mode()
+    }
+
+    /// The bytes used by the stored arrays: the x axis, the data and the
+    /// coefficients of the strategy, see [`Interp1DStrategy::memory_bytes`].
+    ///
+    /// The x axis and the data are counted with their full size,
+    /// also when the interpolator only holds views of them.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(Array::<f64, _>::zeros((4, 2))).build().unwrap();
+    /// // 4 x values and 8 data values, linear interpolation stores no coefficients
+    /// assert_eq!(interp.memory_bytes(), 12 * 8);
+    /// ```
+    pub fn memory_bytes(&self) -> usize {
+        self.x.len() * std::mem::size_of::<Sx::Elem>()
+            + self.data.len() * std::mem::size_of::<Sd::Elem>()
+            + self.gaps.capacity() * std::mem::size_of::<(Sx::Elem, Sx::Elem)>()
+            + self.strategy.memory_bytes()
+    }
+
+    /// The width `x[i+1] - x[i]` of each segment between two knots.
+    ///
+    /// The result has one element less than the x axis.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![1.0, 2.0, 3.0])
+    ///     .x(array![0.0, 0.5, 2.0])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(interp.segment_widths(), array![0.5, 1.5]);
+    /// ```
+    pub fn segment_widths(&self) -> Array1<Sx::Elem> {
+        Zip::from(self.x.windows(2)).map_collect(|x| x[1] - x[0])
+    }
+
+    /// The x value in the middle of each segment between two knots.
+    ///
+    /// The result has one element less than the x axis. For integer x values
+    /// the midpoints are rounded towards the left knot.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![1.0, 2.0, 3.0])
+    ///     .x(array![0.0, 0.5, 2.0])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(interp.midpoints(), array![0.25, 1.25]);
+    /// ```
+    pub fn midpoints(&self) -> Array1<Sx::Elem> {
+        let two: Sx::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        // add half the width instead of halving the sum, which could overflow
+        Zip::from(self.x.windows(2)).map_collect(|x| x[0] + (x[1] - x[0]) / two)
+    }
+
+    /// Calculate the interpolated values at the [`midpoints`](Interp1D::midpoints)
+    /// of all segments.
+    ///
+    /// The result has the shape of the data with one element less along the first axis.
+    /// Together with the data at the knots this refines the grid by a factor of two.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![[0.0, 1.0], [2.0, 3.0], [0.0, 5.0]])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(interp.interp_at_midpoints().unwrap(), array![[1.0, 2.0], [1.0, 4.0]]);
+    /// ```
+    ///
+    /// # Errors
+    /// When a midpoint lies inside a masked gap, see [`Interp1DBuilder::mask`]
+    pub fn interp_at_midpoints(&self) -> Result<Array<Sd::Elem, D>, InterpolateError> {
+        let xs = self.midpoints();
+        let mut dim = self.data.raw_dim();
+        dim[0] = xs.len();
+        let mut ys = Array::zeros(dim);
+        self.interp_array_into_1d(&xs, ys.view_mut()).map(|_| ys)
+    }
+
+    /// The deviation of the interpolant from the data at each knot.
+    ///
+    /// The interpolant is evaluated at the original x values and the stored data is
+    /// subtracted. For interpolating strategies like [`Linear`] and [`CubicSpline`](cubic_spline::CubicSpline)
+    /// the residuals are zero up to rounding errors, for strategies which do not pass
+    /// through the data they measure the quality of the fit.
+    /// The result has the same shape as the data. When the upper bound is open,
+    /// see [`closed_upper`](Interp1DBuilder::closed_upper), the last knot is not
+    /// in the range and its residual is zero.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let interp = Interp1DBuilder::new(array![1.0, 3.0, 2.0, 0.0])
+    ///     .strategy(cubic_spline::CubicSpline::new())
+    ///     .build()
+    ///     .unwrap();
+    /// let residuals = interp.residuals().unwrap();
+    /// # assert_abs_diff_eq!(residuals, array![0.0, 0.0, 0.0, 0.0], epsilon=1e-14);
+    /// ```
+    ///
+    /// # Errors
+    /// When the strategy can not be evaluated at a knot, e.g. when a
+    /// [phase origin](Interp1D::set_phase_origin) moves the queries out of range.
+    pub fn residuals(&self) -> Result<Array<Sd::Elem, D>, InterpolateError>
+    where
+        Sd::Elem: Sub<Output = Sd::Elem>,
+    {
+        let mut residuals = Array::zeros(self.data.raw_dim());
+        let len = if self.closed_upper {
+            self.x.len()
+        } else {
+            self.x.len() - 1
+        };
+        self.interp_array_into_1d_raw(
+            &self.x.slice_axis(Axis(0), Slice::from(..len)),
+            residuals.slice_axis_mut(Axis(0), Slice::from(..len)),
+        )?;
+        residuals
+            .slice_axis_mut(Axis(0), Slice::from(len..))
+            .assign(&self.data.slice_axis(Axis(0), Slice::from(len..)));
+        Zip::from(&mut residuals)
+            .and(&self.data)
+            .for_each(|residual, &y| *residual = *residual - y);
+        Ok(residuals)
+    }
+
+    /// Combine two interpolators with adjacent x ranges into one interpolator over
+    /// the combined grid, built with `strategy`.
+    ///
+    /// The interpolators can be given in either order. When the ranges share their
+    /// boundary knot it is used once, in that case the data at the boundary has to match.
+    /// The masked gaps of both are kept, [`closed_upper`](Interp1DBuilder::closed_upper)
+    /// and the [`output_transform`](Interp1DBuilder::output_transform) have to match.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let left = Interp1DBuilder::new(array![0.0, 1.0, 4.0]).build().unwrap();
+    /// let right = Interp1DBuilder::new(array![4.0, 2.0])
+    ///     .x(array![2.0, 3.0])
+    ///     .build()
+    ///     .unwrap();
+    /// let interp = left.concat(&right, Linear::new()).unwrap();
+    /// assert_eq!(interp.interp_scalar(2.5).unwrap(), 3.0);
+    /// ```
+    ///
+    /// # Errors
+    ///  - When the data shapes, except for the first axis, do not match
+    ///  - When the x ranges overlap, or share a knot with different data
+    ///  - When `closed_upper` or the output transform differ
+    ///  - When the combined data can not be built with `strategy`
+    #[allow(clippy::type_complexity)]
+    pub fn concat<Sd2, Sx2, Strat2, NewStrat>(
+        &self,
+        other: &Interp1D<Sd2, Sx2, D, Strat2>,
+        strategy: NewStrat,
+    ) -> Result<
+        Interp1D<OwnedRepr<Sd::Elem>, OwnedRepr<Sx::Elem>, D, NewStrat::FinishedStrat>,
+        BuilderError,
+    >
+    where
+        Sd::Elem: PartialEq,
+        Sd2: Data<Elem = Sd::Elem>,
+        Sx2: Data<Elem = Sx::Elem>,
+        Strat2: Interp1DStrategy<Sd2, Sx2, D>,
+        NewStrat: Interp1DStrategyBuilder<OwnedRepr<Sd::Elem>, OwnedRepr<Sx::Elem>, D>,
+    {
+        if self.data.shape()[1..] != other.data.shape()[1..] {
+            return Err(BuilderError::ShapeError(format!(
+                "data shapes do not match, got {:?} and {:?}",
+                self.data.shape(),
+                other.data.shape()
+            )));
+        }
+        if self.closed_upper != other.closed_upper {
+            return Err(BuilderError::ValueError(
+                "closed_upper of the interpolators does not match".into(),
+            ));
+        }
+        if self.output_transform != other.output_transform {
+            return Err(BuilderError::ValueError(
+                "the output transforms of the interpolators do not match".into(),
+            ));
+        }
+
+        let (left_x, left_data, right_x, right_data) = if self.x[self.x.len() - 1] <= other.x[0] {
+            (
+                self.x.view(),
+                self.data.view(),
+                other.x.view(),
+                other.data.view(),
+            )
+        } else if other.x[other.x.len() - 1] <= self.x[0] {
+            (
+                other.x.view(),
+                other.data.view(),
+                self.x.view(),
+                self.data.view(),
+            )
+        } else {
+            return Err(BuilderError::Monotonic(format!(
+                "the x ranges [{:?}, {:?}] and [{:?}, {:?}] overlap",
+                self.x[0],
+                self.x[self.x.len() - 1],
+                other.x[0],
+                other.x[other.x.len() - 1]
+            )));
+        };
+
+        let last = left_x.len() - 1;
+        let shared = left_x[last] == right_x[0];
+        if shared && left_data.index_axis(Axis(0), last) != right_data.index_axis(Axis(0), 0) {
+            return Err(BuilderError::ValueError(format!(
+                "the data at the shared knot x = {:?} does not match",
+                right_x[0]
+            )));
+        }
+        let skip = Slice::from(if shared { 1 } else { 0 }..);
+        let concat_err = |err: ndarray::ShapeError| BuilderError::ShapeError(err.to_string());
+        let x = ndarray::concatenate(Axis(0), &[left_x, right_x.slice_axis(Axis(0), skip)])
+            .map_err(concat_err)?;
+        let data =
+            ndarray::concatenate(Axis(0), &[left_data, right_data.slice_axis(Axis(0), skip)])
+                .map_err(concat_err)?;
+
+        Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps: self.gaps.iter().chain(&other.gaps).copied().collect(),
+            // the gaps are already filtered, keep all of them
+            max_gap: Some(Sx::Elem::zero()),
+            min_segment_width: None,
+            closed_upper: self.closed_upper,
+            output_transform: self.output_transform,
+            masked: Vec::new(),
+        }
+        .build()
+    }
+
+    /// Errors when `x` lies inside a masked out gap,
+    /// see [`Interp1DBuilder::mask`]
+    fn check_gaps(&self, x: Sx::Elem) -> Result<(), InterpolateError> {
+        // the gaps are sorted and do not overlap, only the last gap starting left of x can contain it
+        let idx = self.gaps.partition_point(|&(left, _)| left < x);
+        match idx
+            .checked_sub(1)
+            .map(|idx| self.gaps[idx])
+            .filter(|&(_, right)| x < right)
+        {
+            Some((left, right)) => Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is in the masked gap between {left:#?} and {right:#?}"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A short human readable summary of the interpolator
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// let interp = Interp1D::builder(array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])
+///     .x(array![0.0, 0.5, 2.0])
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     interp.to_string(),
+///     "Interp1D: 3 knots, x in [0.0, 2.0], data shape [3, 2], strategy: Linear (extrapolate: false)"
+/// );
+/// ```
+impl<Sd, Sx, D, Strat> Display for Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + Debug + Send,
+    D: Dimension,
+    Strat: Interp1DStrategy<Sd, Sx, D> + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Interp1D: {} knots, x in [{:?}, {:?}], data shape {:?}, strategy: {}",
+            self.x.len(),
+            self.x[0],
+            self.x[self.x.len() - 1],
+            self.data.shape(),
+            self.strategy
+        )
+    }
+}
+
+impl<Sd, Sx, D, Strat> PartialEq for Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: PartialEq + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + Debug + Send,
+    D: Dimension,
+    Strat: Interp1DStrategy<Sd, Sx, D> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x
+            && self.data == other.data
+            && self.gaps == other.gaps
+            && self.closed_upper == other.closed_upper
+            && self.output_transform == other.output_transform
+            && self.strategy == other.strategy
+    }
+}
+
+/// Approximate comparison of the x axis, data, gaps and output transform.
+/// The strategies implement [`AbsDiffEq`](approx::AbsDiffEq) and [`RelativeEq`](approx::RelativeEq)
+/// for [`Interp1D`] on top of this, comparing their coefficients.
+#[cfg(feature = "approx")]
+impl<Sd, Sx, D, Strat> Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Debug + Send + approx::RelativeEq,
+    <Sd::Elem as approx::AbsDiffEq>::Epsilon: Clone,
+    Sx: Data,
+    Sx::Elem:
+        Num + Debug + Send + approx::RelativeEq<Epsilon = <Sd::Elem as approx::AbsDiffEq>::Epsilon>,
+    D: Dimension,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    fn base_abs_diff_eq(
+        &self,
+        other: &Self,
+        epsilon: <Sd::Elem as approx::AbsDiffEq>::Epsilon,
+    ) -> bool {
+        use approx::AbsDiffEq;
+        self.closed_upper == other.closed_upper
+            && self.x.abs_diff_eq(&other.x, epsilon.clone())
+            && self.data.abs_diff_eq(&other.data, epsilon.clone())
+            && self.gaps.len() == other.gaps.len()
+            && self.gaps.iter().zip(&other.gaps).all(|(a, b)| {
+                a.0.abs_diff_eq(&b.0, epsilon.clone()) && a.1.abs_diff_eq(&b.1, epsilon.clone())
+            })
+            && match (&self.output_transform, &other.output_transform) {
+                (Some(a), Some(b)) => {
+                    a.scale.abs_diff_eq(&b.scale, epsilon.clone())
+                        && a.offset.abs_diff_eq(&b.offset, epsilon)
+                }
+                (a, b) => a.is_none() && b.is_none(),
+            }
+    }
+
+    fn base_relative_eq(
+        &self,
+        other: &Self,
+        epsilon: <Sd::Elem as approx::AbsDiffEq>::Epsilon,
+        max_relative: <Sd::Elem as approx::AbsDiffEq>::Epsilon,
+    ) -> bool {
+        use approx::RelativeEq;
+        self.closed_upper == other.closed_upper
+            && self
+                .x
+                .relative_eq(&other.x, epsilon.clone(), max_relative.clone())
+            && self
+                .data
+                .relative_eq(&other.data, epsilon.clone(), max_relative.clone())
+            && self.gaps.len() == other.gaps.len()
+            && self.gaps.iter().zip(&other.gaps).all(|(a, b)| {
+                a.0.relative_eq(&b.0, epsilon.clone(), max_relative.clone())
+                    && a.1.relative_eq(&b.1, epsilon.clone(), max_relative.clone())
+            })
+            && match (&self.output_transform, &other.output_transform) {
+                (Some(a), Some(b)) => {
+                    a.scale
+                        .relative_eq(&b.scale, epsilon.clone(), max_relative.clone())
+                        && a.offset.relative_eq(&b.offset, epsilon, max_relative)
+                }
+                (a, b) => a.is_none() && b.is_none(),
+            }
     }
 }
 
@@ -405,6 +1377,119 @@ where
             })),
             data,
             strategy: Linear::new(),
+            gaps: Vec::new(),
+            max_gap: None,
+            min_segment_width: None,
+            closed_upper: true,
+            output_transform: None,
+            masked: Vec::new(),
+        }
+    }
+}
+
+impl<T> Interp1DBuilder<OwnedRepr<T>, OwnedRepr<T>, Ix1, Linear>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug,
+{
+    /// Create a new [Interp1DBuilder] from a map of `x -> y`.
+    ///
+    /// The keys of a [`BTreeMap`] are already sorted, so they are used as x axis as they are.
+    /// Keys can be of any type which converts into the data type, e.g. a totally ordered
+    /// float wrapper.
+    ///
+    /// ```rust
+    /// # use std::collections::BTreeMap;
+    /// # use ndarray_interp::interp1d::*;
+    /// let map = BTreeMap::from([(0, 0.0), (2, 1.0), (5, 4.0)]);
+    /// let interp = Interp1DBuilder::from_sorted_map(map).build().unwrap();
+    /// assert_eq!(interp.interp_scalar(1.0).unwrap(), 0.5);
+    /// ```
+    pub fn from_sorted_map<K>(map: BTreeMap<K, T>) -> Self
+    where
+        K: Into<T>,
+    {
+        let (x, data): (Vec<T>, Vec<T>) = map.into_iter().map(|(x, y)| (x.into(), y)).unzip();
+        Interp1DBuilder {
+            x: Array::from_vec(x),
+            data: Array::from_vec(data),
+            strategy: Linear::new(),
+            gaps: Vec::new(),
+            max_gap: None,
+            min_segment_width: None,
+            closed_upper: true,
+            output_transform: None,
+            masked: Vec::new(),
+        }
+    }
+
+    /// Create a new [Interp1DBuilder] from a unsorted map of `x -> y`.
+    ///
+    /// The entries are sorted by x, see also [`from_sorted_map`](Interp1DBuilder::from_sorted_map).
+    pub fn from_map<K, S>(map: HashMap<K, T, S>) -> Self
+    where
+        K: Into<T>,
+    {
+        let mut points: Vec<(T, T)> = map.into_iter().map(|(x, y)| (x.into(), y)).collect();
+        points.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let (x, data): (Vec<T>, Vec<T>) = points.into_iter().unzip();
+        Interp1DBuilder {
+            x: Array::from_vec(x),
+            data: Array::from_vec(data),
+            strategy: Linear::new(),
+            gaps: Vec::new(),
+            max_gap: None,
+            min_segment_width: None,
+            closed_upper: true,
+            output_transform: None,
+            masked: Vec::new(),
+        }
+    }
+}
+
+impl<Sd, Sx, D> Interp1DBuilder<Sd, Sx, D, Linear>
+where
+    Sd: Data,
+    Sd::Elem: Debug,
+    Sx: Data,
+    Sx::Elem: Num + Debug,
+    D: Dimension,
+{
+    /// Create a new [Interp1DBuilder] and provide the data and the x axis.
+    ///
+    /// Unlike [`new`](Interp1DBuilder::new) this does not need to derive the x axis
+    /// from the data type, so the data only needs to support the arithmetic of the
+    /// chosen strategy. This allows interpolating e.g. unit-typed data with a numeric x axis.
+    ///
+    /// The arrays are kept as they are given, owned arrays, views or [`CowArray`](ndarray::CowArray)s.
+    /// A [`CowArray`](ndarray::CowArray) is not copied when it borrows, e.g. to accept both
+    /// at a library boundary, see [`Interp1DCow`].
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// fn interpolator<'a>(
+    ///     data: CowArray<'a, f64, Ix1>,
+    ///     x: CowArray<'a, f64, Ix1>,
+    /// ) -> Interp1DCow<'a, f64, Ix1, Linear> {
+    ///     Interp1DBuilder::new_with_x(data, x).build().unwrap()
+    /// }
+    ///
+    /// let x = array![0.0, 1.0, 2.0];
+    /// let borrowed = interpolator(array![0.0, 2.0, 1.0].into(), x.view().into());
+    /// assert_eq!(borrowed.interp_scalar(0.5).unwrap(), 1.0);
+    /// assert_eq!(borrowed.x().as_ptr(), x.as_ptr());
+    /// ```
+    pub fn new_with_x(data: ArrayBase<Sd, D>, x: ArrayBase<Sx, Ix1>) -> Self {
+        Interp1DBuilder {
+            x,
+            data,
+            strategy: Linear::new(),
+            gaps: Vec::new(),
+            max_gap: None,
+            min_segment_width: None,
+            closed_upper: true,
+            output_transform: None,
+            masked: Vec::new(),
         }
     }
 }
@@ -412,38 +1497,527 @@ where
 impl<Sd, Sx, D, Strat> Interp1DBuilder<Sd, Sx, D, Strat>
 where
     Sd: Data,
-    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+{
+    /// Set the interpolation strategy by providing a [Interp1DStrategyBuilder].
+    /// By default [Linear] with `Linear{extrapolate: false}` is used.
+    ///
+    /// The current strategy does not need to support the data,
+    /// e.g. [`Nearest`] can replace [Linear] for data over an integer x axis.
+    pub fn strategy<NewStrat>(self, strategy: NewStrat) -> Interp1DBuilder<Sd, Sx, D, NewStrat>
+    where
+        NewStrat: Interp1DStrategyBuilder<Sd, Sx, D>,
+    {
+        let Interp1DBuilder {
+            x,
+            data,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+            ..
+        } = self;
+        Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        }
+    }
+}
+
+impl<Sd, Sx, D, Strat> Interp1DBuilder<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
     D: Dimension + RemoveAxis,
     Strat: Interp1DStrategyBuilder<Sd, Sx, D>,
 {
     /// Add an custom x axis for the data. The axis needs to have the same lenght
-    /// and store the same Type as the data. `x`  must be strict monotonic rising.
+    /// as the data and store the same Type as the current x axis. `x`  must be strict monotonic rising.
     /// If the x axis is not set the index `0..data.len() - 1` is used
+    ///
+    /// After [`mask`](Interp1DBuilder::mask) or [`close_period`](Interp1DBuilder::close_period)
+    /// the x axis describes the knots which are left, respectively appended.
     pub fn x<NewSx>(self, x: ArrayBase<NewSx, Ix1>) -> Interp1DBuilder<Sd, NewSx, D, Strat>
     where
-        NewSx: Data<Elem = Sd::Elem>,
+        NewSx: Data<Elem = Sx::Elem>,
     {
-        let Interp1DBuilder { data, strategy, .. } = self;
-        Interp1DBuilder { x, data, strategy }
+        let Interp1DBuilder {
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+            ..
+        } = self;
+        Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        }
     }
 
-    /// Set the interpolation strategy by providing a [Interp1DStrategyBuilder].
-    /// By default [Linear] with `Linear{extrapolate: false}` is used.
-    pub fn strategy<NewStrat>(self, strategy: NewStrat) -> Interp1DBuilder<Sd, Sx, D, NewStrat>
+    /// Mark which knots are valid. Invalid knots are removed together with their x value
+    /// before the interpolator is built, so the interpolant ignores them.
+    ///
+    /// The data and x axis are copied into owned arrays. The gaps are taken from the
+    /// x axis when the interpolator is built, an x axis which is set afterwards
+    /// with [`x`](Interp1DBuilder::x) has one value for each valid knot.
+    /// Use [`max_gap`](Interp1DBuilder::max_gap) to reject queries in wide gaps.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let data = array![0.0, 1.0, 100.0, 3.0];
+    /// let interp = Interp1DBuilder::new(data)
+    ///     .mask(array![true, true, false, true])
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(interp.interp_scalar(2.0).unwrap(), 2.0);
+    /// ```
+    ///
+    /// # Errors
+    /// When the lenght of the mask does not match the lenght of the data
+    #[allow(clippy::type_complexity)]
+    pub fn mask(
+        self,
+        mask: Array1<bool>,
+    ) -> Result<Interp1DBuilder<OwnedRepr<Sd::Elem>, OwnedRepr<Sx::Elem>, D, Strat>, BuilderError>
     where
-        NewStrat: Interp1DStrategyBuilder<Sd, Sx, D>,
+        Sd::Elem: Clone,
     {
-        let Interp1DBuilder { x, data, .. } = self;
-        Interp1DBuilder { x, data, strategy }
+        let Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        } = self;
+        if mask.len() != x.len() || mask.len() != data.shape()[0] {
+            return Err(BuilderError::ShapeError(format!(
+                "Lengths of mask, x and data axis need to match. Got mask: {:}, x: {:}, data: {:}",
+                mask.len(),
+                x.len(),
+                data.shape()[0],
+            )));
+        }
+
+        let valid: Vec<usize> = mask
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, &valid)| valid.then_some(idx))
+            .collect();
+        // segments masked before stay masked when both of their knots are kept,
+        // otherwise they are inside of a new masked segment
+        let masked = valid
+            .windows(2)
+            .enumerate()
+            .filter(|(_, idx)| idx[1] - idx[0] > 1 || masked.contains(&idx[0]))
+            .map(|(segment, _)| segment)
+            .collect();
+
+        Ok(Interp1DBuilder {
+            x: x.select(Axis(0), &valid),
+            data: data.select(Axis(0), &valid),
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        })
+    }
+
+    /// Append the first knot one `period` after the first x value, so data which covers
+    /// one period without the repeated endpoint can be used for a
+    /// [`Periodic`](cubic_spline::BoundaryCondition::Periodic) spline.
+    ///
+    /// The data and x axis are copied into owned arrays. An x axis which is set afterwards
+    /// with [`x`](Interp1DBuilder::x) has to include the appended knot.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// // four samples of one period, without the repeated endpoint at x = 4
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 0.0, -1.0])
+    ///     .close_period(4.0)
+    ///     .unwrap()
+    ///     .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(interp.x(), array![0.0, 1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(interp.interp_scalar(4.0).unwrap(), 0.0);
+    /// ```
+    ///
+    /// # Errors
+    ///  - When the data is empty
+    ///  - When `period` is not larger than the x range
+    #[allow(clippy::type_complexity)]
+    pub fn close_period(
+        self,
+        period: Sx::Elem,
+    ) -> Result<Interp1DBuilder<OwnedRepr<Sd::Elem>, OwnedRepr<Sx::Elem>, D, Strat>, BuilderError>
+    where
+        Sd::Elem: Clone,
+    {
+        let Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        } = self;
+        if x.is_empty() || data.shape()[0] == 0 {
+            return Err(BuilderError::ShapeError(
+                "can not close the period of empty data".into(),
+            ));
+        }
+        let (first, last) = (x[0], x[x.len() - 1]);
+        if last - first >= period {
+            return Err(BuilderError::ValueError(format!(
+                "period {period:?} needs to be larger than the x range [{first:?}, {last:?}]"
+            )));
+        }
+
+        let first_row = data.slice_axis(Axis(0), Slice::from(..1));
+        let data = ndarray::concatenate(Axis(0), &[data.view(), first_row])
+            .unwrap_or_else(|_| unreachable!());
+        let x = x.iter().copied().chain([first + period]).collect();
+
+        Ok(Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        })
+    }
+
+    /// Choose how `NaN` values in the data are handled, see [`NanPolicy`].
+    ///
+    /// Without this method `NaN` values are kept like [`NanPolicy::Propagate`].
+    /// The data is copied into an owned array.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let data = array![[0.0, 1.0, 2.0], [1.0, f64::NAN, 3.0], [2.0, 1.0, 4.0]];
+    ///
+    /// let builder = Interp1DBuilder::new(data.view()).nan_policy(NanPolicy::Error);
+    /// assert!(matches!(builder, Err(BuilderError::ValueError(_))));
+    ///
+    /// let interp = Interp1DBuilder::new(data)
+    ///     .nan_policy(NanPolicy::SkipSeries)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// // the second column is removed
+    /// assert_eq!(interp.interp(0.5).unwrap(), array![0.5, 2.5]);
+    /// ```
+    ///
+    /// # Errors
+    ///  - [`NanPolicy::Error`] when the data contains `NaN`
+    ///  - [`NanPolicy::SkipSeries`] when one dimensional data contains `NaN`,
+    ///    its single series can not be removed
+    #[allow(clippy::type_complexity)]
+    pub fn nan_policy(
+        self,
+        policy: NanPolicy,
+    ) -> Result<Interp1DBuilder<OwnedRepr<Sd::Elem>, Sx, D, Strat>, BuilderError>
+    where
+        Sd::Elem: Clone + PartialEq,
+    {
+        let Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        } = self;
+        // NaN is the only value which is not equal to itself, this includes complex numbers
+        #[allow(clippy::eq_op)]
+        let is_nan = |value: &Sd::Elem| value != value;
+
+        let data = match policy {
+            NanPolicy::SkipSeries if data.ndim() >= 2 => {
+                let last = Axis(data.ndim() - 1);
+                let keep: Vec<usize> = (0..data.len_of(last))
+                    .filter(|&idx| !data.index_axis(last, idx).iter().any(is_nan))
+                    .collect();
+                data.select(last, &keep)
+            }
+            NanPolicy::Error | NanPolicy::SkipSeries => {
+                if let Some((idx, _)) = data.indexed_iter().find(|(_, value)| is_nan(value)) {
+                    return Err(BuilderError::ValueError(format!(
+                        "the data contains NaN at index {idx:?}"
+                    )));
+                }
+                data.to_owned()
+            }
+            NanPolicy::Propagate => data.to_owned(),
+        };
+
+        Ok(Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width,
+            closed_upper,
+            output_transform,
+            masked,
+        })
+    }
+
+    /// Apply the affine transform `scale * y + offset` to the interpolated values,
+    /// e.g. for the calibration of a sensor.
+    ///
+    /// The data is kept as it is and the transform is applied to the results of
+    /// the `interp*` methods of [`Interp1D`], including extrapolated values.
+    /// This is correct for every strategy, also for those which are not linear in
+    /// the data like [`Angular`].
+    /// [`Interp1D::data`], [`write_binary`](Interp1D::write_binary) and the analysis methods of the
+    /// strategies, like derivatives, integrals or zeros, refer to the raw data.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let raw = array![0.0, 1.0, 3.0];
+    /// let interp = Interp1DBuilder::new(raw.view())
+    ///     .output_transform(2.0, 10.0)
+    ///     .build()
+    ///     .unwrap();
+    /// // the raw interpolant is 2 at 1.5
+    /// assert_eq!(interp.interp_scalar(1.5).unwrap(), 14.0);
+    /// assert_eq!(interp.data(), raw);
+    /// ```
+    pub fn output_transform(mut self, scale: Sd::Elem, offset: Sd::Elem) -> Self
+    where
+        Sd::Elem: Num + Copy,
+    {
+        self.output_transform = Some(OutputTransform::new(scale, offset));
+        self
+    }
+
+    /// The widest gap left by masked out knots which is still interpolated.
+    /// Queries inside wider gaps return [`InterpolateError::OutOfBounds`].
+    /// By default all gaps are interpolated.
+    pub fn max_gap(mut self, max_gap: Sx::Elem) -> Self {
+        self.max_gap = Some(max_gap);
+        self
+    }
+
+    /// The narrowest segment between two neighbouring knots which is accepted by
+    /// [`build`](Interp1DBuilder::build).
+    ///
+    /// Nearly coincident knots make the division by the segment width blow up.
+    /// By default segments narrower than `16 * f64::EPSILON` times the x range are rejected.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let builder = Interp1DBuilder::new(array![0.0, 1.0, 2.0])
+    ///     .x(array![0.0, 1.0, 1.001])
+    ///     .min_segment_width(0.01);
+    /// assert!(matches!(builder.build(), Err(BuilderError::ValueError(_))));
+    /// ```
+    pub fn min_segment_width(mut self, min_segment_width: Sx::Elem) -> Self {
+        self.min_segment_width = Some(min_segment_width);
+        self
+    }
+
+    /// Is the last knot part of the range of the interpolator. Default is `true`.
+    ///
+    /// With a closed upper bound the range is `[x[0], x[last]]`, a query exactly at the
+    /// last knot is the right end of the last segment.
+    /// With an open upper bound the range is `[x[0], x[last])`, as for a half-open
+    /// bin, and a query at the last knot is out of range. It is still extrapolated
+    /// when the strategy is configured to do so.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 3.0])
+    ///     .closed_upper(false)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(matches!(interp.interp_scalar(2.0), Err(InterpolateError::OutOfBounds(_))));
+    /// assert_eq!(interp.interp_scalar(1.5).unwrap(), 2.0);
+    /// ```
+    pub fn closed_upper(mut self, closed_upper: bool) -> Self {
+        self.closed_upper = closed_upper;
+        self
     }
 
     /// Validate input data and create the configured [Interp1D]
     pub fn build(self) -> Result<Interp1D<Sd, Sx, D, Strat::FinishedStrat>, BuilderError> {
+        self.validate()?;
+        self.finish()
+    }
+
+    /// Same as [`build`](Interp1DBuilder::build), but the workspace of the strategy
+    /// build is borrowed from `scratch`, see [`Scratch`].
+    ///
+    /// Reusing the scratch for repeated builds, e.g. in a loop which refits a
+    /// [`CubicSpline`](cubic_spline::CubicSpline) to new data, avoids allocating the
+    /// memory for the linear solve on each build. Only the solver workspace is reused,
+    /// the build still allocates:
+    ///  - the arrays owned by the interpolator, like the spline coefficients
+    ///    and the flags of [`limit_monotone`](cubic_spline::CubicSpline::limit_monotone)
+    ///  - the x axis relative to the first knot, which the spline is built on
+    ///  - the temporaries of [`derivative_smoothing`](cubic_spline::CubicSpline::derivative_smoothing)
+    ///    and of [`Periodic`](cubic_spline::BoundaryCondition::Periodic) boundaries
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray_interp::linalg::Scratch;
+    /// # use ndarray::*;
+    /// let mut scratch = Scratch::new();
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 0.0, 1.0])
+    ///     .strategy(CubicSpline::new())
+    ///     .build_in(&mut scratch)
+    ///     .unwrap();
+    /// let bytes = scratch.memory_bytes();
+    /// let interp = Interp1DBuilder::new(array![1.0, 0.0, 1.0, 0.0])
+    ///     .strategy(CubicSpline::new())
+    ///     .build_in(&mut scratch)
+    ///     .unwrap();
+    /// assert_eq!(scratch.memory_bytes(), bytes);
+    /// # assert_eq!(interp.interp_scalar(1.0).unwrap(), 0.0);
+    /// ```
+    pub fn build_in(
+        self,
+        scratch: &mut Scratch<Sx::Elem, Sd::Elem>,
+    ) -> Result<Interp1D<Sd, Sx, D, Strat::FinishedStrat>, BuilderError> {
+        self.validate()?;
+        self.finish_in(scratch)
+    }
+
+    /// Same as [`build`](Interp1DBuilder::build), but also measures how long
+    /// the validation of the x axis and the strategy build take.
+    ///
+    /// Only this method reads the clock, the other builds do not measure time.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let (interp, timing) = Interp1DBuilder::new(array![0.0, 1.0, 3.0])
+    ///     .build_timed()
+    ///     .unwrap();
+    /// println!("monotonic check: {:?}", timing.monotonic_check);
+    /// println!("strategy build: {:?}", timing.strategy_build);
+    /// # assert_eq!(interp.interp_scalar(0.5).unwrap(), 0.5);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn build_timed(
+        self,
+    ) -> Result<(Interp1D<Sd, Sx, D, Strat::FinishedStrat>, BuildTiming), BuilderError> {
+        let mut monotonic_check = Duration::ZERO;
+        self.validate_with(|x| {
+            let start = Instant::now();
+            let monotonic = x.monotonic_prop();
+            monotonic_check = start.elapsed();
+            monotonic
+        })?;
+        let mut strategy_build = Duration::ZERO;
+        let interp = self.finish_with(|strategy, x, data| {
+            let start = Instant::now();
+            let strategy = strategy.build(x, data);
+            strategy_build = start.elapsed();
+            strategy
+        })?;
+        let timing = BuildTiming {
+            monotonic_check,
+            strategy_build,
+        };
+        Ok((interp, timing))
+    }
+
+    /// Validate input data and create a [`LazyInterp1D`] which builds the
+    /// strategy on the first access.
+    ///
+    /// The data is validated immediately, only the strategy build (e.g. solving
+    /// for spline coefficients) is deferred. Errors of the strategy build
+    /// are returned by [`LazyInterp1D::get`].
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let lazy = Interp1DBuilder::new(array![0.0, 1.0, 3.0])
+    ///     .strategy(cubic_spline::CubicSpline::new())
+    ///     .build_lazy()
+    ///     .unwrap();
+    /// assert!(!lazy.is_built());
+    /// let interp = lazy.get().unwrap();
+    /// # assert_eq!(interp.interp_scalar(1.0).unwrap(), 1.0);
+    /// assert!(lazy.is_built());
+    /// ```
+    pub fn build_lazy(self) -> Result<LazyInterp1D<Sd, Sx, D, Strat>, BuilderError> {
+        self.validate()?;
+        Ok(LazyInterp1D::new(self))
+    }
+
+    /// check the input data
+    fn validate(&self) -> Result<(), BuilderError> {
+        self.validate_with(|x| x.monotonic_prop())
+    }
+
+    /// check the input data, `monotonic_prop` checks the x axis
+    fn validate_with<F>(&self, monotonic_prop: F) -> Result<(), BuilderError>
+    where
+        F: FnOnce(&ArrayBase<Sx, Ix1>) -> Monotonic,
+    {
         use self::Monotonic::*;
         use BuilderError::*;
 
-        let Interp1DBuilder { x, data, strategy } = self;
+        let Interp1DBuilder { x, data, .. } = self;
 
         if data.ndim() < 1 {
             return Err(ShapeError(
@@ -456,7 +2030,8 @@ where
                 Strat::MINIMUM_DATA_LENGHT
             )));
         }
-        if !matches!(x.monotonic_prop(), Rising { strict: true }) {
+        let monotonic = monotonic_prop(x);
+        if !matches!(monotonic, Rising { strict: true }) {
             return Err(Monotonic(
                 "Values in the x axis need to be strictly monotonic rising".into(),
             ));
@@ -468,10 +2043,99 @@ where
                 data.shape()[0],
             )));
         }
+        let min_width = self.min_segment_width.unwrap_or_else(|| {
+            let (first, last) = (x[0], x[x.len() - 1]);
+            (last - first) * cast(16.0 * f64::EPSILON).unwrap_or_else(Sx::Elem::zero)
+        });
+        if let Some((idx, x)) = x
+            .windows(2)
+            .into_iter()
+            .enumerate()
+            .find(|(_, x)| x[1] - x[0] < min_width)
+        {
+            return Err(ValueError(format!(
+                "segment {idx} between x = {:?} and {:?} is narrower than the minimum segment width {min_width:?}",
+                x[0], x[1]
+            )));
+        }
+        Ok(())
+    }
+
+    /// build the strategy of the validated data
+    fn finish(self) -> Result<Interp1D<Sd, Sx, D, Strat::FinishedStrat>, BuilderError> {
+        self.finish_with(|strategy, x, data| strategy.build(x, data))
+    }
+
+    /// [`finish`](Interp1DBuilder::finish) with the workspace of the strategy build borrowed from `scratch`
+    fn finish_in(
+        self,
+        scratch: &mut Scratch<Sx::Elem, Sd::Elem>,
+    ) -> Result<Interp1D<Sd, Sx, D, Strat::FinishedStrat>, BuilderError> {
+        self.finish_with(|strategy, x, data| strategy.build_in(x, data, scratch))
+    }
 
-        let strategy = strategy.build(&x, &data)?;
+    /// [`finish`](Interp1DBuilder::finish) with `build` building the strategy
+    fn finish_with<F>(
+        self,
+        build: F,
+    ) -> Result<Interp1D<Sd, Sx, D, Strat::FinishedStrat>, BuilderError>
+    where
+        F: FnOnce(
+            Strat,
+            &ArrayBase<Sx, Ix1>,
+            &ArrayBase<Sd, D>,
+        ) -> Result<Strat::FinishedStrat, BuilderError>,
+    {
+        let Interp1DBuilder {
+            x,
+            data,
+            strategy,
+            gaps,
+            max_gap,
+            min_segment_width: _,
+            closed_upper,
+            output_transform,
+            masked,
+        } = self;
 
-        Ok(Interp1D { x, data, strategy })
+        let strategy = build(strategy, &x, &data)?;
+
+        let mut gaps = match max_gap {
+            Some(max_gap) => gaps
+                .into_iter()
+                .chain(masked.into_iter().map(|idx| (x[idx], x[idx + 1])))
+                .filter(|&(left, right)| right - left > max_gap)
+                .collect(),
+            None => Vec::new(),
+        };
+        // sorted and without overlaps, so `check_gaps` can search them
+        gaps.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        let gaps = gaps.into_iter().fold(
+            Vec::<(Sx::Elem, Sx::Elem)>::new(),
+            |mut merged, (left, right)| {
+                match merged.last_mut() {
+                    Some(last) if left < last.1 => {
+                        if right > last.1 {
+                            last.1 = right;
+                        }
+                    }
+                    _ => merged.push((left, right)),
+                }
+                merged
+            },
+        );
+
+        let mut interp = Interp1D {
+            x,
+            data,
+            strategy,
+            gaps,
+            closed_upper,
+            output_transform,
+            data_monotonic: OnceLock::new(),
+        };
+        interp.strategy.set_range(&interp.gaps, closed_upper);
+        Ok(interp)
     }
 }
 
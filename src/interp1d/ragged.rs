@@ -0,0 +1,53 @@
+use std::fmt::Debug;
+
+use ndarray::{Array, Array1, Array2, Axis, Ix1, ViewRepr};
+use num_traits::{Num, NumCast};
+
+use crate::InterpolateError;
+
+use super::{Interp1DBuilder, Interp1DStrategyBuilder};
+
+/// Resample curves of different lenghts onto a common x axis and stack the results.
+///
+/// Every curve is given as `(x, data)` and gets its own interpolator built with
+/// a clone of `strategy`. Row `i` of the result is curve `i` interpolated at `out_x`.
+///
+/// Queries outside of the range of a curve are handled by the shared `strategy`:
+/// they are extrapolated when it is configured to extrapolate, otherwise
+/// [`InterpolateError::OutOfBounds`] is returned.
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// let curves = [
+///     (array![0.0, 1.0, 2.0], array![0.0, 1.0, 4.0]),
+///     (array![0.0, 2.0], array![1.0, 3.0]),
+/// ];
+/// let out_x = array![0.5, 1.5];
+/// let resampled = interp_ragged(&curves, &out_x, Linear::new()).unwrap();
+/// assert_eq!(resampled, array![[0.5, 2.5], [1.5, 2.5]]);
+/// ```
+///
+/// # Errors
+///  - [`InterpolateError::Build`] when a curve can not be built, see [`Interp1DBuilder::build`]
+///  - [`InterpolateError::OutOfBounds`] when `out_x` is outside of the range of a curve
+///    and the strategy does not extrapolate
+pub fn interp_ragged<'a, T, Strat>(
+    curves: &'a [(Array1<T>, Array1<T>)],
+    out_x: &Array1<T>,
+    strategy: Strat,
+) -> Result<Array2<T>, InterpolateError>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    Strat: Interp1DStrategyBuilder<ViewRepr<&'a T>, ViewRepr<&'a T>, Ix1> + Clone,
+{
+    let mut resampled = Array::zeros((curves.len(), out_x.len()));
+    for ((x, data), row) in curves.iter().zip(resampled.axis_iter_mut(Axis(0))) {
+        Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(strategy.clone())
+            .build()?
+            .interp_array_into(out_x, row)?;
+    }
+    Ok(resampled)
+}
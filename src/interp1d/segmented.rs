@@ -0,0 +1,245 @@
+use std::fmt::Debug;
+
+use ndarray::{Array, Array1, ArrayBase, Data, Dimension, Ix1, OwnedRepr};
+use num_traits::{Num, NumCast};
+
+use crate::{
+    vector_extensions::{Monotonic, VectorExtensions},
+    BuilderError, InterpolateError,
+};
+
+use super::{Interp1DBuilder, Interp1DScalar, Interp1DStrategy, Interp1DStrategyBuilder, Linear};
+
+/// One dimensional interpolator for data with gaps
+///
+/// Each contiguous run of valid knots is interpolated by its own [`Interp1D`](super::Interp1D).
+/// Queries inside a gap return [`InterpolateError::OutOfBounds`],
+/// queries left of the first or right of the last segment are passed to that segment,
+/// so they are extrapolated when its strategy is configured to do so.
+/// A run of a single valid knot only returns its value exactly at its x,
+/// it is never extrapolated and the outer segments are not extrapolated past it.
+///
+/// This is created by [`Interp1DBuilder::new_with_gaps`].
+#[derive(Debug)]
+pub struct SegmentedInterp1D<T, Strat>
+where
+    T: Num + Debug + Send,
+    Strat: Interp1DStrategy<OwnedRepr<T>, OwnedRepr<T>, Ix1>,
+{
+    /// segments are sorted by x and do not overlap
+    segments: Vec<Interp1DScalar<T, Strat>>,
+    /// runs of a single knot as `(x, data)`, sorted by x
+    isolated: Vec<(T, T)>,
+}
+
+/// Create and configure a [`SegmentedInterp1D`]
+#[derive(Debug)]
+pub struct SegmentedInterp1DBuilder<T, StratBuilder> {
+    x: Array1<T>,
+    data: Array1<Option<T>>,
+    strategy: StratBuilder,
+}
+
+impl<T> Interp1DBuilder<OwnedRepr<T>, OwnedRepr<T>, Ix1, Linear>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+{
+    /// Create a new [`SegmentedInterp1DBuilder`] for data with gaps.
+    ///
+    /// Knots where the data is `None` are excluded, each contiguous run of `Some` values
+    /// becomes a separate interpolator. Unlike [`mask`](Interp1DBuilder::mask) the gaps
+    /// are never interpolated.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let x = array![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let data = array![Some(0.0), Some(1.0), None, None, Some(4.0), Some(5.0)];
+    /// let interp = Interp1DBuilder::new_with_gaps(x, data).build().unwrap();
+    ///
+    /// assert_eq!(interp.interp_scalar(0.5).unwrap(), 0.5);
+    /// assert!(matches!(interp.interp_scalar(2.5), Err(InterpolateError::OutOfBounds(_))));
+    /// assert_eq!(interp.interp_scalar(4.5).unwrap(), 4.5);
+    /// ```
+    pub fn new_with_gaps(
+        x: Array1<T>,
+        data: Array1<Option<T>>,
+    ) -> SegmentedInterp1DBuilder<T, Linear> {
+        SegmentedInterp1DBuilder {
+            x,
+            data,
+            strategy: Linear::new(),
+        }
+    }
+}
+
+impl<T, StratBuilder> SegmentedInterp1DBuilder<T, StratBuilder>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    StratBuilder: Interp1DStrategyBuilder<OwnedRepr<T>, OwnedRepr<T>, Ix1> + Clone,
+{
+    /// Set the interpolation strategy used for each segment
+    pub fn strategy<NewStrat>(self, strategy: NewStrat) -> SegmentedInterp1DBuilder<T, NewStrat>
+    where
+        NewStrat: Interp1DStrategyBuilder<OwnedRepr<T>, OwnedRepr<T>, Ix1> + Clone,
+    {
+        let SegmentedInterp1DBuilder { x, data, .. } = self;
+        SegmentedInterp1DBuilder { x, data, strategy }
+    }
+
+    /// Validate input data and create the configured [`SegmentedInterp1D`]
+    ///
+    /// # Errors
+    ///  - When the lengths of x and data do not match
+    ///  - When the x axis is not strictly monotonic rising
+    ///  - When there is no valid data, or a segment with more than one knot
+    ///    is too short for the strategy
+    pub fn build(self) -> Result<SegmentedInterp1D<T, StratBuilder::FinishedStrat>, BuilderError> {
+        let SegmentedInterp1DBuilder { x, data, strategy } = self;
+        if x.len() != data.len() {
+            return Err(BuilderError::ShapeError(format!(
+                "Lengths of x and data axis need to match. Got x: {:}, data: {:}",
+                x.len(),
+                data.len(),
+            )));
+        }
+        if !matches!(x.monotonic_prop(), Monotonic::Rising { strict: true }) {
+            return Err(BuilderError::Monotonic(
+                "Values in the x axis need to be strictly monotonic rising".into(),
+            ));
+        }
+
+        let mut interp = SegmentedInterp1D {
+            segments: Vec::new(),
+            isolated: Vec::new(),
+        };
+        let mut run_x = Vec::new();
+        let mut run_data = Vec::new();
+        for (&x, y) in x.iter().zip(data.iter()) {
+            if let Some(y) = y {
+                run_x.push(x);
+                run_data.push(*y);
+                continue;
+            }
+            Self::finish_run(&strategy, &mut run_x, &mut run_data, &mut interp)?;
+        }
+        Self::finish_run(&strategy, &mut run_x, &mut run_data, &mut interp)?;
+        if interp.segments.is_empty() && interp.isolated.is_empty() {
+            return Err(BuilderError::NotEnoughData(
+                "all data values are None".into(),
+            ));
+        }
+        Ok(interp)
+    }
+
+    /// add the run of valid knots to `interp` as a segment or an isolated knot,
+    /// the run is left empty
+    fn finish_run(
+        strategy: &StratBuilder,
+        x: &mut Vec<T>,
+        data: &mut Vec<T>,
+        interp: &mut SegmentedInterp1D<T, StratBuilder::FinishedStrat>,
+    ) -> Result<(), BuilderError> {
+        match x.len() {
+            0 => {}
+            1 => interp.isolated.push((x[0], data[0])),
+            _ => interp.segments.push(
+                Interp1DBuilder::new(Array::from_vec(std::mem::take(data)))
+                    .x(Array::from_vec(std::mem::take(x)))
+                    .strategy(strategy.clone())
+                    .build()?,
+            ),
+        }
+        x.clear();
+        data.clear();
+        Ok(())
+    }
+}
+
+impl<T, Strat> SegmentedInterp1D<T, Strat>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    Strat: Interp1DStrategy<OwnedRepr<T>, OwnedRepr<T>, Ix1>,
+{
+    /// Calculate the interpolated value at `x`
+    ///
+    /// # Errors
+    /// When `x` is inside a gap, or when it is out of bounds of the
+    /// first or last segment and the strategy does not extrapolate.
+    /// Outside of the segments only the x of an isolated knot is valid.
+    pub fn interp_scalar(&self, x: T) -> Result<T, InterpolateError> {
+        let idx = self.isolated.partition_point(|&(knot, _)| knot < x);
+        match self.isolated.get(idx) {
+            Some(&(knot, y)) if knot == x => Ok(y),
+            _ => self.segment_at(x)?.interp_scalar(x),
+        }
+    }
+
+    /// Calculate the interpolated values at all points in `xs`
+    pub fn interp_array<Sq, Dq>(
+        &self,
+        xs: &ArrayBase<Sq, Dq>,
+    ) -> Result<Array<T, Dq>, InterpolateError>
+    where
+        Sq: Data<Elem = T>,
+        Dq: Dimension,
+    {
+        let mut ys = Array::zeros(xs.raw_dim());
+        for (y, &x) in ys.iter_mut().zip(xs.iter()) {
+            *y = self.interp_scalar(x)?;
+        }
+        Ok(ys)
+    }
+
+    /// The interpolators of the individual segments, sorted by x.
+    /// Runs of a single knot are not included, see [`isolated_knots`](Self::isolated_knots)
+    pub fn segments(&self) -> &[Interp1DScalar<T, Strat>] {
+        &self.segments
+    }
+
+    /// The runs of a single valid knot as `(x, data)`, sorted by x
+    pub fn isolated_knots(&self) -> &[(T, T)] {
+        &self.isolated
+    }
+
+    /// the segment which is responsible for `x`
+    fn segment_at(&self, x: T) -> Result<&Interp1DScalar<T, Strat>, InterpolateError> {
+        let outside = || {
+            InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is not in a segment and not at an isolated knot"
+            ))
+        };
+        let idx = self
+            .segments
+            .partition_point(|segment| segment.x[segment.x.len() - 1] < x);
+        let segment = match self.segments.get(idx) {
+            Some(segment) => segment,
+            None => {
+                // right of the last segment, unless an isolated knot is in between
+                let segment = self.segments.last().ok_or_else(outside)?;
+                let end = segment.x[segment.x.len() - 1];
+                if self.isolated.iter().any(|&(knot, _)| knot > end) {
+                    return Err(outside());
+                }
+                return Ok(segment);
+            }
+        };
+        // left of the first segment, unless an isolated knot is in between
+        if idx == 0
+            && x < segment.x[0]
+            && self.isolated.iter().any(|&(knot, _)| knot < segment.x[0])
+        {
+            return Err(outside());
+        }
+        if idx > 0 && x < segment.x[0] {
+            let left = &self.segments[idx - 1].x;
+            return Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is in the gap between {:#?} and {:#?}",
+                left[left.len() - 1],
+                segment.x[0]
+            )));
+        }
+        Ok(segment)
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+
+use ndarray::{ArrayBase, ArrayViewMut, Data, Dimension, Ix1, RemoveAxis, Zip};
+use num_traits::{cast, Euclid, Num, NumCast, Zero};
+
+use crate::{
+    interp1d::{ExtrapolateMode, Interp1D},
+    BuilderError, InterpolateError,
+};
+
+use super::{describe_extrapolation, Interp1DStrategy, Interp1DStrategyBuilder};
+
+/// Linear Interpolation Strategy for angles, which wrap around after a period
+///
+/// Each segment is interpolated along the shorter way around the circle,
+/// e.g. from 350° to 10° through 0° instead of backwards through 180°.
+/// The data does not need to be inside `[0, period)`, the results always are.
+/// When two knots are exactly half a period apart, the interpolation goes forward.
+///
+/// The default period is `2π`, use [`period`](Angular::period) e.g. for degrees.
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// # use approx::*;
+/// let heading = array![350.0, 10.0, 40.0];
+/// let interp = Interp1DBuilder::new(heading)
+///     .strategy(Angular::new().period(360.0))
+///     .build()
+///     .unwrap();
+///
+/// # assert_abs_diff_eq!(interp.interp_scalar(0.25).unwrap(), 355.0, epsilon=1e-12);
+/// # assert_abs_diff_eq!(interp.interp_scalar(0.75).unwrap(), 5.0, epsilon=1e-12);
+/// assert_eq!(interp.interp_scalar(0.5).unwrap(), 0.0);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Angular<T> {
+    extrapolate_low: bool,
+    extrapolate_high: bool,
+    period: T,
+}
+
+impl<T> Angular<T>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+{
+    /// create an angular interpolation strategy with a period of `2π`
+    pub fn new() -> Self {
+        Self {
+            extrapolate_low: false,
+            extrapolate_high: false,
+            period: cast(std::f64::consts::TAU).unwrap_or_else(|| unimplemented!()),
+        }
+    }
+
+    /// does the strategy extrapolate? Default is `false`
+    ///
+    /// The first and the last segment are continued along their shorter way.
+    /// This sets both sides, see [`extrapolate_low`](Angular::extrapolate_low)
+    /// and [`extrapolate_high`](Angular::extrapolate_high).
+    pub fn extrapolate(self, extrapolate: bool) -> Self {
+        self.extrapolate_low(extrapolate)
+            .extrapolate_high(extrapolate)
+    }
+
+    /// does the strategy extrapolate below the first knot? Default is `false`
+    pub fn extrapolate_low(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_low = extrapolate;
+        self
+    }
+
+    /// does the strategy extrapolate above the last knot? Default is `false`
+    pub fn extrapolate_high(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_high = extrapolate;
+        self
+    }
+
+    /// set the period after which the data wraps around. Default is `2π`
+    pub fn period(mut self, period: T) -> Self {
+        self.period = period;
+        self
+    }
+}
+
+impl<T> Angular<T>
+where
+    T: Num + PartialOrd + Euclid + Copy,
+{
+    /// wrap `angle` into `[0, period)`
+    fn wrap(&self, angle: T) -> T {
+        let wrapped = angle.rem_euclid(&self.period);
+        // rounding can map tiny negative angles onto the period itself
+        if wrapped < self.period {
+            wrapped
+        } else {
+            T::zero()
+        }
+    }
+
+    /// the shortest signed difference from `from` to `to`, in `(-period/2, period/2]`
+    /// so half a period apart is a step forward
+    fn shortest_delta(&self, from: T, to: T) -> T {
+        let half = self.period / (T::one() + T::one());
+        half - self.wrap(from - to + half)
+    }
+}
+
+impl<T> Default for Angular<T>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Display for Angular<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Angular (period: {:?}, extrapolate: {})",
+            self.period,
+            describe_extrapolation(self.extrapolate_low, self.extrapolate_high)
+        )
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategyBuilder<Sd, Sx, D> for Angular<Sd::Elem>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + Euclid + NumCast + Copy + Debug + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    const MINIMUM_DATA_LENGHT: usize = 2;
+    type FinishedStrat = Angular<Sd::Elem>;
+
+    fn build<Sx2>(
+        self,
+        _x: &ArrayBase<Sx2, Ix1>,
+        _data: &ArrayBase<Sd, D>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        // also rejects NaN
+        if self.period.partial_cmp(&Sd::Elem::zero()) != Some(Ordering::Greater) {
+            return Err(BuilderError::ValueError(format!(
+                "period needs to be positive, got: {:?}",
+                self.period
+            )));
+        }
+        Ok(self)
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategy<Sd, Sx, D> for Angular<Sd::Elem>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + Euclid + NumCast + Copy + Debug + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    const NAME: &'static str = "Angular";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        ExtrapolateMode::from_sides(self.extrapolate_low, self.extrapolate_high)
+    }
+
+    fn interp_into(
+        &self,
+        interpolator: &Interp1D<Sd, Sx, D, Self>,
+        target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
+        x: Sx::Elem,
+    ) -> Result<(), InterpolateError> {
+        let this = interpolator;
+        this.check_range(x, self.extrapolate_low, self.extrapolate_high)?;
+
+        let idx = this.get_index_left_of(x);
+        let (x_left, y_left) = this.index_point(idx);
+        let (x_right, y_right) = this.index_point(idx + 1);
+        let t = (x - x_left) / (x_right - x_left);
+        Zip::from(target)
+            .and(y_left)
+            .and(y_right)
+            .for_each(|y, &y_left, &y_right| {
+                *y = self.wrap(y_left + self.shortest_delta(y_left, y_right) * t);
+            });
+        Ok(())
+    }
+}
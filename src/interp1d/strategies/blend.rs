@@ -0,0 +1,285 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Range,
+    sync::{Mutex, OnceLock},
+};
+
+use ndarray::{
+    Array, ArrayBase, ArrayViewMut, Axis, Data, Dimension, Ix1, OwnedArcRepr, RemoveAxis, Zip,
+};
+use num_traits::{cast, Num, NumCast, One, Zero};
+
+use crate::{
+    interp1d::{ExtrapolateMode, Interp1D},
+    linalg::Scratch,
+    BuilderError, InterpolateError,
+};
+
+use super::{Interp1DStrategy, Interp1DStrategyBuilder};
+
+/// The inner interpolators of a [`BlendStrategy`], they share the data
+type Shared<T, X, D, Strat> = Interp1D<OwnedArcRepr<T>, OwnedArcRepr<X>, D, Strat>;
+
+/// Blend Interpolation Strategy
+///
+/// The data is interpolated with two strategies and the results are blended with
+/// ```text
+/// (1 - alpha) * first + alpha * second
+/// ```
+/// e.g. a [`Linear`](super::linear::Linear) and a
+/// [`CubicSpline`](super::cubic_spline::CubicSpline) interpolation with `alpha` as a
+/// knob for the smoothness between the two.
+///
+/// Both strategies are built on a shared copy of the data. A query is in range
+/// when it is in range of both strategies, so the extrapolation is configured on
+/// the two strategies. Masked gaps and
+/// [`closed_upper`](crate::interp1d::Interp1DBuilder::closed_upper) apply to both.
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray_interp::interp1d::cubic_spline::*;
+/// # use ndarray::*;
+/// # use approx::*;
+/// let data = array![0.0, 1.0, 0.0, 1.0];
+/// let interp = Interp1DBuilder::new(data)
+///     .strategy(Blend::new(Linear::new(), CubicSpline::new()).alpha(0.25))
+///     .build()
+///     .unwrap();
+///
+/// // the linear interpolation is 0.5, the cubic spline 1.0
+/// let result = interp.interp_scalar(0.5).unwrap();
+/// # assert_abs_diff_eq!(result, 0.625, epsilon = 1e-14);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Blend<A, B, T> {
+    first: A,
+    second: B,
+    alpha: T,
+}
+
+/// The Blend 1d interpolation Strategy (Implementation)
+///
+/// This is constructed by [`Blend`]
+#[derive(Debug)]
+pub struct BlendStrategy<T, X, D, SA, SB>
+where
+    T: Debug + Send,
+    X: Num + Debug + Send,
+    D: Dimension,
+    SA: Interp1DStrategy<OwnedArcRepr<T>, OwnedArcRepr<X>, D>,
+    SB: Interp1DStrategy<OwnedArcRepr<T>, OwnedArcRepr<X>, D>,
+{
+    first: Shared<T, X, D, SA>,
+    second: Shared<T, X, D, SB>,
+    alpha: T,
+    /// the values of the second strategy, reused by the queries
+    buffer: Mutex<Array<T, D::Smaller>>,
+}
+
+impl<A, B, T> Blend<A, B, T>
+where
+    T: NumCast,
+{
+    /// blend the interpolation with `first` and `second`, by default half and half
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            alpha: cast(0.5).unwrap_or_else(|| unimplemented!()),
+        }
+    }
+
+    /// the weight of the second strategy. Default is `0.5`
+    ///
+    /// `0` gives the first strategy and `1` gives the second strategy.
+    /// The build fails when `alpha` is not between `0` and `1`.
+    pub fn alpha(mut self, alpha: T) -> Self {
+        self.alpha = alpha;
+        self
+    }
+}
+
+impl<Sd, Sx, D, A, B> Interp1DStrategyBuilder<Sd, Sx, D> for Blend<A, B, Sd::Elem>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    A: Interp1DStrategyBuilder<OwnedArcRepr<Sd::Elem>, OwnedArcRepr<Sx::Elem>, D>,
+    B: Interp1DStrategyBuilder<OwnedArcRepr<Sd::Elem>, OwnedArcRepr<Sx::Elem>, D>,
+{
+    const MINIMUM_DATA_LENGHT: usize = if A::MINIMUM_DATA_LENGHT > B::MINIMUM_DATA_LENGHT {
+        A::MINIMUM_DATA_LENGHT
+    } else {
+        B::MINIMUM_DATA_LENGHT
+    };
+    type FinishedStrat = BlendStrategy<Sd::Elem, Sx::Elem, D, A::FinishedStrat, B::FinishedStrat>;
+
+    fn build<Sx2>(
+        self,
+        x: &ArrayBase<Sx2, Ix1>,
+        data: &ArrayBase<Sd, D>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        <Self as Interp1DStrategyBuilder<Sd, Sx, D>>::build_in(self, x, data, &mut Scratch::new())
+    }
+
+    fn select_columns(self, columns: Range<usize>) -> Result<Self, BuilderError> {
+        Ok(Blend {
+            first: self.first.select_columns(columns.clone())?,
+            second: self.second.select_columns(columns)?,
+            alpha: self.alpha,
+        })
+    }
+
+    fn build_in<Sx2>(
+        self,
+        x: &ArrayBase<Sx2, Ix1>,
+        data: &ArrayBase<Sd, D>,
+        scratch: &mut Scratch<Sx::Elem, Sd::Elem>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        let (zero, one) = (Sd::Elem::zero(), Sd::Elem::one());
+        if !(self.alpha >= zero && self.alpha <= one) {
+            return Err(BuilderError::ValueError(format!(
+                "alpha must be between 0 and 1, got {:?}",
+                self.alpha
+            )));
+        }
+        let buffer = Mutex::new(Array::zeros(data.raw_dim().remove_axis(Axis(0))));
+        let x = x.to_shared();
+        let data = data.to_shared();
+        let first = self.first.build_in(&x, &data, scratch)?;
+        let second = self.second.build_in(&x, &data, scratch)?;
+        Ok(BlendStrategy {
+            first: Interp1D {
+                x: x.clone(),
+                data: data.clone(),
+                strategy: first,
+                gaps: Vec::new(),
+                closed_upper: true,
+                output_transform: None,
+                data_monotonic: OnceLock::new(),
+            },
+            second: Interp1D {
+                x,
+                data,
+                strategy: second,
+                gaps: Vec::new(),
+                closed_upper: true,
+                output_transform: None,
+                data_monotonic: OnceLock::new(),
+            },
+            alpha: self.alpha,
+            buffer,
+        })
+    }
+}
+
+impl<T, X, D, SA, SB> PartialEq for BlendStrategy<T, X, D, SA, SB>
+where
+    T: PartialEq + Debug + Send,
+    X: Num + Debug + Send,
+    D: Dimension,
+    SA: Interp1DStrategy<OwnedArcRepr<T>, OwnedArcRepr<X>, D> + PartialEq,
+    SB: Interp1DStrategy<OwnedArcRepr<T>, OwnedArcRepr<X>, D> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.first.strategy == other.first.strategy
+            && self.second.strategy == other.second.strategy
+            && self.alpha == other.alpha
+    }
+}
+
+impl<T, X, D, SA, SB> Display for BlendStrategy<T, X, D, SA, SB>
+where
+    T: Debug + Send,
+    X: Num + Debug + Send,
+    D: Dimension,
+    SA: Interp1DStrategy<OwnedArcRepr<T>, OwnedArcRepr<X>, D> + Display,
+    SB: Interp1DStrategy<OwnedArcRepr<T>, OwnedArcRepr<X>, D> + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Blend ({}, {}, alpha: {:?})",
+            self.first.strategy, self.second.strategy, self.alpha
+        )
+    }
+}
+
+impl<Sd, Sx, D, SA, SB> Interp1DStrategy<Sd, Sx, D> for BlendStrategy<Sd::Elem, Sx::Elem, D, SA, SB>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+    SA: Interp1DStrategy<OwnedArcRepr<Sd::Elem>, OwnedArcRepr<Sx::Elem>, D>,
+    SB: Interp1DStrategy<OwnedArcRepr<Sd::Elem>, OwnedArcRepr<Sx::Elem>, D>,
+{
+    const NAME: &'static str = "Blend";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        // a query is only blended when both interpolators can evaluate it
+        let sides = |mode| match mode {
+            ExtrapolateMode::No => (false, false),
+            ExtrapolateMode::Low => (true, false),
+            ExtrapolateMode::High => (false, true),
+            ExtrapolateMode::Yes | ExtrapolateMode::Periodic => (true, true),
+        };
+        let first = self.first.extrapolation_mode();
+        let second = self.second.extrapolation_mode();
+        if first == ExtrapolateMode::Periodic && second == ExtrapolateMode::Periodic {
+            return ExtrapolateMode::Periodic;
+        }
+        let ((first_low, first_high), (second_low, second_high)) = (sides(first), sides(second));
+        ExtrapolateMode::from_sides(first_low && second_low, first_high && second_high)
+    }
+
+    fn memory_bytes(&self) -> usize {
+        // the two interpolators share the x axis and the data
+        self.first.memory_bytes()
+            + self.second.strategy.memory_bytes()
+            + self.first.n_series() * std::mem::size_of::<Sd::Elem>()
+    }
+
+    fn interp_into(
+        &self,
+        _interpolator: &Interp1D<Sd, Sx, D, Self>,
+        mut target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
+        x: Sx::Elem,
+    ) -> Result<(), InterpolateError> {
+        self.first.interp_into(x, target.view_mut())?;
+        // queries on other threads allocate instead of waiting for the buffer
+        let mut owned;
+        let mut locked = self.buffer.try_lock();
+        let second = match locked {
+            Ok(ref mut buffer) => &mut **buffer,
+            Err(_) => {
+                owned = Array::zeros(target.raw_dim());
+                &mut owned
+            }
+        };
+        self.second.interp_into(x, second.view_mut())?;
+        let weight = Sd::Elem::one() - self.alpha;
+        Zip::from(target)
+            .and(&*second)
+            .for_each(|y, &second| *y = *y * weight + second * self.alpha);
+        Ok(())
+    }
+
+    fn set_range(&mut self, gaps: &[(Sx::Elem, Sx::Elem)], closed_upper: bool) {
+        self.first.gaps = gaps.to_vec();
+        self.first.closed_upper = closed_upper;
+        self.first.strategy.set_range(gaps, closed_upper);
+        self.second.gaps = gaps.to_vec();
+        self.second.closed_upper = closed_upper;
+        self.second.strategy.set_range(gaps, closed_upper);
+    }
+}
@@ -12,17 +12,24 @@
 //!
 
 use std::{
-    fmt::Debug,
-    ops::{Add, Neg, Sub, SubAssign},
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Neg, Range, Sub, SubAssign},
+    sync::OnceLock,
 };
 
 use ndarray::{
-    s, Array, Array1, ArrayBase, ArrayView, ArrayViewMut, Axis, Data, Dimension, FoldWhile, Ix1,
-    IxDyn, RemoveAxis, ScalarOperand, Slice, Zip,
+    s, Array, Array1, Array2, ArrayBase, ArrayView, ArrayView1, ArrayViewMut, ArrayViewMut1, Axis,
+    Data, Dimension, FoldWhile, Ix1, Ix2, IxDyn, OwnedRepr, RawData, RemoveAxis, ScalarOperand,
+    Slice, Zip,
 };
-use num_traits::{cast, Euclid, Num, NumCast, Pow};
+use num_traits::{cast, Euclid, Num, NumCast, Pow, Zero};
 
-use crate::{interp1d::Interp1D, BuilderError, InterpolateError};
+use crate::{
+    interp1d::{ExtrapolateMode, Interp1D, Linear},
+    linalg::{solve_banded, zeroed_view, Scratch},
+    BuilderError, InterpolateError,
+};
 
 use super::{Interp1DStrategy, Interp1DStrategyBuilder};
 
@@ -31,6 +38,10 @@ const AX0: Axis = Axis(0);
 /// Marker trait that is implemented for anything that satisfies
 /// the trait bounds required to be used as an element in the CubicSpline
 /// strategy.
+///
+/// `Sync` is needed to assemble the coefficients in parallel with the `rayon` feature.
+/// It is required without the feature as well, so enabling the feature does not
+/// change which element types are accepted.
 pub trait SplineNum:
     Debug
     + Num
@@ -45,6 +56,28 @@ pub trait SplineNum:
     + ScalarOperand
     + Euclid
     + Send
+    + Sync
+{
+}
+
+/// Marker trait that is implemented for anything that satisfies
+/// the trait bounds required to be used as data in the CubicSpline
+/// strategy with an x axis of type `X`.
+///
+/// The data does not need to be ordered, so for example complex numbers
+/// can be interpolated over a real x axis.
+pub trait SplineData<X>:
+    Debug
+    + Copy
+    + Zero
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Mul<X, Output = Self>
+    + Div<X, Output = Self>
+    + Send
+    + Sync
 {
 }
 
@@ -81,17 +114,32 @@ pub trait SplineNum:
 /// ];
 /// # assert_abs_diff_eq!(result, expect, epsilon=f64::EPSILON);
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CubicSpline<T, D: Dimension> {
-    extrapolate: bool,
+    extrapolate_low: bool,
+    extrapolate_high: bool,
+    extrapolation_order: u8,
     boundary: BoundaryCondition<T, D>,
+    breaks: Vec<usize>,
+    derivative_smoothing: Option<f64>,
+    symmetry: Option<Symmetry>,
+    clamp_to_data: Option<ClampFn<T>>,
+    limit_monotone: Option<LimitFn<T>>,
 }
 
+/// Clamps the first argument between the other two, see [`CubicSpline::clamp_to_data`]
+type ClampFn<T> = fn(T, T, T) -> T;
+
+/// Limits the slopes of a monotonic data row, see [`CubicSpline::limit_monotone`]
+type LimitFn<T> = fn(ArrayView1<T>, ArrayViewMut1<T>, ArrayViewMut1<T>) -> bool;
+
 /// The CubicSpline 1d interpolation Strategy (Implementation)
 ///
 /// This is constructed by [`CubicSpline`]
+///
+/// `X` is the element type of the x axis, by default the same as the data.
 #[derive(Debug)]
-pub struct CubicSplineStrategy<Sd, D>
+pub struct CubicSplineStrategy<Sd, D, X = <Sd as RawData>::Elem>
 where
     Sd: Data,
     D: Dimension + RemoveAxis,
@@ -99,6 +147,33 @@ where
     pub a: Array<Sd::Elem, D>,
     pub b: Array<Sd::Elem, D>,
     extrapolate: Extrapolate,
+    /// order of the taylor polynomial used outside of the range
+    extrapolation_order: u8,
+    /// position of the first knot, segment arithmetic is done relative to it
+    x_offset: X,
+    /// added to every query before it is located, see [`Interp1D::set_phase_origin`]
+    phase_origin: X,
+    /// inner knots where the derivatives may jump, see [`CubicSpline::breaks`]
+    breaks: Vec<usize>,
+    /// values and second derivatives at the knots of the smoothing spline,
+    /// see [`CubicSpline::derivative_smoothing`]
+    derivative_spline: Option<SmoothingSpline<Sd::Elem, D>>,
+    /// the boundary condition the spline was solved with, see [`Interp1D::basis`].
+    /// It is fully reflected in `a` and `b`, so it is not compared.
+    boundary: BoundaryCondition<Sd::Elem, D>,
+    /// clamps a value between the data of two knots, see [`CubicSpline::clamp_to_data`]
+    clamp_to_data: Option<ClampFn<Sd::Elem>>,
+    /// the data rows with limited slopes, see [`CubicSpline::limit_monotone`]
+    monotone_limited: Array<bool, D::Smaller>,
+}
+
+/// The natural smoothing spline of [`CubicSpline::derivative_smoothing`]
+#[derive(Debug, PartialEq)]
+struct SmoothingSpline<T, D: Dimension> {
+    /// the smoothed values at the knots
+    values: Array<T, D>,
+    /// the second derivatives at the knots
+    second_deriv: Array<T, D>,
 }
 
 /// Boundary conditions for the whole dataset
@@ -117,6 +192,7 @@ where
 ///  - [`Periodic`](BoundaryCondition::Periodic) - not in [`SingleBoundary`]
 ///  - [`FirstDeriv`](SingleBoundary::FirstDeriv) - only in [`SingleBoundary`]
 ///  - [`SecondDeriv`](SingleBoundary::SecondDeriv) - only in [`SingleBoundary`]
+///  - [`DataSlope`](SingleBoundary::DataSlope) - only in [`SingleBoundary`]
 ///
 /// ## Example
 /// In a complex case all boundaries can be set individually:
@@ -150,7 +226,7 @@ where
 ///     .build().unwrap();
 ///
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum BoundaryCondition<T, D: Dimension> {
     /// Not a knot boundary. The first and second segment at a curve end are the same polynomial.
     NotAKnot,
@@ -214,15 +290,46 @@ pub enum SingleBoundary<T> {
     FirstDeriv(T),
     /// Set a value for the second derivative at the curve end
     SecondDeriv(T),
+    /// The first derivative at the curve end is the slope of the first (or last)
+    /// segment of the data, `(y[1] - y[0]) / (x[1] - x[0])` at the left end
+    DataSlope,
 }
 
-#[derive(Debug)]
+/// Symmetry of the data about the first knot, see [`CubicSpline::symmetry`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Symmetry {
+    /// `f(x0 - h) = f(x0 + h)`, e.g. a cosine sampled from 0.
+    /// The first derivative at the first knot is 0
+    Even,
+    /// `f(x0 - h) = 2 f(x0) - f(x0 + h)`, e.g. a sine sampled from 0.
+    /// The second derivative at the first knot is 0
+    Odd,
+}
+
+#[derive(Debug, PartialEq)]
 enum Extrapolate {
     Yes,
+    Low,
+    High,
     No,
     Periodic,
 }
 
+impl<T, X> SplineData<X> for T where
+    T: Debug
+        + Copy
+        + Zero
+        + PartialEq
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Neg<Output = T>
+        + Mul<X, Output = T>
+        + Div<X, Output = T>
+        + Send
+        + Sync
+{
+}
+
 impl<T> SplineNum for T where
     T: Debug
         + Num
@@ -237,6 +344,7 @@ impl<T> SplineNum for T where
         + ScalarOperand
         + Euclid
         + Send
+        + Sync
 {
 }
 
@@ -252,7 +360,7 @@ impl<T: SplineNum> Default for RowBoundary<T> {
     }
 }
 
-impl<T: SplineNum> InternalBoundary<T> {
+impl<T> InternalBoundary<T> {
     fn specialize(self) -> Self {
         use SingleBoundary::*;
         match self {
@@ -284,97 +392,341 @@ impl<T> From<RowBoundary<T>> for InternalBoundary<T> {
     }
 }
 
-impl<T: SplineNum> SingleBoundary<T> {
+impl<T: Zero> SingleBoundary<T> {
     fn specialize(self) -> Self {
         use SingleBoundary::*;
         match self {
-            SingleBoundary::Natural => SecondDeriv(cast(0.0).unwrap_or_else(|| unimplemented!())),
-            SingleBoundary::Clamped => FirstDeriv(cast(0.0).unwrap_or_else(|| unimplemented!())),
+            SingleBoundary::Natural => SecondDeriv(T::zero()),
+            SingleBoundary::Clamped => FirstDeriv(T::zero()),
             _ => self,
         }
     }
 }
 
-impl<T: SplineNum> Default for SingleBoundary<T> {
-    fn default() -> Self {
-        Self::NotAKnot
+/// Limit the slopes at both ends of each segment of a monotonic data row,
+/// see [`CubicSpline::limit_monotone`]. `secants` are the slopes of the segments.
+///
+/// **returns** `false` without changing the slopes when the data is not monotonic
+fn limit_slopes<T>(
+    secants: ArrayView1<T>,
+    mut left: ArrayViewMut1<T>,
+    mut right: ArrayViewMut1<T>,
+) -> bool
+where
+    T: PartialOrd + Copy + Zero + Add<Output = T> + Neg<Output = T>,
+{
+    let zero = T::zero();
+    let rising = secants.iter().all(|&secant| secant >= zero);
+    if !rising && !secants.iter().all(|&secant| secant <= zero) {
+        return false;
+    }
+    // work with rising slopes, falling data is mirrored
+    let mirror = |slope: T| if rising { slope } else { -slope };
+    // the largest slope at a knot, three times the smaller secant of its segments
+    let bound = |idx: usize| {
+        let neighbours = secants.slice(s![idx.saturating_sub(1)..(idx + 1).min(secants.len())]);
+        let smallest = neighbours
+            .iter()
+            .map(|&secant| mirror(secant))
+            .fold(None, |min, secant| match min {
+                Some(min) if min <= secant => Some(min),
+                _ => Some(secant),
+            })
+            .unwrap_or(zero);
+        smallest + smallest + smallest
+    };
+    let limit = |slope: T, bound: T| {
+        let slope = mirror(slope);
+        mirror(if slope < zero {
+            zero
+        } else if slope > bound {
+            bound
+        } else {
+            slope
+        })
+    };
+    for idx in 0..secants.len() {
+        left[idx] = limit(left[idx], bound(idx));
+        right[idx] = limit(right[idx], bound(idx + 1));
     }
+    true
 }
 
 impl<T, D> CubicSpline<T, D>
 where
     D: Dimension + RemoveAxis,
-    T: SplineNum,
 {
     /// Calculate the coefficients `a` and `b`
-    fn calc_coefficients<Sd, Sx>(
+    #[allow(clippy::type_complexity)]
+    fn calc_coefficients<X, Sd, Sx>(
         &self,
         x: &ArrayBase<Sx, Ix1>,
         data: &ArrayBase<Sd, D>,
+        scratch: &mut Scratch<X, T>,
     ) -> Result<(Array<Sd::Elem, D>, Array<Sd::Elem, D>), BuilderError>
     where
+        X: SplineNum,
+        T: SplineData<X>,
         Sd: Data<Elem = T>,
-        Sx: Data<Elem = T>,
+        Sx: Data<Elem = X>,
+    {
+        let mut a_b_dim = data.raw_dim();
+        a_b_dim[0] -= 1;
+        let mut c_a = Array::zeros(a_b_dim.clone());
+        let mut c_b = Array::zeros(a_b_dim);
+
+        for (start, end) in self.runs(x.len())? {
+            let x = x.slice(s![start..end + 1]);
+            let data = data.slice_axis(AX0, Slice::from(start..end + 1));
+            // the solution is taken out of the scratch, so the solver can borrow the other buffers
+            let mut solution = std::mem::take(&mut scratch.solution);
+            let mut k = zeroed_view(&mut solution, data.raw_dim());
+            let solved = self.solve_run(k.view_mut(), &x, &data, scratch);
+            if solved.is_ok() {
+                Self::calc_segments(
+                    c_a.slice_axis_mut(AX0, Slice::from(start..end)),
+                    c_b.slice_axis_mut(AX0, Slice::from(start..end)),
+                    k.view(),
+                    &x,
+                    &data,
+                );
+                self.zero_constant_rows(
+                    c_a.slice_axis_mut(AX0, Slice::from(start..end)),
+                    c_b.slice_axis_mut(AX0, Slice::from(start..end)),
+                    &data,
+                );
+            }
+            scratch.solution = solution;
+            solved?;
+        }
+        Ok((c_a, c_b))
+    }
+
+    /// A constant data row with a homogeneous boundary condition is exactly solved by `k = 0`.
+    /// Set the coefficients of such rows to zero, so rounding in the solver can not
+    /// leave a tiny curvature in an interpolant which must be exactly constant.
+    fn zero_constant_rows(
+        &self,
+        mut c_a: ArrayViewMut<T, D>,
+        mut c_b: ArrayViewMut<T, D>,
+        data: &ArrayView<T, D>,
+    ) where
+        T: Copy + Zero + PartialEq,
+    {
+        let is_constant = |row: ArrayView1<T>| row.iter().all(|&y| y == row[0]);
+        let clear = |mut c_a: ArrayViewMut1<T>, mut c_b: ArrayViewMut1<T>| {
+            c_a.fill(T::zero());
+            c_b.fill(T::zero());
+        };
+        let lanes = Zip::from(c_a.lanes_mut(AX0))
+            .and(c_b.lanes_mut(AX0))
+            .and(data.lanes(AX0));
+        match self.boundary {
+            BoundaryCondition::Individual(ref bounds) => {
+                let is_homogeneous = |bound: &SingleBoundary<T>| match bound {
+                    SingleBoundary::FirstDeriv(deriv) | SingleBoundary::SecondDeriv(deriv) => {
+                        deriv.is_zero()
+                    }
+                    _ => true,
+                };
+                lanes
+                    .and(bounds.lanes(AX0))
+                    .for_each(|c_a, c_b, data, bound| {
+                        let homogeneous = match &bound[0] {
+                            RowBoundary::Mixed { left, right } => {
+                                is_homogeneous(left) && is_homogeneous(right)
+                            }
+                            _ => true,
+                        };
+                        if homogeneous && is_constant(data) {
+                            clear(c_a, c_b);
+                        }
+                    })
+            }
+            _ => lanes.for_each(|c_a, c_b, data| {
+                if is_constant(data) {
+                    clear(c_a, c_b);
+                }
+            }),
+        }
+    }
+
+    /// The first and last knot index of each run of knots between the breaks
+    fn runs(&self, len: usize) -> Result<Vec<(usize, usize)>, BuilderError> {
+        if self.breaks.is_empty() {
+            return Ok(vec![(0, len - 1)]);
+        }
+        if matches!(self.boundary, BoundaryCondition::Periodic) {
+            return Err(BuilderError::ValueError(
+                "breaks can not be used with a periodic boundary condition".into(),
+            ));
+        }
+
+        let mut breaks = self.breaks.clone();
+        breaks.sort_unstable();
+        breaks.dedup();
+        if let Some(idx) = breaks.iter().find(|&&idx| idx == 0 || idx >= len - 1) {
+            return Err(BuilderError::ValueError(format!(
+                "break at index {idx} is not an inner knot, valid indices are 1..{}",
+                len - 1
+            )));
+        }
+
+        let mut runs = Vec::with_capacity(breaks.len() + 1);
+        let mut start = 0;
+        for end in breaks.into_iter().chain(std::iter::once(len - 1)) {
+            if end - start < 2 {
+                return Err(BuilderError::NotEnoughData(format!(
+                    "each run between breaks needs at least 3 knots, got {} from index {start} to {end}",
+                    end - start + 1
+                )));
+            }
+            runs.push((start, end));
+            start = end;
+        }
+        Ok(runs)
+    }
+
+    /// solve for `k` on a single run of knots with the configured boundary condition
+    fn solve_run<X>(
+        &self,
+        kv: ArrayViewMut<T, D>,
+        x: &ArrayView1<X>,
+        data: &ArrayView<T, D>,
+        scratch: &mut Scratch<X, T>,
+    ) -> Result<(), BuilderError>
+    where
+        X: SplineNum,
+        T: SplineData<X>,
     {
-        let dim = data.raw_dim();
-        let len = dim[0];
-        let mut k = Array::zeros(dim.clone());
-        let kv = k.view_mut();
         match self.boundary {
             BoundaryCondition::Periodic => {
-                Self::solve_for_k(kv, x, data, InternalBoundary::Periodic)
+                Self::solve_for_k(kv, x, data, InternalBoundary::Periodic, scratch)
+            }
+            BoundaryCondition::Natural => {
+                Self::solve_for_k(kv, x, data, InternalBoundary::Natural, scratch)
+            }
+            BoundaryCondition::Clamped => {
+                Self::solve_for_k(kv, x, data, InternalBoundary::Clamped, scratch)
             }
-            BoundaryCondition::Natural => Self::solve_for_k(kv, x, data, InternalBoundary::Natural),
-            BoundaryCondition::Clamped => Self::solve_for_k(kv, x, data, InternalBoundary::Clamped),
             BoundaryCondition::NotAKnot => {
-                Self::solve_for_k(kv, x, data, InternalBoundary::NotAKnot)
+                Self::solve_for_k(kv, x, data, InternalBoundary::NotAKnot, scratch)
             }
             BoundaryCondition::Individual(ref bounds) => {
+                // the rank can only differ for dynamic dimensions
+                if bounds.ndim() != kv.ndim() {
+                    return Err(BuilderError::ShapeError(format!(
+                        "Boundary conditions array has wrong dimensionality. Expected {} dimensional array (the data shape with a first axis of length 1), got {} dimensions",
+                        kv.ndim(),
+                        bounds.ndim()
+                    )));
+                }
                 let mut bounds_shape = kv.raw_dim();
                 bounds_shape[0] = 1;
                 if bounds_shape != bounds.raw_dim() {
                     return Err(BuilderError::ShapeError(format!(
-                        "Boundary conditions array has wrong shape. Expected: {bounds_shape:?}, got: {:?}",
-                        bounds.raw_dim()
+                        "Boundary conditions array has wrong shape. Expected: {:?}, got: {:?}",
+                        bounds_shape.slice(),
+                        bounds.shape()
                     )));
                 }
+                Self::check_finite_boundaries(bounds)?;
                 Self::solve_for_k_individual(
                     kv.into_dyn(),
                     x,
                     data.view().into_dyn(),
                     bounds.view().into_dyn(),
+                    scratch,
                 )
             }
-        }?;
+        }
+    }
 
-        let mut a_b_dim = data.raw_dim();
-        a_b_dim[0] -= 1;
-        let mut c_a = Array::zeros(a_b_dim.clone());
-        let mut c_b = Array::zeros(a_b_dim);
-        for index in 0..len - 1 {
-            Zip::from(c_a.index_axis_mut(AX0, index))
-                .and(c_b.index_axis_mut(AX0, index))
-                .and(k.index_axis(AX0, index))
-                .and(k.index_axis(AX0, index + 1))
-                .and(data.index_axis(AX0, index))
-                .and(data.index_axis(AX0, index + 1))
-                .for_each(|c_a, c_b, &k, &k_right, &y, &y_right| {
-                    *c_a = k * (x[index + 1] - x[index]) - (y_right - y);
-                    *c_b = (y_right - y) - k_right * (x[index + 1] - x[index]);
-                })
+    /// A NaN or infinite boundary derivative would silently spread through the
+    /// whole row of the solution, so reject it while building.
+    fn check_finite_boundaries<X>(bounds: &Array<RowBoundary<T>, D>) -> Result<(), BuilderError>
+    where
+        X: SplineNum,
+        T: SplineData<X>,
+    {
+        // `deriv * 0` is NaN for NaN and infinite values, this also works for complex data
+        let is_finite = |deriv: &T| (*deriv * X::zero()).is_zero();
+        for (idx, bound) in bounds.indexed_iter() {
+            let RowBoundary::Mixed { left, right } = bound else {
+                continue;
+            };
+            for (side, single) in [("left", left), ("right", right)] {
+                if let SingleBoundary::FirstDeriv(deriv) | SingleBoundary::SecondDeriv(deriv) =
+                    single
+                {
+                    if !is_finite(deriv) {
+                        return Err(BuilderError::ValueError(format!(
+                            "the {side} boundary derivative {deriv:?} of row {:?} is not finite",
+                            idx
+                        )));
+                    }
+                }
+            }
         }
+        Ok(())
+    }
 
-        Ok((c_a, c_b))
+    /// Calculate the coefficients `a` and `b` of each segment from `k`
+    fn calc_segments<X>(
+        mut c_a: ArrayViewMut<T, D>,
+        mut c_b: ArrayViewMut<T, D>,
+        k: ArrayView<T, D>,
+        x: &ArrayView1<X>,
+        data: &ArrayView<T, D>,
+    ) where
+        X: SplineNum,
+        T: SplineData<X>,
+    {
+        // the segments are independent of each other, so we zip over the
+        // left and right values of each segment instead of indexing
+        let k_left = k.slice_axis(AX0, Slice::from(..-1));
+        let k_right = k.slice_axis(AX0, Slice::from(1..));
+        let dy = &data.slice_axis(AX0, Slice::from(1..)) - &data.slice_axis(AX0, Slice::from(..-1));
+        let dx = &x.slice(s![1..]) - &x.slice(s![..-1]);
+        let segments = Zip::from(c_a.axis_iter_mut(AX0))
+            .and(c_b.axis_iter_mut(AX0))
+            .and(k_left.axis_iter(AX0))
+            .and(k_right.axis_iter(AX0))
+            .and(dy.axis_iter(AX0))
+            .and(&dx);
+        let calc_segment = |c_a: ArrayViewMut<T, D::Smaller>,
+                            c_b: ArrayViewMut<T, D::Smaller>,
+                            k_left: ArrayView<T, D::Smaller>,
+                            k_right: ArrayView<T, D::Smaller>,
+                            dy: ArrayView<T, D::Smaller>,
+                            &dx: &X| {
+            Zip::from(c_a)
+                .and(c_b)
+                .and(k_left)
+                .and(k_right)
+                .and(dy)
+                .for_each(|c_a, c_b, &k, &k_right, &dy| {
+                    *c_a = k * dx - dy;
+                    *c_b = dy - k_right * dx;
+                })
+        };
+        #[cfg(feature = "rayon")]
+        segments.par_for_each(calc_segment);
+        #[cfg(not(feature = "rayon"))]
+        segments.for_each(calc_segment);
     }
 
-    fn solve_for_k_individual<Sx>(
+    fn solve_for_k_individual<X, Sx>(
         mut k: ArrayViewMut<T, IxDyn>,
         x: &ArrayBase<Sx, Ix1>,
         data: ArrayView<T, IxDyn>,
         boundary: ArrayView<RowBoundary<T>, IxDyn>,
+        scratch: &mut Scratch<X, T>,
     ) -> Result<(), BuilderError>
     where
-        Sx: Data<Elem = T>,
+        X: SplineNum,
+        T: SplineData<X>,
+        Sx: Data<Elem = X>,
     {
         if k.ndim() > 1 {
             let ax = Axis(k.ndim() - 1);
@@ -382,7 +734,7 @@ where
                 .and(data.axis_iter(ax))
                 .and(boundary.axis_iter(ax))
                 .fold_while(Ok(()), |_, k, data, boundary| {
-                    Self::solve_for_k_individual(k, x, data, boundary).map_or_else(
+                    Self::solve_for_k_individual(k, x, data, boundary, scratch).map_or_else(
                         |err| FoldWhile::Done(Err(err)),
                         |_| FoldWhile::Continue(Ok(())),
                     )
@@ -398,6 +750,7 @@ where
                     .cloned()
                     .unwrap_or_else(|| unreachable!())
                     .into(),
+                scratch,
             )
         }
     }
@@ -406,16 +759,19 @@ where
     /// each row in the data
     ///
     /// **returns** k
-    fn solve_for_k<Sd, Sx, _D>(
+    fn solve_for_k<X, Sd, Sx, _D>(
         mut k: ArrayViewMut<T, _D>,
         x: &ArrayBase<Sx, Ix1>,
         data: &ArrayBase<Sd, _D>,
         boundary: InternalBoundary<T>,
+        scratch: &mut Scratch<X, T>,
     ) -> Result<(), BuilderError>
     where
+        X: SplineNum,
+        T: SplineData<X>,
         _D: Dimension + RemoveAxis,
         Sd: Data<Elem = T>,
-        Sx: Data<Elem = T>,
+        Sx: Data<Elem = X>,
     {
         let dim = data.raw_dim();
         let len = dim[0];
@@ -427,19 +783,19 @@ where
          * This requires solving the Linear equation A * k = rhs
          */
 
-        // upper, middle and lower diagonal of A
-        let mut a_up = Array::zeros(len);
-        let mut a_mid = Array::zeros(len);
-        let mut a_low = Array::zeros(len);
+        // upper, middle and lower diagonal of A and the RHS vector
+        let (mut diagonals, mut rhs, band) = scratch.tridiagonal(dim.clone());
+        let (mut a_up, mut a_mid, mut a_low) =
+            diagonals.multi_slice_mut((s![0, ..], s![1, ..], s![2, ..]));
 
-        let zero: T = cast(0.0).unwrap_or_else(|| unimplemented!());
-        let one: T = cast(1.0).unwrap_or_else(|| unimplemented!());
-        let two: T = cast(2.0).unwrap_or_else(|| unimplemented!());
-        let three: T = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let zero: X = cast(0.0).unwrap_or_else(|| unimplemented!());
+        let one: X = cast(1.0).unwrap_or_else(|| unimplemented!());
+        let two: X = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: X = cast(3.0).unwrap_or_else(|| unimplemented!());
 
-        Zip::from(a_up.slice_mut(s![1..-1]))
-            .and(a_mid.slice_mut(s![1..-1]))
-            .and(a_low.slice_mut(s![1..-1]))
+        Zip::from(a_up.slice_mut(s![1..len - 1]))
+            .and(a_mid.slice_mut(s![1..len - 1]))
+            .and(a_low.slice_mut(s![1..len - 1]))
             .and(x.windows(3))
             .for_each(|a_up, a_mid, a_low, x| {
                 let dxn = x[2] - x[1];
@@ -450,9 +806,6 @@ where
                 *a_low = dxn;
             });
 
-        // RHS vector
-        let mut rhs = Array::zeros(dim.clone());
-
         for n in 1..len - 1 {
             let rhs = rhs.index_axis_mut(AX0, n);
             let y_left = data.index_axis(AX0, n - 1);
@@ -465,7 +818,7 @@ where
             Zip::from(y_left).and(y_mid).and(y_right).map_assign_into(
                 rhs,
                 |&y_left, &y_mid, &y_right| {
-                    three * (dxn * (y_mid - y_left) / dxn_1 + dxn_1 * (y_right - y_mid) / dxn)
+                    ((y_mid - y_left) * dxn / dxn_1 + (y_right - y_mid) * dxn_1 / dxn) * three
                 },
             );
         }
@@ -476,6 +829,8 @@ where
         let dx_2 = x[len - 2] - x[len - 3];
 
         // apply boundary conditions
+        // the first and last row only use the outer two segments, so apart from
+        // the periodic and the not-a-knot on both ends the general case also holds for `len == 3`
         match (boundary.specialize(), len) {
             (InternalBoundary::Periodic, 3) => {
                 let y0 = data.index_axis(AX0, 0);
@@ -506,14 +861,9 @@ where
                     }
                 }
 
-                // due to the preriodicity we need to solve one less equation
-                // the system matrix a is also condensed
+                // due to the preriodicity we need to solve one less equation,
+                // the first and the last unknown are coupled through the corners
                 // https://web.archive.org/web/20151220180652/http://www.cfm.brown.edu/people/gk/chap6/node14.html
-                a_up.slice_axis_inplace(AX0, Slice::from(0..-2));
-                a_mid.slice_axis_inplace(AX0, Slice::from(0..-2));
-                a_low.slice_axis_inplace(AX0, Slice::from(0..-2));
-                rhs.slice_axis_inplace(AX0, Slice::from(0..-1));
-
                 a_mid[0] = two * (dx_1 + dx0);
                 a_up[0] = dx_1;
 
@@ -531,34 +881,20 @@ where
                 rhs.index_axis_mut(AX0, len - 1 - 1)
                     .assign(&((slope_2 * dx_1 + slope_1 * dx_2) * three));
 
-                let rhs1 = rhs.slice_axis(AX0, Slice::from(0..-1)).to_owned();
-                let mut rhs2 = Array::zeros(rhs1.raw_dim());
-                rhs2.index_axis_mut(AX0, 0).fill(-dx0); // = -dx0;
-                let dx_3 = x[len - 3] - x[len - 4];
-                rhs2.index_axis_mut(AX0, len - 3).fill(-dx_3);
-
-                let mut k1 = Array::zeros(rhs1.raw_dim());
-                let mut k2 = Array::zeros(rhs1.raw_dim());
-
-                Self::thomas(
-                    k1.view_mut(),
-                    a_up.clone(),
-                    a_mid.clone(),
-                    a_low.clone(),
-                    rhs1,
+                // the last knot is the same as the first one
+                a_up.slice_axis_inplace(AX0, Slice::from(..-1));
+                a_mid.slice_axis_inplace(AX0, Slice::from(..-1));
+                a_low.slice_axis_inplace(AX0, Slice::from(..-1));
+                rhs.slice_axis_inplace(AX0, Slice::from(..-1));
+                Self::solve_cyclic_tridiagonal(
+                    k.slice_axis_mut(AX0, Slice::from(..-1)),
+                    a_up.view(),
+                    a_mid.view(),
+                    a_low.view(),
+                    rhs.view(),
+                    (dx0, dx_2),
+                    band,
                 );
-                Self::thomas(k2.view_mut(), a_up, a_mid, a_low, rhs2);
-
-                let k_m1 = (&rhs.index_axis(AX0, len - 2)
-                    - &k1.index_axis(AX0, 0) * dx_2
-                    - &k1.index_axis(AX0, len - 3) * dx_1)
-                    / (&k2.index_axis(AX0, 0) * dx_2
-                        + &k2.index_axis(AX0, len - 3) * dx_1
-                        + two * (dx_1 + dx_2));
-
-                k.slice_axis_mut(AX0, Slice::from(0..-2))
-                    .assign(&(k1 + &k_m1 * k2));
-                k.index_axis_mut(AX0, len - 2).assign(&k_m1);
                 let k0 = k.index_axis(AX0, 0).to_owned();
                 k.index_axis_mut(AX0, len - 1).assign(&k0);
                 return Ok(());
@@ -606,7 +942,7 @@ where
                             .and(data.index_axis(AX0, 1))
                             .and(data.index_axis(AX0, 2))
                             .for_each(|b, &y0, &y1, &y2| {
-                                *b = (tmp1 * (y1 - y0) / dx0 + dx0.pow(two) * (y2 - y1) / dx1) / d;
+                                *b = ((y1 - y0) * tmp1 / dx0 + (y2 - y1) * dx0.pow(two) / dx1) / d;
                             });
                     }
                     SingleBoundary::Natural => unreachable!(),
@@ -616,6 +952,14 @@ where
                         a_up[0] = zero;
                         rhs.index_axis_mut(AX0, 0).fill(deriv);
                     }
+                    SingleBoundary::DataSlope => {
+                        a_mid[0] = one;
+                        a_up[0] = zero;
+                        Zip::from(rhs.index_axis_mut(AX0, 0))
+                            .and(data.index_axis(AX0, 0))
+                            .and(data.index_axis(AX0, 1))
+                            .for_each(|b, &y0, &y1| *b = (y1 - y0) / dx0);
+                    }
                     SingleBoundary::SecondDeriv(deriv) => {
                         a_up[0] = dx0;
                         a_mid[0] = two * dx0;
@@ -626,13 +970,13 @@ where
                             .and(data_0)
                             .and(data_1)
                             .for_each(|rhs_0, &y_0, &y_1| {
-                                *rhs_0 = three * (y_1 - y_0) - deriv * dx0.pow(two) / two;
+                                *rhs_0 = (y_1 - y_0) * three - deriv * dx0.pow(two) / two;
                             });
                     }
                 };
                 match right.specialize() {
                     SingleBoundary::NotAKnot => {
-                        a_mid[len - 1] = dx_1;
+                        a_mid[len - 1] = dx_2;
                         let d = x[len - 1] - x[len - 3];
                         a_low[len - 1] = d;
                         let tmp1 = (two * d + dx_1) * dx_2;
@@ -641,8 +985,8 @@ where
                             .and(data.index_axis(AX0, len - 2))
                             .and(data.index_axis(AX0, len - 3))
                             .for_each(|b, &y_1, &y_2, &y_3| {
-                                *b = (dx_1.pow(two) * (y_2 - y_3) / dx_2
-                                    + tmp1 * (y_1 - y_2) / dx_1)
+                                *b = ((y_2 - y_3) * dx_1.pow(two) / dx_2
+                                    + (y_1 - y_2) * tmp1 / dx_1)
                                     / d;
                             });
                     }
@@ -653,6 +997,14 @@ where
                         a_low[len - 1] = zero;
                         rhs.index_axis_mut(AX0, len - 1).fill(deriv);
                     }
+                    SingleBoundary::DataSlope => {
+                        a_mid[len - 1] = one;
+                        a_low[len - 1] = zero;
+                        Zip::from(rhs.index_axis_mut(AX0, len - 1))
+                            .and(data.index_axis(AX0, len - 1))
+                            .and(data.index_axis(AX0, len - 2))
+                            .for_each(|b, &y_1, &y_2| *b = (y_1 - y_2) / dx_1);
+                    }
                     SingleBoundary::SecondDeriv(deriv) => {
                         a_mid[len - 1] = two * dx_1;
                         a_low[len - 1] = dx_1;
@@ -663,74 +1015,237 @@ where
                             .and(data_n)
                             .and(data_n1)
                             .for_each(|rhs_n, &y_n, &y_n1| {
-                                *rhs_n = three * (y_n - y_n1) + deriv * dx_1.pow(two) / two;
+                                *rhs_n = (y_n - y_n1) * three + deriv * dx_1.pow(two) / two;
                             });
                     }
                 };
             }
         }
-        Self::thomas(k, a_up, a_mid, a_low, rhs);
+        Self::thomas(k, a_up.view(), a_mid.view(), a_low.view(), rhs, band);
         Ok(())
     }
 
+    /// Solve `A * k = rhs` for a cyclic tridiagonal matrix `A` with `m >= 3` rows.
+    ///
+    /// `a_low`, `a_mid` and `a_up` are the diagonals of `A` like for [`thomas`](Self::thomas),
+    /// `corners` are the entries `A[0, m - 1]` and `A[m - 1, 0]` which couple the first and
+    /// the last unknown. `a_low[0]` and `a_up[m - 1]` are not used.
+    ///
+    /// The last unknown is eliminated by solving the tridiagonal system of the first `m - 1`
+    /// rows twice, once for `rhs` and once for the coupling column of the last unknown.
+    /// The last row then determines the last unknown.
+    ///
+    /// `band` is the buffer for the band storage of [`thomas`](Self::thomas).
+    fn solve_cyclic_tridiagonal<X, R, _D>(
+        mut k: ArrayViewMut<R, _D>,
+        mut a_up: ArrayView1<X>,
+        mut a_mid: ArrayView1<X>,
+        mut a_low: ArrayView1<X>,
+        rhs: ArrayView<R, _D>,
+        corners: (X, X),
+        band: &mut Vec<X>,
+    ) where
+        X: SplineNum,
+        R: SplineData<X>,
+        _D: Dimension + RemoveAxis,
+    {
+        let m = a_mid.len();
+        let (corner_up, corner_low) = corners;
+        let a_mid_last = a_mid[m - 1];
+        let a_low_last = a_low[m - 1];
+        let a_up_inner = a_up[m - 2];
+        a_up.slice_axis_inplace(AX0, Slice::from(..-1));
+        a_mid.slice_axis_inplace(AX0, Slice::from(..-1));
+        a_low.slice_axis_inplace(AX0, Slice::from(..-1));
+
+        let mut rhs1 = rhs.slice_axis(AX0, Slice::from(..-1)).to_owned();
+        // the second system only depends on the matrix, so it is the same for all rows
+        let mut rhs2 = Array1::zeros(m - 1);
+        rhs2[0] = -corner_up;
+        rhs2[m - 2] = -a_up_inner;
+
+        let mut k1 = Array::zeros(rhs1.raw_dim());
+        let mut k2 = Array1::zeros(m - 1);
+
+        Self::thomas(k1.view_mut(), a_up, a_mid, a_low, rhs1.view_mut(), band);
+        Self::thomas(k2.view_mut(), a_up, a_mid, a_low, rhs2.view_mut(), band);
+
+        let k_last = (&rhs.index_axis(AX0, m - 1)
+            - &k1.index_axis(AX0, 0) * corner_low
+            - &k1.index_axis(AX0, m - 2) * a_low_last)
+            / (k2[0] * corner_low + k2[m - 2] * a_low_last + a_mid_last);
+
+        Zip::from(k.slice_axis_mut(AX0, Slice::from(..-1)).axis_iter_mut(AX0))
+            .and(k1.axis_iter(AX0))
+            .and(&k2)
+            .for_each(|mut k, k1, &k2| k.assign(&(&k1 + &(&k_last * k2))));
+        k.index_axis_mut(AX0, m - 1).assign(&k_last);
+    }
+
     /// The Thomas algorithm is used, because the matrix A will be tridiagonal and diagonally dominant
     /// [https://en.wikipedia.org/wiki/Tridiagonal_matrix_algorithm]
-    fn thomas<_D>(
-        mut k: ArrayViewMut<T, _D>,
-        a_up: Array1<T>,
-        mut a_mid: Array1<T>,
-        a_low: Array1<T>,
-        mut rhs: Array<T, _D>,
+    ///
+    /// This is [`solve_banded`] with one diagonal above and below the main diagonal,
+    /// the band storage is placed in `band` and `rhs` is overwritten.
+    fn thomas<X, R, _D>(
+        mut k: ArrayViewMut<R, _D>,
+        a_up: ArrayView1<X>,
+        a_mid: ArrayView1<X>,
+        a_low: ArrayView1<X>,
+        mut rhs: ArrayViewMut<R, _D>,
+        band: &mut Vec<X>,
     ) where
+        X: SplineNum,
+        R: SplineData<X>,
         _D: Dimension + RemoveAxis,
     {
-        let dim = rhs.raw_dim();
-        let len = dim[0];
-        let mut rhs_left = rhs.index_axis(AX0, 0).into_owned();
-        for i in 1..len {
-            let w = a_low[i] / a_mid[i - 1];
-            a_mid[i] -= w * a_up[i - 1];
-
-            let rhs = rhs.index_axis_mut(AX0, i);
-            Zip::from(rhs)
-                .and(rhs_left.view_mut())
-                .for_each(|rhs, rhs_left| {
-                    let new_rhs = *rhs - w * *rhs_left;
-                    *rhs = new_rhs;
-                    *rhs_left = new_rhs;
+        let len = a_mid.len();
+        let mut ab = zeroed_view(band, Ix2(3, len));
+        // the band storage shifts the upper diagonal right and the lower diagonal left
+        ab.slice_mut(s![0, 1..]).assign(&a_up.slice(s![..len - 1]));
+        ab.row_mut(1).assign(&a_mid);
+        ab.slice_mut(s![2, ..len - 1]).assign(&a_low.slice(s![1..]));
+        solve_banded(1, 1, ab, rhs.view_mut());
+        k.assign(&rhs);
+    }
+
+    /// Fit the natural smoothing spline with the Reinsch algorithm, see
+    /// [`derivative_smoothing`](CubicSpline::derivative_smoothing)
+    ///
+    /// The second derivatives `gamma` at the inner knots solve the pentadiagonal system
+    /// `(R + lambda * QᵀQ) * gamma = Qᵀ * y`, the smoothed values are `y - lambda * Q * gamma`.
+    ///
+    fn smoothing_spline<X, Sd, _D>(
+        x: &Array1<X>,
+        data: &ArrayBase<Sd, _D>,
+        lambda: X,
+    ) -> SmoothingSpline<Sd::Elem, _D>
+    where
+        X: SplineNum,
+        Sd: Data,
+        Sd::Elem: SplineData<X>,
+        _D: Dimension + RemoveAxis,
+    {
+        let one = X::one();
+        let three: X = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let six: X = cast(6.0).unwrap_or_else(|| unimplemented!());
+        let inner = x.len() - 2;
+        let h = Zip::from(x.windows(2)).map_collect(|x| x[1] - x[0]);
+
+        // column j of Q has its entries in the rows j, j + 1 and j + 2
+        let q = Array2::from_shape_fn((inner, 3), |(j, r)| match r {
+            0 => one / h[j],
+            1 => -one / h[j] - one / h[j + 1],
+            _ => one / h[j + 1],
+        });
+
+        // R + lambda * QᵀQ, with two diagonals above and below the main diagonal
+        let mut ab = Array2::zeros((5, inner));
+        for j in 0..inner {
+            for k in j.saturating_sub(2)..(j + 3).min(inner) {
+                // the columns j and k overlap in the rows max(j, k)..=min(j, k) + 2
+                let qtq = (j.max(k)..=j.min(k) + 2).fold(X::zero(), |acc, row| {
+                    acc + q[[j, row - j]] * q[[k, row - k]]
                 });
+                let r = if j == k {
+                    (h[j] + h[j + 1]) / three
+                } else if j.abs_diff(k) == 1 {
+                    h[j.max(k)] / six
+                } else {
+                    X::zero()
+                };
+                ab[[2 + j - k, k]] = r + lambda * qtq;
+            }
         }
 
-        Zip::from(k.index_axis_mut(AX0, len - 1))
-            .and(rhs.index_axis(AX0, len - 1))
-            .for_each(|k, &rhs| {
-                *k = rhs / a_mid[len - 1];
-            });
-
-        let mut k_right = k.index_axis(AX0, len - 1).into_owned();
-        for i in (0..len - 1).rev() {
-            Zip::from(k.index_axis_mut(AX0, i))
-                .and(k_right.view_mut())
-                .and(rhs.index_axis(AX0, i))
-                .for_each(|k, k_right, &rhs| {
-                    let new_k = (rhs - a_up[i] * *k_right) / a_mid[i];
-                    *k = new_k;
-                    *k_right = new_k;
-                })
+        let mut dim = data.raw_dim();
+        dim[0] = inner;
+        let mut gamma = Array::zeros(dim);
+        for (j, mut gamma) in gamma.axis_iter_mut(AX0).enumerate() {
+            for r in 0..3 {
+                let q = q[[j, r]];
+                Zip::from(&mut gamma)
+                    .and(data.index_axis(AX0, j + r))
+                    .for_each(|gamma, &y| *gamma = *gamma + y * q);
+            }
+        }
+        solve_banded(2, 2, ab, gamma.view_mut());
+
+        let mut values = data.to_owned();
+        for (j, column) in gamma.axis_iter(AX0).enumerate() {
+            for r in 0..3 {
+                let w = lambda * q[[j, r]];
+                Zip::from(values.index_axis_mut(AX0, j + r))
+                    .and(&column)
+                    .for_each(|g, &gamma| *g = *g - gamma * w);
+            }
+        }
+        let mut second_deriv = Array::zeros(data.raw_dim());
+        second_deriv
+            .slice_axis_mut(AX0, Slice::new(1, Some(-1), 1))
+            .assign(&gamma);
+        SmoothingSpline {
+            values,
+            second_deriv,
         }
     }
 
     /// create a cubic-spline interpolation stratgy
     pub fn new() -> Self {
         Self {
-            extrapolate: false,
+            extrapolate_low: false,
+            extrapolate_high: false,
+            extrapolation_order: 3,
             boundary: BoundaryCondition::NotAKnot,
+            breaks: Vec::new(),
+            derivative_smoothing: None,
+            symmetry: None,
+            clamp_to_data: None,
+            limit_monotone: None,
         }
     }
 
     /// does the strategy extrapolate? Default is `false`
-    pub fn extrapolate(mut self, extrapolate: bool) -> Self {
-        self.extrapolate = extrapolate;
+    ///
+    /// This sets both sides, see [`extrapolate_low`](CubicSpline::extrapolate_low)
+    /// and [`extrapolate_high`](CubicSpline::extrapolate_high).
+    pub fn extrapolate(self, extrapolate: bool) -> Self {
+        self.extrapolate_low(extrapolate)
+            .extrapolate_high(extrapolate)
+    }
+
+    /// does the strategy extrapolate below the first knot? Default is `false`
+    ///
+    /// A [`BoundaryCondition::Periodic`] spline can only extrapolate on both sides,
+    /// the build fails when only one side is enabled.
+    pub fn extrapolate_low(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_low = extrapolate;
+        self
+    }
+
+    /// does the strategy extrapolate above the last knot? Default is `false`
+    ///
+    /// See [`extrapolate_low`](CubicSpline::extrapolate_low)
+    pub fn extrapolate_high(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_high = extrapolate;
+        self
+    }
+
+    /// The order of the polynomial used when extrapolating. Default is `3`
+    ///
+    /// Outside of the data range the spline is continued with the taylor
+    /// polynomial of the boundary segment at the first or last knot:
+    ///  - `1` continues linearly with the slope at the boundary knot
+    ///  - `2` adds the curvature at the boundary knot
+    ///  - `3` continues the full cubic of the boundary segment
+    ///
+    /// Lower orders grow slower and are more stable far away from the data.
+    /// This has no effect unless [`extrapolate`](CubicSpline::extrapolate) is enabled,
+    /// and it does not apply to [`BoundaryCondition::Periodic`] splines.
+    ///
+    /// The build fails when the order is not one of `1`, `2` or `3`.
+    pub fn extrapolation_order(mut self, order: u8) -> Self {
+        self.extrapolation_order = order;
         self
     }
 
@@ -739,17 +1254,275 @@ where
         self.boundary = boundary;
         self
     }
+
+    /// Break the spline at the given knot indices.
+    ///
+    /// The spline is solved independently on each run of knots between two breaks,
+    /// with the configured boundary condition at both ends of every run.
+    /// Neighbouring runs share the knot at the break, so the result is continuous
+    /// but the derivatives may jump at the breaks. Exactly at a break the run
+    /// right of the break is used.
+    ///
+    /// Breaks must be inner knot indices and each run needs at least 3 knots.
+    /// Breaks can not be combined with [`BoundaryCondition::Periodic`].
+    pub fn breaks(mut self, breaks: &[usize]) -> Self {
+        self.breaks = breaks.to_vec();
+        self
+    }
+
+    /// Smooth the derivative returned by [`interp_derivative`](Interp1D::interp_derivative).
+    ///
+    /// Differentiating a spline which passes through noisy data amplifies the noise.
+    /// With this option the derivative is taken from the natural smoothing spline `g`
+    /// instead, which minimizes
+    /// ```text
+    /// sum((y[i] - g(x[i]))²) + lambda * integral(g''(x)² dx)
+    /// ```
+    /// `lambda = 0` gives the natural interpolating spline, large values approach
+    /// the least squares line. `lambda` has the unit of `x³`, so it scales with
+    /// the third power of the knot spacing. It is converted to the type of the x axis
+    /// when the spline is built.
+    ///
+    /// The interpolated values are not affected, they still pass through the data.
+    /// The build fails when `lambda` is negative, not finite or can not be converted.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// let x = Array::linspace(0.0, 3.0, 31);
+    /// // a line with a small zigzag on top
+    /// let y = Array::from_shape_fn(31, |i| 2.0 * x[i] + if i % 2 == 0 { 0.01 } else { -0.01 });
+    /// let interp = Interp1DBuilder::new(y)
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new().derivative_smoothing(1.0))
+    ///     .build()
+    ///     .unwrap();
+    /// let slope: f64 = interp.interp_derivative(1.55).unwrap().into_scalar();
+    /// assert!((slope - 2.0).abs() < 1e-2);
+    /// ```
+    pub fn derivative_smoothing(mut self, lambda: f64) -> Self {
+        self.derivative_smoothing = Some(lambda);
+        self
+    }
+
+    /// The data is even or odd symmetric about the first knot.
+    ///
+    /// The spline is then the spline through the data mirrored at the first knot,
+    /// with the configured boundary condition at both outer ends. By symmetry this
+    /// is the same as replacing the left boundary condition with
+    /// [`Clamped`](SingleBoundary::Clamped) for [`Even`](Symmetry::Even) data
+    /// and [`Natural`](SingleBoundary::Natural) for [`Odd`](Symmetry::Odd) data,
+    /// so the mirrored points are never constructed.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// let x = Array::linspace(0.0, 3.0, 7);
+    /// let interp = Interp1DBuilder::new(x.mapv(f64::cos))
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new().symmetry(Symmetry::Even))
+    ///     .build()
+    ///     .unwrap();
+    /// let (_, slope) = interp.interp_value_and_slope(0.0).unwrap();
+    /// assert_eq!(slope.into_scalar(), 0.0);
+    /// ```
+    ///
+    /// The build fails when this is combined with [`BoundaryCondition::Periodic`]
+    /// or with [`breaks`](CubicSpline::breaks).
+    pub fn symmetry(mut self, symmetry: Symmetry) -> Self {
+        self.symmetry = Some(symmetry);
+        self
+    }
+
+    /// Clamp the interpolated values between the data of the two knots
+    /// around the query. Default is `false`
+    ///
+    /// A spline can overshoot the data, e.g. next to a step. Clamping guards against this
+    /// without giving up the cubic interpolation where it stays inside the data.
+    /// Where the clamping is active the interpolant is flat, so it is not
+    /// continuously differentiable at the points where the clamping starts and ends.
+    /// Unlike a monotonic interpolation the shape of the spline is not changed,
+    /// only the overshooting part is cut off.
+    ///
+    /// The values, slopes and bounds like [`interp_interval`](Interp1D::interp_interval)
+    /// describe the clamped spline, the slope is zero where the clamping is active.
+    /// Methods which use the coefficients of the cubic, like integrals,
+    /// [`to_tck`](Interp1D::to_tck) or [`bending_energy`](Interp1D::bending_energy),
+    /// return an error for a clamped spline. Extrapolated values are not clamped.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// let step = array![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+    /// let spline = Interp1DBuilder::new(step.view())
+    ///     .strategy(CubicSpline::new())
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(spline.interp_scalar(1.5).unwrap() < 0.0);
+    ///
+    /// let clamped = Interp1DBuilder::new(step.view())
+    ///     .strategy(CubicSpline::new().clamp_to_data(true))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(clamped.interp_scalar(1.5).unwrap(), 0.0);
+    /// ```
+    pub fn clamp_to_data(mut self, clamp: bool) -> Self
+    where
+        T: PartialOrd,
+    {
+        fn clamp_between<T: PartialOrd>(value: T, a: T, b: T) -> T {
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+            if value < low {
+                low
+            } else if value > high {
+                high
+            } else {
+                value
+            }
+        }
+        self.clamp_to_data = clamp.then_some(clamp_between::<T> as ClampFn<T>);
+        self
+    }
+
+    /// Limit the slopes of monotonic data rows, so the spline does not overshoot.
+    /// Default is `false`
+    ///
+    /// Each data row is checked when the spline is built. When the data of a row is
+    /// monotonic (not strictly), the slopes of the spline at the knots are limited
+    /// like in a PCHIP interpolation, following Fritsch and Carlson:
+    /// a slope with the wrong sign or next to a flat segment is set to zero, and
+    /// the magnitude is at most three times the smaller secant slope of the two
+    /// neighbouring segments. The segments with limited slopes are the cubic Hermite
+    /// polynomials of the new slopes, so the interpolant of these rows is monotonic.
+    /// Rows which are not monotonic keep the spline.
+    ///
+    /// Where a slope is limited the spline is only continuously differentiable,
+    /// the second derivative may jump at the knot. The rows which are limited are
+    /// reported by [`monotone_limited`](Interp1D::monotone_limited).
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// let step = array![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+    /// let interp = Interp1DBuilder::new(step)
+    ///     .strategy(CubicSpline::new().limit_monotone(true))
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(interp.monotone_limited().into_scalar());
+    /// assert!(interp.is_monotone().unwrap());
+    /// ```
+    pub fn limit_monotone(mut self, limit: bool) -> Self
+    where
+        T: PartialOrd + Copy + Zero + Add<Output = T> + Neg<Output = T>,
+    {
+        self.limit_monotone = limit.then_some(limit_slopes::<T> as LimitFn<T>);
+        self
+    }
+
+    /// limit the slopes of the monotonic data rows in the coefficients `a` and `b`,
+    /// see [`CubicSpline::limit_monotone`]
+    fn limit_rows<X, Sd>(
+        limit: LimitFn<T>,
+        x: &Array1<X>,
+        data: &ArrayBase<Sd, D>,
+        a: &mut Array<T, D>,
+        b: &mut Array<T, D>,
+        limited: &mut Array<bool, D::Smaller>,
+    ) where
+        X: SplineNum,
+        T: SplineData<X>,
+        Sd: Data<Elem = T>,
+    {
+        let dx = Array1::from_shape_fn(x.len() - 1, |i| x[i + 1] - x[i]);
+        Zip::from(limited)
+            .and(data.lanes(AX0))
+            .and(a.lanes_mut(AX0))
+            .and(b.lanes_mut(AX0))
+            .for_each(|limited, y, mut a, mut b| {
+                let rise = Array1::from_shape_fn(dx.len(), |i| y[i + 1] - y[i]);
+                let secants = Zip::from(&rise)
+                    .and(&dx)
+                    .map_collect(|&rise, &dx| rise / dx);
+                // the slopes in x at the left and the right end of each segment
+                let mut left = Zip::from(&rise)
+                    .and(&a)
+                    .and(&dx)
+                    .map_collect(|&rise, &a, &dx| (rise + a) / dx);
+                let mut right = Zip::from(&rise)
+                    .and(&b)
+                    .and(&dx)
+                    .map_collect(|&rise, &b, &dx| (rise - b) / dx);
+                let (old_left, old_right) = (left.clone(), right.clone());
+                *limited = limit(secants.view(), left.view_mut(), right.view_mut());
+                // only the limited slopes are written back, the others are not rounded
+                for i in 0..dx.len() {
+                    if left[i] != old_left[i] {
+                        a[i] = left[i] * dx[i] - rise[i];
+                    }
+                    if right[i] != old_right[i] {
+                        b[i] = rise[i] - right[i] * dx[i];
+                    }
+                }
+            });
+    }
+
+    /// the boundary condition with the left side replaced according to the symmetry
+    fn symmetric_boundary(
+        &self,
+        symmetry: Symmetry,
+        dim: D,
+    ) -> Result<BoundaryCondition<T, D>, BuilderError>
+    where
+        T: Clone,
+    {
+        if !self.breaks.is_empty() {
+            return Err(BuilderError::ValueError(
+                "symmetry can not be combined with breaks".into(),
+            ));
+        }
+        let row = |right| RowBoundary::Mixed {
+            left: match symmetry {
+                Symmetry::Even => SingleBoundary::Clamped,
+                Symmetry::Odd => SingleBoundary::Natural,
+            },
+            right,
+        };
+        let mut shape = dim;
+        shape[0] = 1;
+        let bounds = match &self.boundary {
+            BoundaryCondition::Periodic => {
+                return Err(BuilderError::ValueError(
+                    "symmetry can not be combined with a periodic boundary condition".into(),
+                ))
+            }
+            BoundaryCondition::NotAKnot => Array::from_elem(shape, row(SingleBoundary::NotAKnot)),
+            BoundaryCondition::Natural => Array::from_elem(shape, row(SingleBoundary::Natural)),
+            BoundaryCondition::Clamped => Array::from_elem(shape, row(SingleBoundary::Clamped)),
+            BoundaryCondition::Individual(bounds) => bounds.map(|bound| match bound {
+                RowBoundary::NotAKnot => row(SingleBoundary::NotAKnot),
+                RowBoundary::Natural => row(SingleBoundary::Natural),
+                RowBoundary::Clamped => row(SingleBoundary::Clamped),
+                RowBoundary::Mixed { right, .. } => row(right.clone()),
+            }),
+        };
+        Ok(BoundaryCondition::Individual(bounds))
+    }
 }
 
 impl<Sd, Sx, D> Interp1DStrategyBuilder<Sd, Sx, D> for CubicSpline<Sd::Elem, D>
 where
     Sd: Data,
-    Sd::Elem: SplineNum,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: SplineData<Sx::Elem>,
+    Sx: Data,
+    Sx::Elem: SplineNum,
     D: Dimension + RemoveAxis,
 {
     const MINIMUM_DATA_LENGHT: usize = 3;
-    type FinishedStrat = CubicSplineStrategy<Sd, D>;
+    type FinishedStrat = CubicSplineStrategy<Sd, D, Sx::Elem>;
 
     fn build<Sx2>(
         self,
@@ -757,75 +1530,1657 @@ where
         data: &ArrayBase<Sd, D>,
     ) -> Result<Self::FinishedStrat, BuilderError>
     where
-        Sx2: Data<Elem = Sd::Elem>,
+        Sx2: Data<Elem = Sx::Elem>,
     {
-        let (a, b) = self.calc_coefficients(x, data)?;
-        let extrapolate = if !self.extrapolate {
-            Extrapolate::No
-        } else if matches!(self.boundary, BoundaryCondition::Periodic) {
-            Extrapolate::Periodic
-        } else {
-            Extrapolate::Yes
+        <Self as Interp1DStrategyBuilder<Sd, Sx, D>>::build_in(self, x, data, &mut Scratch::new())
+    }
+
+    /// Select the columns of [`Individual`](BoundaryCondition::Individual) boundary conditions
+    fn select_columns(mut self, columns: Range<usize>) -> Result<Self, BuilderError> {
+        if let BoundaryCondition::Individual(bounds) = &self.boundary {
+            if bounds.ndim() < 2 || columns.end > bounds.len_of(Axis(1)) {
+                return Err(BuilderError::ShapeError(format!(
+                    "can not select the columns {columns:?} of the boundary conditions with shape {:?}",
+                    bounds.shape()
+                )));
+            }
+            let bounds = bounds.slice_axis(Axis(1), Slice::from(columns)).to_owned();
+            self.boundary = BoundaryCondition::Individual(bounds);
+        }
+        Ok(self)
+    }
+
+    fn build_in<Sx2>(
+        mut self,
+        x: &ArrayBase<Sx2, Ix1>,
+        data: &ArrayBase<Sd, D>,
+        scratch: &mut Scratch<Sx::Elem, Sd::Elem>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        if let Some(symmetry) = self.symmetry {
+            self.boundary = self.symmetric_boundary(symmetry, data.raw_dim())?;
+        }
+        if !(1..=3).contains(&self.extrapolation_order) {
+            return Err(BuilderError::ValueError(format!(
+                "extrapolation order must be 1, 2 or 3, got {}",
+                self.extrapolation_order
+            )));
+        }
+        // work relative to the first knot so large absolute x values
+        // (e.g. timestamps) do not dominate the segment arithmetic
+        let x_offset = x[0];
+        let x_rel = x.mapv(|x| x - x_offset);
+        let (mut a, mut b) = self.calc_coefficients(&x_rel, data, scratch)?;
+        let mut monotone_limited = Array::from_elem(data.raw_dim().remove_axis(AX0), false);
+        if let Some(limit) = self.limit_monotone {
+            Self::limit_rows(limit, &x_rel, data, &mut a, &mut b, &mut monotone_limited);
+        }
+        let derivative_spline = match self.derivative_smoothing {
+            Some(lambda) if !(lambda.is_finite() && lambda >= 0.0) => {
+                return Err(BuilderError::ValueError(format!(
+                    "the derivative smoothing must be finite and not negative, got {lambda}"
+                )));
+            }
+            Some(lambda) => {
+                // a float conversion may overflow to infinity instead of failing
+                let lambda = cast(lambda)
+                    .filter(|&lambda| cast::<Sx::Elem, f64>(lambda).is_some_and(f64::is_finite))
+                    .ok_or_else(|| {
+                        BuilderError::ValueError(format!(
+                            "the derivative smoothing {lambda} can not be converted to the type of x"
+                        ))
+                    })?;
+                Some(Self::smoothing_spline(&x_rel, data, lambda))
+            }
+            None => None,
+        };
+        let breaks = self.runs(x.len())?[1..]
+            .iter()
+            .map(|&(start, _)| start)
+            .collect();
+        let periodic = matches!(self.boundary, BoundaryCondition::Periodic);
+        let extrapolate = match (self.extrapolate_low, self.extrapolate_high) {
+            (false, false) => Extrapolate::No,
+            (true, true) if periodic => Extrapolate::Periodic,
+            (true, true) => Extrapolate::Yes,
+            _ if periodic => {
+                return Err(BuilderError::ValueError(
+                    "a periodic spline must extrapolate on both sides or not at all".into(),
+                ))
+            }
+            (true, false) => Extrapolate::Low,
+            (false, true) => Extrapolate::High,
         };
-        Ok(CubicSplineStrategy { a, b, extrapolate })
+        Ok(CubicSplineStrategy {
+            a,
+            b,
+            extrapolate,
+            extrapolation_order: self.extrapolation_order,
+            x_offset,
+            phase_origin: Sx::Elem::zero(),
+            breaks,
+            derivative_spline,
+            boundary: self.boundary,
+            clamp_to_data: self.clamp_to_data,
+            monotone_limited,
+        })
     }
 }
 
 impl<T, D> Default for CubicSpline<T, D>
 where
     D: Dimension + RemoveAxis,
-    T: SplineNum,
 {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<Sd, Sx, D> Interp1DStrategy<Sd, Sx, D> for CubicSplineStrategy<Sd, D>
+impl<Sd, D, X> PartialEq for CubicSplineStrategy<Sd, D, X>
 where
     Sd: Data,
-    Sd::Elem: SplineNum,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: PartialEq,
     D: Dimension + RemoveAxis,
+    X: PartialEq,
 {
-    fn interp_into(
-        &self,
-        interp: &Interp1D<Sd, Sx, D, Self>,
-        target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
-        x: <Sx>::Elem,
-    ) -> Result<(), InterpolateError> {
-        let in_range = interp.is_in_range(x);
-        if matches!(self.extrapolate, Extrapolate::No) && !in_range {
-            return Err(InterpolateError::OutOfBounds(format!(
-                "x = {x:#?} is not in range",
-            )));
-        }
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a
+            && self.b == other.b
+            && self.extrapolate == other.extrapolate
+            && self.extrapolation_order == other.extrapolation_order
+            && self.x_offset == other.x_offset
+            && self.phase_origin == other.phase_origin
+            && self.breaks == other.breaks
+            && self.derivative_spline == other.derivative_spline
+            && self.clamp_to_data.is_some() == other.clamp_to_data.is_some()
+            && self.monotone_limited == other.monotone_limited
+    }
+}
+
+impl<Sd, D, X> Display for CubicSplineStrategy<Sd, D, X>
+where
+    Sd: Data,
+    D: Dimension + RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let extrapolate = match self.extrapolate {
+            Extrapolate::Yes => "true",
+            Extrapolate::Low => "low",
+            Extrapolate::High => "high",
+            Extrapolate::No => "false",
+            Extrapolate::Periodic => "periodic",
+        };
+        write!(f, "CubicSpline (extrapolate: {extrapolate})")
+    }
+}
 
+impl<Sd, D, X> CubicSplineStrategy<Sd, D, X>
+where
+    Sd: Data,
+    Sd::Elem: SplineData<X>,
+    D: Dimension + RemoveAxis,
+    X: SplineNum,
+{
+    /// Find the segment containing `x` with respect to the extrapolation setting.
+    ///
+    /// **returns** the index left of the segment and the position `t` of `x`
+    /// inside the segment normalized to the segment width.
+    fn locate<Sx>(
+        &self,
+        interp: &Interp1D<Sd, Sx, D, Self>,
+        x: X,
+    ) -> Result<(usize, X), InterpolateError>
+    where
+        Sx: Data<Elem = X>,
+    {
+        let x = x + self.phase_origin;
+        let in_range = interp.is_in_range(x);
+        let (low, high) = match self.extrapolate {
+            Extrapolate::Yes | Extrapolate::Periodic => (true, true),
+            Extrapolate::Low => (true, false),
+            Extrapolate::High => (false, true),
+            Extrapolate::No => (false, false),
+        };
+        interp.check_range(x, low, high)?;
+
+        let x0 = self.x_offset;
+        let mut x_rel = x - x0;
         let mut x = x;
         if matches!(self.extrapolate, Extrapolate::Periodic) && !in_range {
-            let x0 = interp.x[0];
-            let xn = interp.x[interp.x.len() - 1];
-            x = ((x - x0).rem_euclid(&(xn - x0))) + x0;
+            let period = interp.x[interp.x.len() - 1] - x0;
+            x_rel = x_rel.rem_euclid(&period);
+            // tiny negative offsets can round up to a full period,
+            // both ends of the period are the same point
+            if x_rel >= period {
+                x_rel = X::zero();
+            }
+            x = x_rel + x0;
+        }
+
+        // the differences to `x0` are exact, while the remapped `x` is rounded
+        // to the resolution of `x0`. The exact relative position decides the segment.
+        let mut idx = interp.get_index_left_of(x);
+        if idx > 0 && x_rel < interp.x[idx] - x0 {
+            idx -= 1;
+        } else if idx + 2 < interp.x.len() && x_rel >= interp.x[idx + 1] - x0 {
+            idx += 1;
         }
+        let x_left = interp.x[idx] - x0;
+        let x_right = interp.x[idx + 1] - x0;
+        Ok((idx, (x_rel - x_left) / (x_right - x_left)))
+    }
+
+    /// Evaluate the segment at the normalized position `t`.
+    ///
+    /// **returns** the value and the derivative with respect to `t`.
+    /// Outside of the segment the taylor polynomial at the closer knot is truncated
+    /// to the [extrapolation order](CubicSpline::extrapolation_order).
+    fn eval_segment(
+        &self,
+        y_left: Sd::Elem,
+        y_right: Sd::Elem,
+        a: Sd::Elem,
+        b: Sd::Elem,
+        t: X,
+    ) -> (Sd::Elem, Sd::Elem) {
+        let one: X = cast(1.0).unwrap_or_else(|| unimplemented!());
+        let two: X = cast(2.0).unwrap_or_else(|| unimplemented!());
+        if self.extrapolation_order >= 3 || (t >= X::zero() && t <= one) {
+            // written relative to `y_left`, so constant data is interpolated exactly
+            let value = y_left + (y_right - y_left) * t + (a * (one - t) + b * t) * (t * (one - t));
+            let slope = y_right - y_left
+                + (a * (one - t) + b * t) * (one - two * t)
+                + (b - a) * (t * (one - t));
+            if let Some(clamp) = self.clamp_to_data {
+                if t >= X::zero() && t <= one {
+                    let clamped = clamp(value, y_left, y_right);
+                    if clamped != value {
+                        return (clamped, Sd::Elem::zero());
+                    }
+                }
+            }
+            return (value, slope);
+        }
+
+        // taylor coefficients of the segment at the closer knot in terms of `s`
+        let (c0, c1, c2, s) = if t < X::zero() {
+            (y_left, y_right - y_left + a, b - a - a, t)
+        } else {
+            (y_right, y_right - y_left - b, a - b - b, t - one)
+        };
+        let mut value = c0 + c1 * s;
+        let mut slope = c1;
+        if self.extrapolation_order == 2 {
+            value = value + c2 * (s * s);
+            slope = slope + c2 * (two * s);
+        }
+        (value, slope)
+    }
 
-        let idx = interp.get_index_left_of(x);
-        let (x_left, data_left) = interp.index_point(idx);
-        let (x_right, data_right) = interp.index_point(idx + 1);
+    /// The integral of the segment from its left knot to the normalized position `t`
+    /// with respect to `t`, multiply by the segment width for the integral in x.
+    fn integrate_segment(
+        &self,
+        y_left: Sd::Elem,
+        y_right: Sd::Elem,
+        a: Sd::Elem,
+        b: Sd::Elem,
+        t: X,
+    ) -> Sd::Elem {
+        let two: X = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: X = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let four: X = cast(4.0).unwrap_or_else(|| unimplemented!());
+        let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+        // the antiderivatives of t(1-t)² and t²(1-t), the weights of `a` and `b`
+        let a_weight = t2 / two - two * t3 / three + t4 / four;
+        let b_weight = t3 / three - t4 / four;
+        y_left * t + (y_right - y_left) * (t2 / two) + a * a_weight + b * b_weight
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategy<Sd, Sx, D> for CubicSplineStrategy<Sd, D, Sx::Elem>
+where
+    Sd: Data,
+    Sd::Elem: SplineData<Sx::Elem>,
+    Sx: Data,
+    Sx::Elem: SplineNum,
+    D: Dimension + RemoveAxis,
+{
+    const NAME: &'static str = "CubicSpline";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        match self.extrapolate {
+            Extrapolate::Yes => ExtrapolateMode::Yes,
+            Extrapolate::Low => ExtrapolateMode::Low,
+            Extrapolate::High => ExtrapolateMode::High,
+            Extrapolate::No => ExtrapolateMode::No,
+            Extrapolate::Periodic => ExtrapolateMode::Periodic,
+        }
+    }
+
+    fn memory_bytes(&self) -> usize {
+        let smoothing = self
+            .derivative_spline
+            .as_ref()
+            .map_or(0, |spline| spline.values.len() + spline.second_deriv.len());
+        let boundary = match &self.boundary {
+            BoundaryCondition::Individual(bounds) => {
+                bounds.len() * std::mem::size_of::<RowBoundary<Sd::Elem>>()
+            }
+            _ => 0,
+        };
+        (self.a.len() + self.b.len() + smoothing) * std::mem::size_of::<Sd::Elem>()
+            + self.breaks.capacity() * std::mem::size_of::<usize>()
+            + boundary
+    }
+
+    fn interp_into(
+        &self,
+        interp: &Interp1D<Sd, Sx, D, Self>,
+        target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
+        x: <Sx>::Elem,
+    ) -> Result<(), InterpolateError> {
+        let (idx, t) = self.locate(interp, x)?;
+        let (_, data_left) = interp.index_point(idx);
+        let (_, data_right) = interp.index_point(idx + 1);
         let a_left = self.a.index_axis(AX0, idx);
         let b_left = self.b.index_axis(AX0, idx);
-        let one: Sd::Elem = cast(1.0).unwrap_or_else(|| unimplemented!());
 
-        let t = (x - x_left) / (x_right - x_left);
         Zip::from(data_left)
             .and(data_right)
             .and(a_left)
             .and(b_left)
             .and(target)
             .for_each(|&y_left, &y_right, &a_left, &b_left, y| {
-                *y = (one - t) * y_left
-                    + t * y_right
-                    + t * (one - t) * (a_left * (one - t) + b_left * t);
+                *y = self.eval_segment(y_left, y_right, a_left, b_left, t).0;
             });
         Ok(())
     }
 }
+
+impl<Sd, Sx, D> Interp1D<Sd, Sx, D, CubicSplineStrategy<Sd, D, Sx::Elem>>
+where
+    Sd: Data,
+    Sd::Elem: SplineData<Sx::Elem>,
+    Sx: Data,
+    Sx::Elem: SplineNum,
+    D: Dimension + RemoveAxis,
+{
+    /// Returns an error when the values are [clamped to the data](CubicSpline::clamp_to_data),
+    /// the coefficients of the cubic then do not describe the interpolant.
+    fn check_unclamped(&self) -> Result<(), BuilderError> {
+        if self.strategy.clamp_to_data.is_some() {
+            return Err(BuilderError::ValueError(
+                "the cubic does not describe the spline when the values are clamped".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Offset all subsequent queries by `x0`, so querying `x` returns the value at `x + x0`.
+    ///
+    /// The offset is applied before the periodic remap, for a
+    /// [`Periodic`](BoundaryCondition::Periodic) spline with extrapolation
+    /// this rotates the signal without rebuilding the interpolator.
+    /// For other splines the shifted query must still be in range.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let y = array![0.0, 1.0, 0.0, -1.0, 0.0];
+    /// let mut interpolator = Interp1DBuilder::new(y)
+    ///     .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic).extrapolate(true))
+    ///     .build().unwrap();
+    ///
+    /// interpolator.set_phase_origin(3.0);
+    /// let result = interpolator.interp_scalar(2.0).unwrap();
+    /// # assert_abs_diff_eq!(result, 1.0, epsilon=1e-12);
+    /// ```
+    pub fn set_phase_origin(&mut self, x0: Sx::Elem) {
+        self.strategy.phase_origin = x0;
+    }
+
+    /// The current phase origin, see [`set_phase_origin`](Interp1D::set_phase_origin)
+    pub fn phase_origin(&self) -> Sx::Elem {
+        self.strategy.phase_origin
+    }
+
+    /// The boundary conditions the spline was solved with, for each data row,
+    /// e.g. to debug a complex boundary setup.
+    ///
+    /// The result has the shape of the data with a first axis of length `1`,
+    /// like the array of [`BoundaryCondition::Individual`]. Each entry is a
+    /// [`RowBoundary::Mixed`] with the conditions as they are applied in the solver:
+    ///  - [`Natural`](SingleBoundary::Natural) is reported as `SecondDeriv(0)`
+    ///  - [`Clamped`](SingleBoundary::Clamped) is reported as `FirstDeriv(0)`
+    ///  - the left side of a [`symmetry`](CubicSpline::symmetry) is included
+    ///  - a [`Periodic`](BoundaryCondition::Periodic) spline can not be described
+    ///    per row, it is reported as the first derivative at the first knot on both
+    ///    sides, which gives the same spline
+    ///
+    /// With [`breaks`](CubicSpline::breaks) the conditions apply at both ends of every run.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// let interpolator = Interp1DBuilder::new(array![[0.0, 1.0], [1.0, 0.0], [3.0, 2.0]])
+    ///     .strategy(CubicSpline::new().boundary(BoundaryCondition::Clamped))
+    ///     .build()
+    ///     .unwrap();
+    /// let clamped = RowBoundary::Mixed {
+    ///     left: SingleBoundary::FirstDeriv(0.0),
+    ///     right: SingleBoundary::FirstDeriv(0.0),
+    /// };
+    /// assert_eq!(interpolator.effective_boundaries(), array![[clamped.clone(), clamped]]);
+    /// ```
+    pub fn effective_boundaries(&self) -> Array<RowBoundary<Sd::Elem>, D> {
+        let specialize =
+            |bound: RowBoundary<Sd::Elem>| match InternalBoundary::from(bound).specialize() {
+                InternalBoundary::Mixed { left, right } => RowBoundary::Mixed {
+                    left: left.specialize(),
+                    right: right.specialize(),
+                },
+                _ => unreachable!(),
+            };
+        let mut shape = self.data.raw_dim();
+        shape[0] = 1;
+        match &self.strategy.boundary {
+            BoundaryCondition::NotAKnot => {
+                Array::from_elem(shape, specialize(RowBoundary::NotAKnot))
+            }
+            BoundaryCondition::Natural => Array::from_elem(shape, specialize(RowBoundary::Natural)),
+            BoundaryCondition::Clamped => Array::from_elem(shape, specialize(RowBoundary::Clamped)),
+            BoundaryCondition::Individual(bounds) => bounds.map(|bound| specialize(bound.clone())),
+            BoundaryCondition::Periodic => {
+                let dx = self.x[1] - self.x[0];
+                let mut bounds = Array::from_elem(shape, RowBoundary::NotAKnot);
+                Zip::from(bounds.index_axis_mut(AX0, 0))
+                    .and(self.data.index_axis(AX0, 0))
+                    .and(self.data.index_axis(AX0, 1))
+                    .and(self.strategy.a.index_axis(AX0, 0))
+                    .for_each(|bound, &y_0, &y_1, &a_0| {
+                        let slope = (y_1 - y_0 + a_0) / dx;
+                        *bound = RowBoundary::Mixed {
+                            left: SingleBoundary::FirstDeriv(slope),
+                            right: SingleBoundary::FirstDeriv(slope),
+                        };
+                    });
+                bounds
+            }
+        }
+    }
+
+    /// The data rows whose slopes were limited to keep the interpolant monotonic,
+    /// see [`CubicSpline::limit_monotone`].
+    ///
+    /// The result has the shape of the data with the first axis removed. A row is
+    /// `false` when the limiting is not enabled or when the data of the row is not monotonic.
+    pub fn monotone_limited(&self) -> ArrayView<'_, bool, D::Smaller> {
+        self.strategy.monotone_limited.view()
+    }
+
+    /// Calculate the interpolated values and the slope (first derivative)
+    /// at `x` with a single segment lookup.
+    ///
+    /// Returns `(value, slope)`, both with the shape of the data with the
+    /// first axis removed.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// // the not-a-knot spline through three points is a parabola
+    /// let y = array![0.5, 0.0, 3.0];
+    /// let x = array![-1.0, 0.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(y)
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build().unwrap();
+    ///
+    /// let (value, slope) = interpolator.interp_value_and_slope(1.0).unwrap();
+    /// # assert_abs_diff_eq!(value, array![0.25].remove_axis(Axis(0)), epsilon=1e-14);
+    /// # assert_abs_diff_eq!(slope, array![0.625].remove_axis(Axis(0)), epsilon=1e-14);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn interp_value_and_slope(
+        &self,
+        x: Sx::Elem,
+    ) -> Result<(Array<Sd::Elem, D::Smaller>, Array<Sd::Elem, D::Smaller>), InterpolateError> {
+        self.check_gaps(x)?;
+        let (idx, t) = self.strategy.locate(self, x)?;
+        let (x_left, data_left) = self.index_point(idx);
+        let (x_right, data_right) = self.index_point(idx + 1);
+        let a_left = self.strategy.a.index_axis(AX0, idx);
+        let b_left = self.strategy.b.index_axis(AX0, idx);
+        let dx = x_right - x_left;
+
+        let dim = self.data.raw_dim().remove_axis(AX0);
+        let mut value = Array::zeros(dim.clone());
+        let mut slope = Array::zeros(dim);
+        Zip::from(data_left)
+            .and(data_right)
+            .and(a_left)
+            .and(b_left)
+            .and(&mut value)
+            .and(&mut slope)
+            .for_each(|&y_left, &y_right, &a_left, &b_left, y, dy| {
+                let (value, slope) = self
+                    .strategy
+                    .eval_segment(y_left, y_right, a_left, b_left, t);
+                *y = value;
+                *dy = slope / dx;
+            });
+        Ok((value, slope))
+    }
+
+    /// The first derivative of the spline at `x`.
+    ///
+    /// When [`derivative_smoothing`](CubicSpline::derivative_smoothing) is set this is
+    /// the derivative of the smoothing spline, otherwise the slope of
+    /// [`interp_value_and_slope`](Interp1D::interp_value_and_slope).
+    /// The result has the shape of the data with the first axis removed.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let y = array![0.5, 0.0, 3.0];
+    /// let x = array![-1.0, 0.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(y)
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build().unwrap();
+    ///
+    /// let slope = interpolator.interp_derivative(1.0).unwrap();
+    /// # assert_abs_diff_eq!(slope, array![0.625].remove_axis(Axis(0)), epsilon=1e-14);
+    /// ```
+    pub fn interp_derivative(
+        &self,
+        x: Sx::Elem,
+    ) -> Result<Array<Sd::Elem, D::Smaller>, InterpolateError> {
+        let SmoothingSpline {
+            values,
+            second_deriv,
+        } = match &self.strategy.derivative_spline {
+            Some(spline) => spline,
+            None => return self.interp_value_and_slope(x).map(|(_, slope)| slope),
+        };
+        self.check_gaps(x)?;
+        let (idx, t) = self.strategy.locate(self, x)?;
+        let one: Sx::Elem = cast(1.0).unwrap_or_else(|| unimplemented!());
+        let three: Sx::Elem = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let six: Sx::Elem = cast(6.0).unwrap_or_else(|| unimplemented!());
+        let h = self.x[idx + 1] - self.x[idx];
+        let s = one - t;
+        let w_left = (one - three * s * s) * h / six;
+        let w_right = (three * t * t - one) * h / six;
+
+        let mut slope = Array::zeros(self.data.raw_dim().remove_axis(AX0));
+        Zip::from(&mut slope)
+            .and(values.index_axis(AX0, idx))
+            .and(values.index_axis(AX0, idx + 1))
+            .and(second_deriv.index_axis(AX0, idx))
+            .and(second_deriv.index_axis(AX0, idx + 1))
+            .for_each(|dy, &g_left, &g_right, &m_left, &m_right| {
+                *dy = (g_right - g_left) / h + m_left * w_left + m_right * w_right;
+            });
+        Ok(slope)
+    }
+
+    /// The integral of the spline from the first knot to each point in `xs`.
+    ///
+    /// The integrals of all segments are accumulated once in a forward sweep,
+    /// each query then only adds the part of its own segment. `xs` does not need to be sorted.
+    /// The result has the shape of the data with the first axis replaced by the lenght of `xs`.
+    /// The [phase origin](Interp1D::set_phase_origin) is not applied to `xs`.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// // the spline reproduces y = x², the integral is x³ / 3
+    /// let x = array![0.0, 1.0, 2.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(x.mapv(|x| x * x))
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build().unwrap();
+    ///
+    /// let integral = interpolator.cumulative_integral(&array![1.5, 3.0]).unwrap();
+    /// # assert_abs_diff_eq!(integral, array![1.125, 9.0], epsilon=1e-12);
+    /// ```
+    ///
+    /// Masked gaps wider than [`max_gap`](crate::interp1d::Interp1DBuilder::max_gap)
+    /// are not integrated, they add nothing to the integral of the points beyond them.
+    ///
+    /// # Errors
+    ///  - When a point in `xs` is outside of the x range or inside a masked gap,
+    ///    the integral does not extrapolate.
+    ///  - When the values are [clamped to the data](CubicSpline::clamp_to_data).
+    pub fn cumulative_integral<Sq>(
+        &self,
+        xs: &ArrayBase<Sq, Ix1>,
+    ) -> Result<Array<Sd::Elem, D>, InterpolateError>
+    where
+        Sq: Data<Elem = Sx::Elem>,
+    {
+        self.check_unclamped()?;
+        let strategy = &self.strategy;
+        let at_knots = self.integral_at_knots();
+
+        let mut dim = self.data.raw_dim();
+        dim[0] = xs.len();
+        let mut integrals = Array::zeros(dim);
+        for (&x, mut target) in xs.iter().zip(integrals.axis_iter_mut(AX0)) {
+            self.check_range(x, false, false)?;
+            self.check_gaps(x)?;
+            let idx = self.get_index_left_of(x);
+            let (x_left, y_left) = self.index_point(idx);
+            let (x_right, y_right) = self.index_point(idx + 1);
+            if x == x_right {
+                // the segment may be a masked gap
+                target.assign(&at_knots.index_axis(AX0, idx + 1));
+                continue;
+            }
+            let width = x_right - x_left;
+            let t = (x - x_left) / width;
+            Zip::from(target)
+                .and(at_knots.index_axis(AX0, idx))
+                .and(y_left)
+                .and(y_right)
+                .and(strategy.a.index_axis(AX0, idx))
+                .and(strategy.b.index_axis(AX0, idx))
+                .for_each(|integral, &prev, &y_left, &y_right, &a, &b| {
+                    *integral = prev + strategy.integrate_segment(y_left, y_right, a, b, t) * width;
+                });
+        }
+        Ok(integrals)
+    }
+
+    /// The linear interpolator of the integral of the spline from the first knot,
+    /// see [`Interp1D::<_, _, _, Linear>::to_cumulative`](Interp1D::to_cumulative).
+    ///
+    /// The values at the knots are the exact integrals of the spline, between the knots
+    /// the integral is interpolated linearly, so its derivative is not the spline.
+    /// A spline through non negative data can undershoot zero, then the cumulative
+    /// data can fall. The integral is constant over a masked gap.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// // the spline reproduces y = x², the integral is x³ / 3
+    /// let x = array![0.0, 1.0, 2.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(x.mapv(|x| x * x))
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build()
+    ///     .unwrap();
+    /// let cumulative = interpolator.to_cumulative().unwrap();
+    /// # assert_abs_diff_eq!(cumulative.data(), array![0.0, 1.0 / 3.0, 8.0 / 3.0, 9.0], epsilon = 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    /// When the values are [clamped to the data](CubicSpline::clamp_to_data).
+    #[allow(clippy::type_complexity)]
+    pub fn to_cumulative(
+        &self,
+    ) -> Result<Interp1D<OwnedRepr<Sd::Elem>, OwnedRepr<Sx::Elem>, D, Linear>, BuilderError> {
+        self.check_unclamped()?;
+        Ok(Interp1D {
+            x: self.x.to_owned(),
+            data: self.integral_at_knots(),
+            strategy: Linear::new(),
+            gaps: self.gaps.clone(),
+            closed_upper: self.closed_upper,
+            output_transform: None,
+            data_monotonic: OnceLock::new(),
+        })
+    }
+
+    /// the integral from the first knot to each knot
+    fn integral_at_knots(&self) -> Array<Sd::Elem, D> {
+        let strategy = &self.strategy;
+        let one: Sx::Elem = cast(1.0).unwrap_or_else(|| unimplemented!());
+        let two: Sx::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let mut at_knots = Array::<Sd::Elem, D>::zeros(self.data.raw_dim());
+        for idx in 1..self.x.len() {
+            let (x_left, y_left) = self.index_point(idx - 1);
+            let (x_right, y_right) = self.index_point(idx);
+            let width = x_right - x_left;
+            // a masked gap adds nothing
+            let in_gap = self.check_gaps((x_left + x_right) / two).is_err();
+            let (done, mut next) = at_knots.view_mut().split_at(AX0, idx);
+            Zip::from(next.index_axis_mut(AX0, 0))
+                .and(done.index_axis(AX0, idx - 1))
+                .and(y_left)
+                .and(y_right)
+                .and(strategy.a.index_axis(AX0, idx - 1))
+                .and(strategy.b.index_axis(AX0, idx - 1))
+                .for_each(|integral, &prev, &y_left, &y_right, &a, &b| {
+                    *integral = if in_gap {
+                        prev
+                    } else {
+                        prev + strategy.integrate_segment(y_left, y_right, a, b, one) * width
+                    };
+                });
+        }
+        at_knots
+    }
+
+    /// The second derivative of the spline at each knot.
+    ///
+    /// This is calculated from the spline coefficients and has the same shape as the data.
+    /// The value is taken from the segment right of the knot, for the last knot from the
+    /// segment left of it. Those only differ at [`breaks`](CubicSpline::breaks).
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// // the not-a-knot spline through three points is the parabola 0.375x² - 0.125x
+    /// let y = array![0.5, 0.0, 3.0];
+    /// let x = array![-1.0, 0.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(y)
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build().unwrap();
+    ///
+    /// let second_deriv = interpolator.second_derivatives_at_knots();
+    /// # assert_abs_diff_eq!(second_deriv, array![0.75, 0.75, 0.75], epsilon=1e-12);
+    /// ```
+    pub fn second_derivatives_at_knots(&self) -> Array<Sd::Elem, D> {
+        let two: Sx::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let four: Sx::Elem = cast(4.0).unwrap_or_else(|| unimplemented!());
+
+        let len = self.x.len();
+        let mut second_deriv = Array::zeros(self.data.raw_dim());
+        Zip::from(self.strategy.a.axis_iter(AX0))
+            .and(self.strategy.b.axis_iter(AX0))
+            .and(self.x.windows(2))
+            .and(
+                second_deriv
+                    .slice_axis_mut(AX0, Slice::from(..len - 1))
+                    .axis_iter_mut(AX0),
+            )
+            .for_each(|a, b, x, target| {
+                let dx = x[1] - x[0];
+                Zip::from(target).and(a).and(b).for_each(|y2, &a, &b| {
+                    *y2 = (b * two - a * four) / (dx * dx);
+                });
+            });
+
+        // the right end of the last segment
+        let dx = self.x[len - 1] - self.x[len - 2];
+        Zip::from(second_deriv.index_axis_mut(AX0, len - 1))
+            .and(self.strategy.a.index_axis(AX0, len - 2))
+            .and(self.strategy.b.index_axis(AX0, len - 2))
+            .for_each(|y2, &a, &b| {
+                *y2 = (a * two - b * four) / (dx * dx);
+            });
+        second_deriv
+    }
+
+    /// Export the spline in B-spline form as the tuple `(t, c, k)`
+    ///
+    /// `t` are the knots, `c` the B-spline coefficients along the first axis
+    /// and `k = 3` the degree, as used by SciPy's `splrep`/`splev` and `BSpline`.
+    /// The first and last knot are repeated four times, knots at
+    /// [`breaks`](CubicSpline::breaks) three times and all other knots once.
+    ///
+    /// `c` has `t.len() - k - 1` entries along the first axis. SciPy's `splrep`
+    /// pads the coefficients with `k + 1` zeros, `splev` accepts both.
+    /// The tuple describes the spline inside the range of x, the phase origin is not applied.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// // the not-a-knot spline through three points is the parabola 0.375x² - 0.125x
+    /// let y = array![0.5, 0.0, 3.0];
+    /// let x = array![-1.0, 0.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(y)
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build().unwrap();
+    ///
+    /// let (t, c, k) = interpolator.to_tck().unwrap();
+    /// assert_eq!(t, array![-1.0, -1.0, -1.0, -1.0, 0.0, 3.0, 3.0, 3.0, 3.0]);
+    /// assert_eq!(k, 3);
+    /// # assert_abs_diff_eq!(c, array![0.5, 0.625 / 3.0, -1.375 / 3.0, 0.875, 3.0], epsilon=1e-12);
+    /// ```
+    ///
+    /// # Errors
+    /// When the values are [clamped to the data](CubicSpline::clamp_to_data).
+    #[allow(clippy::type_complexity)]
+    pub fn to_tck(&self) -> Result<(Array1<Sx::Elem>, Array<Sd::Elem, D>, usize), BuilderError> {
+        self.check_unclamped()?;
+        let three: Sx::Elem = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let len = self.x.len();
+
+        // the knots, and for each knot the segment starting at it if that is not empty
+        let mut t = Vec::with_capacity(len + 6 + 2 * self.strategy.breaks.len());
+        let mut segment = Vec::with_capacity(t.capacity());
+        for (idx, &x) in self.x.iter().enumerate() {
+            let multiplicity = if idx == 0 || idx == len - 1 {
+                4
+            } else if self.strategy.breaks.contains(&idx) {
+                3
+            } else {
+                1
+            };
+            for copy in 1..=multiplicity {
+                t.push(x);
+                segment.push((copy == multiplicity && idx < len - 1).then_some(idx));
+            }
+        }
+
+        // each coefficient is the blossom of the polynomial on any segment
+        // inside its support, evaluated at the three inner knots of the support
+        let mut dim = self.data.raw_dim();
+        dim[0] = t.len() - 4;
+        let mut c = Array::zeros(dim);
+        for (i, target) in c.axis_iter_mut(AX0).enumerate() {
+            let idx = (i..i + 4)
+                .find_map(|l| segment[l])
+                .unwrap_or_else(|| unreachable!("every support contains a segment"));
+            let (x_left, data_left) = self.index_point(idx);
+            let (x_right, data_right) = self.index_point(idx + 1);
+            let dx = x_right - x_left;
+            let [u1, u2, u3] = [t[i + 1], t[i + 2], t[i + 3]].map(|u| (u - x_left) / dx);
+            let sum = (u1 + u2 + u3) / three;
+            let pairs = (u1 * u2 + u1 * u3 + u2 * u3) / three;
+            let product = u1 * u2 * u3;
+
+            Zip::from(target)
+                .and(data_left)
+                .and(data_right)
+                .and(self.strategy.a.index_axis(AX0, idx))
+                .and(self.strategy.b.index_axis(AX0, idx))
+                .for_each(|c, &y_left, &y_right, &a, &b| {
+                    // power form of the segment in the normalized coordinate
+                    let c1 = y_right - y_left + a;
+                    let c2 = b - a - a;
+                    let c3 = a - b;
+                    *c = y_left + c1 * sum + c2 * pairs + c3 * product;
+                });
+        }
+        Ok((Array1::from_vec(t), c, 3))
+    }
+}
+
+impl<Sd, Sx, D> Interp1D<Sd, Sx, D, CubicSplineStrategy<Sd, D>>
+where
+    Sd: Data,
+    Sd::Elem: SplineNum,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    /// The bending energy `∫ f''(x)^2 dx` of the spline over the range of x.
+    ///
+    /// This is calculated analytically segment by segment from the spline coefficients.
+    /// Returns one value for each row of the data (the shape of the data with the first axis removed).
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// // the not-a-knot spline through three points is the parabola 0.375x² - 0.125x
+    /// let y = array![0.5, 0.0, 3.0];
+    /// let x = array![-1.0, 0.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(y)
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build().unwrap();
+    ///
+    /// let energy = interpolator.bending_energy().unwrap().into_scalar();
+    /// # assert_relative_eq!(energy, 0.75 * 0.75 * 4.0, epsilon=1e-12);
+    /// ```
+    ///
+    /// # Errors
+    /// When the values are [clamped to the data](CubicSpline::clamp_to_data).
+    pub fn bending_energy(&self) -> Result<Array<Sd::Elem, D::Smaller>, BuilderError> {
+        self.check_unclamped()?;
+        let two: Sd::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: Sd::Elem = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let four: Sd::Elem = cast(4.0).unwrap_or_else(|| unimplemented!());
+
+        let mut energy = Array::zeros(self.data.raw_dim().remove_axis(AX0));
+        Zip::from(self.strategy.a.axis_iter(AX0))
+            .and(self.strategy.b.axis_iter(AX0))
+            .and(self.x.windows(2))
+            .for_each(|a, b, x| {
+                let dx = x[1] - x[0];
+                // in the normalized coordinate t the second derivative is linear,
+                // going from `p` at the left knot to `q` at the right knot
+                Zip::from(&mut energy)
+                    .and(a)
+                    .and(b)
+                    .for_each(|energy, &a, &b| {
+                        let p = two * b - four * a;
+                        let q = two * a - four * b;
+                        *energy = *energy + (p * p + p * q + q * q) / (three * dx * dx * dx);
+                    });
+            });
+        Ok(energy)
+    }
+
+    /// Are the two adjacent segments `seg_a` and `seg_b` the same cubic polynomial?
+    ///
+    /// Segment `i` is the segment from knot `i` to knot `i + 1`. The first, second and third
+    /// derivative of both segments are compared at the shared knot, with a relative
+    /// tolerance of `1e-6` of the largest of them. For multi dimensional data all rows must
+    /// be a single polynomial.
+    ///
+    /// This is e.g. true for the first two and the last two segments of a
+    /// [`NotAKnot`](BoundaryCondition::NotAKnot) spline.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// let interpolator = Interp1DBuilder::new(array![0.0, 2.0, 1.0, 3.0, 0.0])
+    ///     .strategy(CubicSpline::new())
+    ///     .build().unwrap();
+    ///
+    /// assert!(interpolator.is_single_polynomial(0, 1));
+    /// assert!(!interpolator.is_single_polynomial(1, 2));
+    /// assert!(interpolator.is_single_polynomial(2, 3));
+    /// ```
+    ///
+    /// # panics
+    /// When the segments are not adjacent or out of bounds
+    pub fn is_single_polynomial(&self, seg_a: usize, seg_b: usize) -> bool {
+        let (left, right) = (seg_a.min(seg_b), seg_a.max(seg_b));
+        if right != left + 1 || right + 1 >= self.x.len() {
+            panic!("{seg_a} and {seg_b} are not adjacent segments of the spline");
+        }
+        let two: Sd::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let tolerance: Sd::Elem = cast(1e-6).unwrap_or_else(|| unimplemented!());
+        let abs = |v: Sd::Elem| if v < Sd::Elem::zero() { -v } else { v };
+
+        // the derivatives are compared in the normalized coordinate of the right segment
+        let r = (self.x[right + 1] - self.x[right]) / (self.x[left + 1] - self.x[left]);
+        let delta_left = &self.data.index_axis(AX0, left + 1) - &self.data.index_axis(AX0, left);
+        let delta_right = &self.data.index_axis(AX0, right + 1) - &self.data.index_axis(AX0, right);
+        Zip::from(&delta_left)
+            .and(&delta_right)
+            .and(self.strategy.a.index_axis(AX0, left))
+            .and(self.strategy.b.index_axis(AX0, left))
+            .and(self.strategy.a.index_axis(AX0, right))
+            .and(self.strategy.b.index_axis(AX0, right))
+            .all(|&dl, &dr, &al, &bl, &ar, &br| {
+                let from_left = [
+                    (dl - bl) * r,
+                    (al - two * bl) * r * r,
+                    (al - bl) * r * r * r,
+                ];
+                let from_right = [dr + ar, br - two * ar, ar - br];
+                let max = |max: Sd::Elem, &v: &Sd::Elem| if abs(v) > max { abs(v) } else { max };
+                let scale = from_left
+                    .iter()
+                    .chain(&from_right)
+                    .fold(Sd::Elem::zero(), max);
+                from_left
+                    .iter()
+                    .zip(&from_right)
+                    .all(|(&l, &r)| abs(l - r) <= tolerance * scale)
+            })
+    }
+
+    /// The minimum and the maximum of the interpolant over the interval `[x - delta, x + delta]`,
+    /// e.g. to propagate an uncertainty of `x` into the interpolated values.
+    ///
+    /// The extreme values are at the ends of the interval or where the derivative
+    /// is zero. The derivative of each segment is a quadratic, so these points are its roots.
+    /// Segments without a root inside the interval are monotonic and only their end
+    /// values are evaluated. Extrapolated parts of the interval are included.
+    /// Returns one minimum and one maximum for each row of the data.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// // the not-a-knot spline through three points is the parabola 1.5x - 0.5x²
+    /// let interpolator = Interp1DBuilder::new(array![0.0, 1.0, 0.0])
+    ///     .x(array![0.0, 1.0, 3.0])
+    ///     .strategy(CubicSpline::new())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let (min, max) = interpolator.interp_interval(1.6, 0.4).unwrap();
+    /// // the maximum is at the vertex 1.5, not at one of the ends
+    /// # assert_abs_diff_eq!(min.into_scalar(), 1.0, epsilon = 1e-12);
+    /// # assert_abs_diff_eq!(max.into_scalar(), 1.125, epsilon = 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///  - When `delta` is negative or NaN
+    ///  - When the interval is out of range and the spline does not extrapolate,
+    ///    or when it overlaps a masked gap
+    #[allow(clippy::type_complexity)]
+    pub fn interp_interval(
+        &self,
+        x: Sx::Elem,
+        delta: Sx::Elem,
+    ) -> Result<(Array<Sd::Elem, D::Smaller>, Array<Sd::Elem, D::Smaller>), InterpolateError> {
+        let zero = Sd::Elem::zero();
+        let one: Sd::Elem = cast(1.0).unwrap_or_else(|| unimplemented!());
+        let two: Sd::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: Sd::Elem = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let four: Sd::Elem = cast(4.0).unwrap_or_else(|| unimplemented!());
+        let half: Sd::Elem = cast(0.5).unwrap_or_else(|| unimplemented!());
+        // also rejects NaN
+        if !matches!(
+            delta.partial_cmp(&zero),
+            Some(Ordering::Greater | Ordering::Equal)
+        ) {
+            return Err(BuilderError::ValueError(format!(
+                "delta needs to be non negative, got: {delta:?}"
+            ))
+            .into());
+        }
+        let (low, high) = (x - delta, x + delta);
+        if let Some((left, right)) = self
+            .gaps
+            .iter()
+            .find(|&&(left, right)| left < high && low < right)
+        {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "the interval from {low:#?} to {high:#?} overlaps the masked gap between {left:#?} and {right:#?}"
+            )));
+        }
+        // checks the range of both ends
+        self.strategy.locate(self, low)?;
+        self.strategy.locate(self, high)?;
+
+        let origin = self.strategy.phase_origin;
+        let (low, high) = (low + origin, high + origin);
+        let (first, last) = (self.x[0], self.x[self.x.len() - 1]);
+        let mut ranges = vec![(low, high)];
+        if matches!(self.strategy.extrapolate, Extrapolate::Periodic)
+            && !(self.is_in_range(low) && self.is_in_range(high))
+        {
+            let period = last - first;
+            ranges = if high - low >= period {
+                vec![(first, last)]
+            } else {
+                // move the interval into the first period, it may still end in the next one
+                let mut start = (low - first).rem_euclid(&period);
+                if start >= period {
+                    start = zero;
+                }
+                let (low, high) = (first + start, first + start + (high - low));
+                if high <= last {
+                    vec![(low, high)]
+                } else {
+                    vec![(low, last), (first, high - period)]
+                }
+            };
+        }
+
+        let mut min = self.interp(x)?;
+        let mut max = min.clone();
+        let order = self.strategy.extrapolation_order;
+        for (low, high) in ranges {
+            let (idx_low, idx_high) = (self.get_index_left_of(low), self.get_index_left_of(high));
+            for idx in idx_low..=idx_high {
+                let (x_left, x_right) = (self.x[idx], self.x[idx + 1]);
+                // the interval inside of the segment, this only leaves [0, 1] when extrapolating
+                let t_low = if idx == idx_low {
+                    (low - x_left) / (x_right - x_left)
+                } else {
+                    zero
+                };
+                let t_high = if idx == idx_high {
+                    (high - x_left) / (x_right - x_left)
+                } else {
+                    one
+                };
+                Zip::from(&mut min)
+                    .and(&mut max)
+                    .and(self.data.index_axis(AX0, idx))
+                    .and(self.data.index_axis(AX0, idx + 1))
+                    .and(self.strategy.a.index_axis(AX0, idx))
+                    .and(self.strategy.b.index_axis(AX0, idx))
+                    .for_each(|min, max, &y_left, &y_right, &a, &b| {
+                        let mut update = |t: Sd::Elem| {
+                            if t < t_low || t > t_high {
+                                return;
+                            }
+                            let value = self.strategy.eval_segment(y_left, y_right, a, b, t).0;
+                            if value < *min {
+                                *min = value;
+                            }
+                            if value > *max {
+                                *max = value;
+                            }
+                        };
+                        update(t_low);
+                        update(t_high);
+
+                        // the derivative of the cubic with respect to `t` is `c0 + c1 * t + c2 * t²`
+                        let delta = y_right - y_left;
+                        let (c0, c1, c2) = (delta + a, two * b - four * a, three * (a - b));
+                        let is_cubic = |t: Sd::Elem| order >= 3 || (t >= zero && t <= one);
+                        if c2 != zero {
+                            let discriminant = c1 * c1 - four * c2 * c0;
+                            if discriminant >= zero {
+                                let root = discriminant.pow(half);
+                                for t in [(-c1 - root) / (two * c2), (-c1 + root) / (two * c2)] {
+                                    if is_cubic(t) {
+                                        update(t);
+                                    }
+                                }
+                            }
+                        } else if c1 != zero && is_cubic(-c0 / c1) {
+                            update(-c0 / c1);
+                        }
+
+                        if order == 2 {
+                            // the vertices of the taylor parabolas at both knots
+                            let (c1, c2) = (delta + a, b - two * a);
+                            if c2 != zero && -c1 / (two * c2) < zero {
+                                update(-c1 / (two * c2));
+                            }
+                            let (c1, c2) = (delta - b, a - two * b);
+                            if c2 != zero && -c1 / (two * c2) > zero {
+                                update(one - c1 / (two * c2));
+                            }
+                        }
+                    });
+            }
+        }
+        Ok((min, max))
+    }
+}
+
+impl<Sd, Sx> Interp1D<Sd, Sx, Ix1, CubicSplineStrategy<Sd, Ix1>>
+where
+    Sd: Data,
+    Sd::Elem: SplineNum,
+    Sx: Data<Elem = Sd::Elem>,
+{
+    /// The weights of all knots in the interpolated value at `x`.
+    ///
+    /// The spline is linear in the data, `interp_scalar(x) = sum_j basis(x)[j] * data[j]`.
+    /// So the weights are the derivatives `∂interp_scalar(x) / ∂data[j]`, e.g. for sensitivity
+    /// analysis or to backpropagate gradients to the data. Unlike linear interpolation all
+    /// knots of the run around `x` have a weight, because the spline equations couple them.
+    ///
+    /// The weights are found by solving the spline for each knot with unit data,
+    /// this costs `O(n²)` for `n` knots on each call.
+    /// For a [`Periodic`](BoundaryCondition::Periodic) spline the first and the last knot
+    /// are the same point, the whole weight of this point is given to the first knot.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let data = array![1.0, 3.0, 2.0, 0.0, 1.0];
+    /// let interpolator = Interp1DBuilder::new(data.view())
+    ///     .strategy(CubicSpline::new().boundary(BoundaryCondition::Natural))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let weights = interpolator.basis(1.5).unwrap();
+    /// // the knot at 4 is two segments away but still has a weight
+    /// assert!(weights[4] != 0.0);
+    /// # assert_abs_diff_eq!(weights.dot(&data), interpolator.interp_scalar(1.5).unwrap(), epsilon = 1e-14);
+    /// ```
+    ///
+    /// # Errors
+    ///  - When `x` is out of range and the spline does not extrapolate,
+    ///    or when `x` is inside a masked gap
+    ///  - When an individual boundary condition sets a derivative which is not zero.
+    ///    The spline is then not linear in the data but has an additional offset.
+    ///  - When the slopes were [limited](CubicSpline::limit_monotone), the limiting
+    ///    depends on the data and is not linear.
+    ///  - When the values are [clamped to the data](CubicSpline::clamp_to_data),
+    ///    the clamping is not linear either.
+    pub fn basis(&self, x: Sx::Elem) -> Result<Array1<Sd::Elem>, InterpolateError> {
+        self.check_gaps(x)?;
+        let (idx, t) = self.strategy.locate(self, x)?;
+        if self.monotone_limited().iter().any(|&limited| limited) {
+            return Err(BuilderError::ValueError(
+                "the spline is not linear in the data when the slopes are limited".into(),
+            )
+            .into());
+        }
+        if self.strategy.clamp_to_data.is_some() {
+            return Err(BuilderError::ValueError(
+                "the spline is not linear in the data when the values are clamped".into(),
+            )
+            .into());
+        }
+
+        let len = self.x.len();
+        let boundary = match &self.strategy.boundary {
+            BoundaryCondition::NotAKnot => BoundaryCondition::NotAKnot,
+            BoundaryCondition::Natural => BoundaryCondition::Natural,
+            BoundaryCondition::Clamped => BoundaryCondition::Clamped,
+            BoundaryCondition::Periodic => BoundaryCondition::Periodic,
+            BoundaryCondition::Individual(bounds) => {
+                let bound = &bounds[0];
+                if let RowBoundary::Mixed { left, right } = bound {
+                    let is_homogeneous = |single: &SingleBoundary<Sd::Elem>| match single {
+                        SingleBoundary::FirstDeriv(deriv) | SingleBoundary::SecondDeriv(deriv) => {
+                            deriv.is_zero()
+                        }
+                        _ => true,
+                    };
+                    if !(is_homogeneous(left) && is_homogeneous(right)) {
+                        return Err(BuilderError::ValueError(format!(
+                            "the spline is not linear in the data with the boundary condition {bound:?}"
+                        ))
+                        .into());
+                    }
+                }
+                // every column of the unit data gets the boundary of the single data row
+                BoundaryCondition::Individual(Array2::from_elem((1, len), bound.clone()))
+            }
+        };
+        // column `j` is the data which is one at knot `j` and zero elsewhere
+        let (zero, one): (Sd::Elem, Sd::Elem) =
+            (Zero::zero(), cast(1.0).unwrap_or_else(|| unimplemented!()));
+        let mut unit = Array2::eye(len);
+        if matches!(boundary, BoundaryCondition::Periodic) {
+            // the first and the last knot are the same point
+            unit[[len - 1, len - 1]] = zero;
+            unit[[len - 1, 0]] = one;
+        }
+        let x_rel = self.x.mapv(|x| x - self.strategy.x_offset);
+        let (a, b) = CubicSpline::new()
+            .boundary(boundary)
+            .breaks(&self.strategy.breaks)
+            .calc_coefficients(&x_rel, &unit, &mut Scratch::new())?;
+
+        Ok(Array1::from_shape_fn(len, |j| {
+            let (y_left, y_right) = (unit[[idx, j]], unit[[idx + 1, j]]);
+            self.strategy
+                .eval_segment(y_left, y_right, a[[idx, j]], b[[idx, j]], t)
+                .0
+        }))
+    }
+
+    /// Is the interpolant monotonic (not strictly) over the range of x?
+    ///
+    /// A spline through monotonic data can still overshoot between the knots.
+    /// The derivative of each segment is a quadratic, its extreme values on the segment
+    /// are at the knots or at the vertex of the quadratic. The spline is monotonic
+    /// when all of them have the same sign, zero is allowed. A value at the vertex
+    /// which is zero up to rounding counts as zero, e.g. for a segment whose derivative
+    /// just touches zero at a knot.
+    /// Extrapolated parts outside of the range are not checked.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// // a step in the data makes the spline overshoot
+    /// let interp = Interp1DBuilder::new(array![0.0, 0.0, 0.0, 1.0, 1.0, 1.0])
+    ///     .strategy(CubicSpline::new())
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(!interp.is_monotone().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// When the values are [clamped to the data](CubicSpline::clamp_to_data).
+    pub fn is_monotone(&self) -> Result<bool, BuilderError> {
+        self.check_unclamped()?;
+        let zero = Sd::Elem::zero();
+        let one: Sd::Elem = cast(1.0).unwrap_or_else(|| unimplemented!());
+        let two: Sd::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: Sd::Elem = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let four: Sd::Elem = cast(4.0).unwrap_or_else(|| unimplemented!());
+        let rounding: Sd::Elem = cast(4.0 * f64::EPSILON).unwrap_or_else(|| unimplemented!());
+        let abs = |value: Sd::Elem| if value < zero { -value } else { value };
+        let (mut rising, mut falling) = (true, true);
+        let mut check = |slope: Sd::Elem| {
+            rising &= slope >= zero;
+            falling &= slope <= zero;
+        };
+        for (idx, (&a, &b)) in self.strategy.a.iter().zip(&self.strategy.b).enumerate() {
+            // the derivative with respect to `t` is `c0 + c1 * t + c2 * t²`,
+            // its sign is the sign of the derivative in x
+            let rise = self.data[idx + 1] - self.data[idx];
+            let c0 = rise + a;
+            let c1 = two * b - four * a;
+            let c2 = three * (a - b);
+            check(c0);
+            // `c0 + c1 + c2` without rounding, a zero slope at the knot stays zero
+            check(rise - b);
+            if c2 != zero {
+                let vertex = -c1 / (two * c2);
+                let slope = c0 + c1 * vertex + c2 * vertex * vertex;
+                if vertex > zero
+                    && vertex < one
+                    && abs(slope) > rounding * (abs(c0) + abs(c1) + abs(c2))
+                {
+                    check(slope);
+                }
+            }
+        }
+        Ok(rising || falling)
+    }
+
+    /// All x in range where the spline is zero, in ascending order,
+    /// e.g. where a signal crosses a threshold after subtracting it.
+    ///
+    /// Each segment is split at the extreme values of its cubic into monotonic pieces,
+    /// the root of each piece which changes the sign is found by bisection to full precision.
+    /// Knots with zero data are returned as well. A root where the spline touches zero
+    /// without changing the sign is only found when it is zero at a knot or at an
+    /// extreme value without rounding.
+    /// Segments inside [masked gaps](crate::interp1d::Interp1DBuilder::mask) are skipped.
+    ///
+    /// When the spline is [clamped to the data](CubicSpline::clamp_to_data), segments
+    /// between two knots with data of the same sign have no zeros. Where the clamped
+    /// spline stays at the zero data of a knot, the ends of this stretch are returned.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray_interp::interp1d::cubic_spline::*;
+    /// # use ndarray::*;
+    /// let x = Array::linspace(0.0, 5.0, 21);
+    /// let interp = Interp1DBuilder::new(x.mapv(f64::cos))
+    ///     .x(x)
+    ///     .strategy(CubicSpline::new())
+    ///     .build()
+    ///     .unwrap();
+    /// let zeros = interp.zeros();
+    /// assert_eq!(zeros.len(), 2);
+    /// assert!((zeros[0] - std::f64::consts::FRAC_PI_2).abs() < 1e-4);
+    /// ```
+    pub fn zeros(&self) -> Vec<Sx::Elem> {
+        let zero = Sd::Elem::zero();
+        let one: Sd::Elem = cast(1.0).unwrap_or_else(|| unimplemented!());
+        let two: Sd::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: Sd::Elem = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let half: Sd::Elem = cast(0.5).unwrap_or_else(|| unimplemented!());
+        let last = self.x.len() - 1;
+        let mut zeros = Vec::new();
+        for idx in 0..last {
+            let (x_left, x_right) = (self.x[idx], self.x[idx + 1]);
+            let (y_left, y_right) = (self.data[idx], self.data[idx + 1]);
+            let (a, b) = (self.strategy.a[idx], self.strategy.b[idx]);
+            if self.check_gaps((x_left + x_right) / two).is_err() {
+                if y_left == zero {
+                    zeros.push(x_left);
+                }
+                continue;
+            }
+            if self.strategy.clamp_to_data.is_some()
+                && (y_left > zero && y_right > zero || y_left < zero && y_right < zero)
+            {
+                continue;
+            }
+            let value = |t| self.strategy.eval_segment(y_left, y_right, a, b, t).0;
+
+            // the extreme values are the roots of the derivative `c1 + 2 c2 t + 3 c3 t²`
+            let (c1, c2, c3) = (y_right - y_left + a, b - a - a, a - b);
+            let mut bounds = vec![zero];
+            if c3 != zero {
+                let discriminant = c2 * c2 - three * c3 * c1;
+                if discriminant > zero {
+                    let root = discriminant.pow(half);
+                    let (t1, t2) = ((-c2 - root) / (three * c3), (-c2 + root) / (three * c3));
+                    bounds.extend(if t1 < t2 { [t1, t2] } else { [t2, t1] });
+                }
+            } else if c2 != zero {
+                bounds.push(-c1 / (two * c2));
+            }
+            bounds.retain(|&t| t >= zero && t < one);
+            bounds.push(one);
+
+            for piece in bounds.windows(2) {
+                let (mut low, mut high) = (piece[0], piece[1]);
+                let (y_low, y_high) = (value(low), value(high));
+                if y_low == zero {
+                    zeros.push(x_left + (x_right - x_left) * low);
+                }
+                if !(y_low < zero && y_high > zero || y_low > zero && y_high < zero) {
+                    continue;
+                }
+                // bisect until the interval can not be split any more
+                loop {
+                    let mid = (low + high) / two;
+                    if mid <= low || mid >= high {
+                        break;
+                    }
+                    let y_mid = value(mid);
+                    if y_mid == zero {
+                        (low, high) = (mid, mid);
+                    } else if (y_mid < zero) == (y_low < zero) {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                zeros.push(x_left + (x_right - x_left) * ((low + high) / two));
+            }
+        }
+        if self.data[last] == zero && self.is_in_range(self.x[last]) {
+            zeros.push(self.x[last]);
+        }
+        zeros
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<Sd, Sx, D> approx::AbsDiffEq for Interp1D<Sd, Sx, D, CubicSplineStrategy<Sd, D>>
+where
+    Sd: Data,
+    Sd::Elem: SplineNum + approx::RelativeEq,
+    <Sd::Elem as approx::AbsDiffEq>::Epsilon: Clone,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    type Epsilon = <Sd::Elem as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Sd::Elem::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        let (this, that) = (&self.strategy, &other.strategy);
+        this.extrapolate == that.extrapolate
+            && this.extrapolation_order == that.extrapolation_order
+            && this.x_offset.abs_diff_eq(&that.x_offset, epsilon.clone())
+            && this
+                .phase_origin
+                .abs_diff_eq(&that.phase_origin, epsilon.clone())
+            && this.a.abs_diff_eq(&that.a, epsilon.clone())
+            && this.b.abs_diff_eq(&that.b, epsilon.clone())
+            && self.base_abs_diff_eq(other, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<Sd, Sx, D> approx::RelativeEq for Interp1D<Sd, Sx, D, CubicSplineStrategy<Sd, D>>
+where
+    Sd: Data,
+    Sd::Elem: SplineNum + approx::RelativeEq,
+    <Sd::Elem as approx::AbsDiffEq>::Epsilon: Clone,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        Sd::Elem::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let (this, that) = (&self.strategy, &other.strategy);
+        this.extrapolate == that.extrapolate
+            && this.extrapolation_order == that.extrapolation_order
+            && this
+                .x_offset
+                .relative_eq(&that.x_offset, epsilon.clone(), max_relative.clone())
+            && this.phase_origin.relative_eq(
+                &that.phase_origin,
+                epsilon.clone(),
+                max_relative.clone(),
+            )
+            && this
+                .a
+                .relative_eq(&that.a, epsilon.clone(), max_relative.clone())
+            && this
+                .b
+                .relative_eq(&that.b, epsilon.clone(), max_relative.clone())
+            && self.base_relative_eq(other, epsilon, max_relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{
+        array, Array, Array1, Array2, ArrayBase, Data, Dimension, Ix1, Ix2, RemoveAxis, Zip,
+    };
+
+    use super::{BoundaryCondition, CubicSpline, InternalBoundary, AX0};
+    use crate::linalg::Scratch;
+
+    /// the original index based coefficient assembly, used as reference
+    fn reference_coefficients<S, D>(
+        x: &Array1<f64>,
+        data: &ArrayBase<S, D>,
+        boundary: InternalBoundary<f64>,
+    ) -> (Array<f64, D>, Array<f64, D>)
+    where
+        S: Data<Elem = f64>,
+        D: Dimension + RemoveAxis,
+    {
+        let len = data.shape()[0];
+        let mut k = Array::zeros(data.raw_dim());
+        CubicSpline::<f64, D>::solve_for_k(k.view_mut(), x, data, boundary, &mut Scratch::new())
+            .unwrap();
+
+        let mut a_b_dim = data.raw_dim();
+        a_b_dim[0] -= 1;
+        let mut c_a = Array::zeros(a_b_dim.clone());
+        let mut c_b = Array::zeros(a_b_dim);
+        for index in 0..len - 1 {
+            Zip::from(c_a.index_axis_mut(AX0, index))
+                .and(c_b.index_axis_mut(AX0, index))
+                .and(k.index_axis(AX0, index))
+                .and(k.index_axis(AX0, index + 1))
+                .and(data.index_axis(AX0, index))
+                .and(data.index_axis(AX0, index + 1))
+                .for_each(|c_a, c_b, &k, &k_right, &y, &y_right| {
+                    *c_a = k * (x[index + 1] - x[index]) - (y_right - y);
+                    *c_b = (y_right - y) - k_right * (x[index + 1] - x[index]);
+                })
+        }
+        (c_a, c_b)
+    }
+
+    #[test]
+    fn coefficients_match_reference() {
+        let x = array![0.0, 0.5, 1.75, 2.0, 3.5, 4.0, 6.0];
+        let data = array![1.0, 2.0, 2.5, -1.0, 3.0, 0.5, 1.0];
+        let strat = CubicSpline::new().boundary(BoundaryCondition::Natural);
+        let (a, b) = strat
+            .calc_coefficients(&x, &data, &mut Scratch::new())
+            .unwrap();
+        let (a_ref, b_ref) = reference_coefficients(&x, &data, InternalBoundary::Natural);
+        assert_eq!(a, a_ref);
+        assert_eq!(b, b_ref);
+
+        let strat = CubicSpline::new().boundary(BoundaryCondition::Periodic);
+        let (a, b) = strat
+            .calc_coefficients(&x, &data, &mut Scratch::new())
+            .unwrap();
+        let (a_ref, b_ref) = reference_coefficients(&x, &data, InternalBoundary::Periodic);
+        assert_eq!(a, a_ref);
+        assert_eq!(b, b_ref);
+    }
+
+    #[test]
+    fn coefficients_match_reference_multidim() {
+        let x = array![-1.0, 0.0, 2.0, 3.0, 3.5];
+        let data = array![
+            [[0.5, 1.0], [0.0, 1.5]],
+            [[0.0, 1.5], [2.0, -1.0]],
+            [[3.0, 0.5], [1.0, 1.0]],
+            [[2.0, 2.5], [0.5, 0.0]],
+            [[1.0, 0.0], [3.0, 2.0]],
+        ];
+        let strat = CubicSpline::new();
+        let (a, b) = strat
+            .calc_coefficients(&x, &data, &mut Scratch::new())
+            .unwrap();
+        let (a_ref, b_ref) = reference_coefficients(&x, &data, InternalBoundary::NotAKnot);
+        assert_eq!(a, a_ref);
+        assert_eq!(b, b_ref);
+    }
+
+    #[test]
+    fn periodic_solve_unchanged() {
+        // results of the periodic solve before it was moved into `solve_cyclic_tridiagonal`
+        let x = array![0.0, 0.5, 1.75, 2.0, 3.5, 4.0, 6.0];
+        let data = array![
+            [1.0, 0.0],
+            [2.0, 1.0],
+            [2.5, -2.0],
+            [-1.0, 0.5],
+            [3.0, 0.25],
+            [0.5, 3.0],
+            [1.0, 0.0]
+        ];
+        let mut k = Array::zeros(data.raw_dim());
+        CubicSpline::<f64, Ix2>::solve_for_k(
+            k.view_mut(),
+            &x,
+            &data,
+            InternalBoundary::Periodic,
+            &mut Scratch::new(),
+        )
+        .unwrap();
+        let expect = array![
+            [1.6214392306878485, 1.7534709809620854],
+            [3.555077705204661, -0.6790065093073626],
+            [-12.739142013152248, 8.169368112746325],
+            [-11.897074709475527, 9.08931783127029],
+            [-1.0061019884291351, 3.23334168573799],
+            [-5.68470312769713, 4.681316227608596],
+            [1.6214392306878485, 1.7534709809620854]
+        ];
+        assert_eq!(k, expect);
+
+        // the smallest system which is not handled as a special case
+        let x = array![0.0, 1.0, 1.5, 3.0];
+        let data = array![1.0, -1.0, 2.0, 1.0];
+        let mut k = Array::zeros(data.raw_dim());
+        CubicSpline::<f64, Ix1>::solve_for_k(
+            k.view_mut(),
+            &x,
+            &data,
+            InternalBoundary::Periodic,
+            &mut Scratch::new(),
+        )
+        .unwrap();
+        let expect = array![
+            -4.484848484848484,
+            3.87878787878788,
+            5.6060606060606055,
+            -4.484848484848484
+        ];
+        assert_eq!(k, expect);
+    }
+
+    #[test]
+    fn cyclic_tridiagonal() {
+        let a_up = array![1.0, 2.0, -1.0, 0.5, 99.0];
+        let a_mid = array![4.0, 5.0, 6.0, 4.5, 3.0];
+        let a_low = array![99.0, 1.5, 2.0, -1.0, 0.5];
+        let corners = (0.75, -1.25);
+        let rhs = array![1.0, -2.0, 0.5, 3.0, 2.0];
+
+        let mut k: Array1<f64> = Array1::zeros(5);
+        CubicSpline::<f64, Ix1>::solve_cyclic_tridiagonal(
+            k.view_mut(),
+            a_up.view(),
+            a_mid.view(),
+            a_low.view(),
+            rhs.view(),
+            corners,
+            &mut Vec::new(),
+        );
+
+        let mut matrix = Array2::zeros((5, 5));
+        for i in 0..5 {
+            matrix[[i, i]] = a_mid[i];
+            if i > 0 {
+                matrix[[i, i - 1]] = a_low[i];
+            }
+            if i < 4 {
+                matrix[[i, i + 1]] = a_up[i];
+            }
+        }
+        matrix[[0, 4]] = corners.0;
+        matrix[[4, 0]] = corners.1;
+        let result = matrix.dot(&k);
+        for (result, expect) in result.iter().zip(rhs.iter()) {
+            assert!((result - expect).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! The cubic Hermite basis, for strategies which interpolate between knot values and tangents.
+//!
+//! On a segment from `x_left` to `x_right` with `h = x_right - x_left` and the normalized
+//! position `t = (x - x_left) / h` the cubic Hermite interpolant is
+//! ```text
+//! y(t) = h00(t) * y_left + h10(t) * h * m_left + h01(t) * y_right + h11(t) * h * m_right
+//! ```
+//! where `m_left` and `m_right` are the tangents (first derivatives in x) at the two knots.
+//!
+//! All functions return the four basis functions in the order `[h00, h10, h01, h11]`.
+//! The derivatives are taken with respect to `t`, divide by `h` (or `h²`) for the
+//! derivatives with respect to `x`.
+//!
+//! ```rust
+//! # use ndarray_interp::interp1d::hermite;
+//! let (y_left, m_left, y_right, m_right) = (1.0, 0.0, 3.0, 2.0);
+//! let h = 2.0;
+//! let [h00, h10, h01, h11] = hermite::basis(0.5);
+//! let y = h00 * y_left + h10 * h * m_left + h01 * y_right + h11 * h * m_right;
+//! assert_eq!(y, 1.5);
+//! ```
+
+use num_traits::{cast, Num, NumCast};
+
+fn constant<T: NumCast>(value: f64) -> T {
+    cast(value).unwrap_or_else(|| unimplemented!())
+}
+
+/// The cubic Hermite basis `[h00, h10, h01, h11]` at `t`
+pub fn basis<T>(t: T) -> [T; 4]
+where
+    T: Num + NumCast + Copy,
+{
+    let (one, two, three) = (T::one(), constant::<T>(2.0), constant::<T>(3.0));
+    let t2 = t * t;
+    let t3 = t2 * t;
+    [
+        two * t3 - three * t2 + one,
+        t3 - two * t2 + t,
+        three * t2 - two * t3,
+        t3 - t2,
+    ]
+}
+
+/// The first derivative of the cubic Hermite basis with respect to `t`
+pub fn basis_derivative<T>(t: T) -> [T; 4]
+where
+    T: Num + NumCast + Copy,
+{
+    let (one, two, three) = (T::one(), constant::<T>(2.0), constant::<T>(3.0));
+    let (four, six) = (constant::<T>(4.0), constant::<T>(6.0));
+    let t2 = t * t;
+    [
+        six * t2 - six * t,
+        three * t2 - four * t + one,
+        six * t - six * t2,
+        three * t2 - two * t,
+    ]
+}
+
+/// The second derivative of the cubic Hermite basis with respect to `t`
+pub fn basis_second_derivative<T>(t: T) -> [T; 4]
+where
+    T: Num + NumCast + Copy,
+{
+    let (two, four) = (constant::<T>(2.0), constant::<T>(4.0));
+    let (six, twelve) = (constant::<T>(6.0), constant::<T>(12.0));
+    [
+        twelve * t - six,
+        six * t - four,
+        six - twelve * t,
+        six * t - two,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::{basis, basis_derivative, basis_second_derivative};
+
+    #[test]
+    fn endpoint_conditions() {
+        // h00 and h01 interpolate the values, h10 and h11 the tangents
+        assert_eq!(basis(0.0), [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(basis(1.0), [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(basis_derivative(0.0), [0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(basis_derivative(1.0), [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn partition_of_unity() {
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let [h00, _, h01, _] = basis(t);
+            let [d00, _, d01, _] = basis_derivative(t);
+            let [s00, _, s01, _] = basis_second_derivative(t);
+            assert_abs_diff_eq!(h00 + h01, 1.0, epsilon = 1e-15);
+            assert_abs_diff_eq!(d00 + d01, 0.0, epsilon = 1e-15);
+            assert_abs_diff_eq!(s00 + s01, 0.0, epsilon = 1e-15);
+        }
+    }
+
+    #[test]
+    fn derivatives_match_finite_differences() {
+        let eps = 1e-6;
+        for t in [0.1, 0.35, 0.8] {
+            let (lower, upper) = (basis(t - eps), basis(t + eps));
+            let (d_lower, d_upper) = (basis_derivative(t - eps), basis_derivative(t + eps));
+            let d = basis_derivative(t);
+            let s = basis_second_derivative(t);
+            for i in 0..4 {
+                assert_abs_diff_eq!(d[i], (upper[i] - lower[i]) / (2.0 * eps), epsilon = 1e-8);
+                assert_abs_diff_eq!(
+                    s[i],
+                    (d_upper[i] - d_lower[i]) / (2.0 * eps),
+                    epsilon = 1e-8
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn integer_types() {
+        assert_eq!(basis(1i32), [0, 0, 1, 0]);
+        assert_eq!(basis_derivative(0i32), [0, 1, 0, 0]);
+    }
+}
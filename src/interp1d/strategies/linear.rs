@@ -1,34 +1,108 @@
-use std::{fmt::Debug, ops::Sub};
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Sub},
+    sync::OnceLock,
+};
 
-use ndarray::{ArrayBase, ArrayViewMut, Data, Dimension, Ix1, RemoveAxis, Zip};
-use num_traits::{Num, NumCast};
+use ndarray::{
+    Array, Array1, ArrayBase, ArrayViewMut, Axis, Data, Dimension, Ix1, OwnedRepr, RemoveAxis, Zip,
+};
+use num_traits::{cast, Num, NumCast, One, Zero};
 
-use crate::{interp1d::Interp1D, BuilderError, InterpolateError};
+use crate::{
+    interp1d::{ExtrapolateMode, Interp1D},
+    vector_extensions::{Monotonic, VectorExtensions},
+    BuilderError, InterpolateError,
+};
 
-use super::{Interp1DStrategy, Interp1DStrategyBuilder};
+use super::{describe_extrapolation, Interp1DStrategy, Interp1DStrategyBuilder};
+
+/// The arithmetic needed to linearly interpolate data over an x axis of type `X`
+///
+/// Data only needs to support addition, subtraction and scaling by
+/// the x axis type. This is automatically implemented for all types
+/// satisfying the trait bounds, e.g. all [`Num`] types with `X = Self` or
+/// unit-typed quantities with a numeric x axis.
+///
+/// Arbitrary precision types like `BigRational` can not be used yet. They are not
+/// `Copy`, and as x axis they also lack the [`NumCast`] needed to locate the queries.
+pub trait LinearData<X>:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<X, Output = Self>
+    + Div<X, Output = Self>
+    + Zero
+    + Copy
+    + Debug
+    + Send
+{
+}
+
+impl<T, X> LinearData<X> for T where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<X, Output = T>
+        + Div<X, Output = T>
+        + Zero
+        + Copy
+        + Debug
+        + Send
+{
+}
 
 /// Linear Interpolation Strategy
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Linear {
-    extrapolate: bool,
+    extrapolate_low: bool,
+    extrapolate_high: bool,
 }
 
 impl Linear {
     /// create a linear interpolation stratgy
     pub fn new() -> Self {
-        Self { extrapolate: false }
+        Self {
+            extrapolate_low: false,
+            extrapolate_high: false,
+        }
     }
 
     /// does the strategy extrapolate? Default is `false`
-    pub fn extrapolate(mut self, extrapolate: bool) -> Self {
-        self.extrapolate = extrapolate;
+    ///
+    /// This sets both sides, see [`extrapolate_low`](Linear::extrapolate_low)
+    /// and [`extrapolate_high`](Linear::extrapolate_high).
+    pub fn extrapolate(self, extrapolate: bool) -> Self {
+        self.extrapolate_low(extrapolate)
+            .extrapolate_high(extrapolate)
+    }
+
+    /// does the strategy extrapolate below the first knot? Default is `false`
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let interp = Interp1DBuilder::new(array![0.0, 1.0, 2.0])
+    ///     .strategy(Linear::new().extrapolate_high(true))
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(interp.interp_scalar(-1.0).is_err());
+    /// assert_eq!(interp.interp_scalar(3.0).unwrap(), 3.0);
+    /// ```
+    pub fn extrapolate_low(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_low = extrapolate;
+        self
+    }
+
+    /// does the strategy extrapolate above the last knot? Default is `false`
+    pub fn extrapolate_high(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_high = extrapolate;
         self
     }
 
     /// linearly interpolate/exrapolate between two points
-    pub(crate) fn calc_frac<T>((x1, y1): (T, T), (x2, y2): (T, T), x: T) -> T
+    pub(crate) fn calc_frac<X, Y>((x1, y1): (X, Y), (x2, y2): (X, Y), x: X) -> Y
     where
-        T: Num + Copy,
+        X: Num + Copy,
+        Y: Add<Output = Y> + Sub<Output = Y> + Mul<X, Output = Y> + Div<X, Output = Y> + Copy,
     {
         let b = y1;
         let m = (y2 - y1) / (x2 - x1);
@@ -42,11 +116,22 @@ impl Default for Linear {
     }
 }
 
+impl Display for Linear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Linear (extrapolate: {})",
+            describe_extrapolation(self.extrapolate_low, self.extrapolate_high)
+        )
+    }
+}
+
 impl<Sd, Sx, D> Interp1DStrategyBuilder<Sd, Sx, D> for Linear
 where
     Sd: Data,
-    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: LinearData<Sx::Elem>,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
     D: Dimension + RemoveAxis,
 {
     const MINIMUM_DATA_LENGHT: usize = 2;
@@ -57,7 +142,7 @@ where
         _data: &ArrayBase<Sd, D>,
     ) -> Result<Self::FinishedStrat, BuilderError>
     where
-        Sx2: Data<Elem = Sd::Elem>,
+        Sx2: Data<Elem = Sx::Elem>,
     {
         Ok(self)
     }
@@ -66,23 +151,34 @@ where
 impl<Sd, Sx, D> Interp1DStrategy<Sd, Sx, D> for Linear
 where
     Sd: Data,
-    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: LinearData<Sx::Elem>,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
     D: Dimension + RemoveAxis,
 {
+    const NAME: &'static str = "Linear";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        ExtrapolateMode::from_sides(self.extrapolate_low, self.extrapolate_high)
+    }
+
     fn interp_into(
         &self,
         interpolator: &Interp1D<Sd, Sx, D, Self>,
         target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
         x: Sx::Elem,
     ) -> Result<(), InterpolateError> {
-        let this = interpolator;
-        if !self.extrapolate && !this.is_in_range(x) {
-            return Err(InterpolateError::OutOfBounds(format!(
-                "x = {x:#?} is not in range",
-            )));
-        }
+        interpolator.check_range(x, self.extrapolate_low, self.extrapolate_high)?;
+        self.interp_into_in_range(interpolator, target, x)
+    }
 
+    fn interp_into_in_range(
+        &self,
+        interpolator: &Interp1D<Sd, Sx, D, Self>,
+        target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
+        x: Sx::Elem,
+    ) -> Result<(), InterpolateError> {
+        let this = interpolator;
         // find the relevant index
         let idx = this.get_index_left_of(x);
 
@@ -97,3 +193,513 @@ where
         Ok(())
     }
 }
+
+impl<Sd, Sx, D> Interp1D<Sd, Sx, D, Linear>
+where
+    Sd: Data,
+    Sd::Elem: LinearData<Sx::Elem>,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+{
+    /// Calculate the interpolated values and the slope (first derivative)
+    /// at `x` with a single segment lookup.
+    ///
+    /// Returns `(value, slope)`, both with the shape of the data with the
+    /// first axis removed. At a knot the slope of the segment right of the
+    /// knot is returned, at the last knot the slope of the last segment.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let data = array![0.0, 1.0, 3.0];
+    /// let interpolator = Interp1DBuilder::new(data).build().unwrap();
+    ///
+    /// let (value, slope) = interpolator.interp_value_and_slope(1.5).unwrap();
+    /// # assert_abs_diff_eq!(value, array![2.0].remove_axis(Axis(0)), epsilon=f64::EPSILON);
+    /// # assert_abs_diff_eq!(slope, array![2.0].remove_axis(Axis(0)), epsilon=f64::EPSILON);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn interp_value_and_slope(
+        &self,
+        x: Sx::Elem,
+    ) -> Result<(Array<Sd::Elem, D::Smaller>, Array<Sd::Elem, D::Smaller>), InterpolateError> {
+        self.check_range(
+            x,
+            self.strategy.extrapolate_low,
+            self.strategy.extrapolate_high,
+        )?;
+        self.check_gaps(x)?;
+
+        let idx = self.get_index_left_of(x);
+        let (x1, y1) = self.index_point(idx);
+        let (x2, y2) = self.index_point(idx + 1);
+
+        let dim = self.data.raw_dim().remove_axis(Axis(0));
+        let mut value = Array::zeros(dim.clone());
+        let mut slope = Array::zeros(dim);
+        Zip::from(y1)
+            .and(y2)
+            .and(&mut value)
+            .and(&mut slope)
+            .for_each(|&y1, &y2, y, dy| {
+                *y = Linear::calc_frac((x1, y1), (x2, y2), x);
+                *dy = (y2 - y1) / (x2 - x1);
+            });
+        Ok((value, slope))
+    }
+
+    /// The integral of the interpolant from the first knot to each point in `xs`.
+    ///
+    /// The integrals of all segments are accumulated once in a forward sweep,
+    /// each query then only adds the part of its own segment. `xs` does not need to be sorted.
+    /// The result has the shape of the data with the first axis replaced by the lenght of `xs`.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// // a constant rate of 2 for one second and then of 4
+    /// let rate = array![2.0, 2.0, 4.0, 4.0];
+    /// let t = array![0.0, 1.0, 1.0 + 1e-9, 3.0];
+    /// let interpolator = Interp1DBuilder::new(rate).x(t).build().unwrap();
+    ///
+    /// let total = interpolator.cumulative_integral(&array![0.5, 1.0, 3.0]).unwrap();
+    /// # approx::assert_abs_diff_eq!(total, array![1.0, 2.0, 10.0], epsilon = 1e-8);
+    /// ```
+    ///
+    /// Masked gaps wider than [`max_gap`](crate::interp1d::Interp1DBuilder::max_gap)
+    /// are not integrated, they add nothing to the integral of the points beyond them.
+    ///
+    /// # Errors
+    /// When a point in `xs` is outside of the x range or inside a masked gap,
+    /// the integral does not extrapolate.
+    pub fn cumulative_integral<Sq>(
+        &self,
+        xs: &ArrayBase<Sq, Ix1>,
+    ) -> Result<Array<Sd::Elem, D>, InterpolateError>
+    where
+        Sq: Data<Elem = Sx::Elem>,
+    {
+        let two: Sx::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let at_knots = self.integral_at_knots();
+
+        let mut dim = self.data.raw_dim();
+        dim[0] = xs.len();
+        let mut integrals = Array::zeros(dim);
+        for (&x, mut target) in xs.iter().zip(integrals.axis_iter_mut(Axis(0))) {
+            self.check_range(x, false, false)?;
+            self.check_gaps(x)?;
+            let idx = self.get_index_left_of(x);
+            let (x_left, y_left) = self.index_point(idx);
+            let (x_right, y_right) = self.index_point(idx + 1);
+            if x == x_right {
+                // the segment may be a masked gap
+                target.assign(&at_knots.index_axis(Axis(0), idx + 1));
+                continue;
+            }
+            let dx = x - x_left;
+            let t = dx / (x_right - x_left);
+            Zip::from(target)
+                .and(at_knots.index_axis(Axis(0), idx))
+                .and(y_left)
+                .and(y_right)
+                .for_each(|integral, &prev, &y_left, &y_right| {
+                    *integral = prev + (y_left + (y_right - y_left) * t / two) * dx;
+                });
+        }
+        Ok(integrals)
+    }
+
+    /// The interpolator of the integral from the first knot, e.g. the cumulative
+    /// distribution function of a sampled probability density.
+    ///
+    /// The integral is exact at the knots and interpolated linearly between them,
+    /// the last knot holds the integral over the whole range. When the data is not
+    /// negative the cumulative data is monotonic rising, so it can be inverted with
+    /// [`value_to_index`](Interp1D::value_to_index) when it is also strictly rising.
+    /// Masked gaps and [`closed_upper`](crate::interp1d::Interp1DBuilder::closed_upper)
+    /// are kept, the cumulative interpolator does not extrapolate.
+    /// The integral is constant over a masked gap, see [`cumulative_integral`](Interp1D::cumulative_integral).
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// // a triangular probability density on [0, 2]
+    /// let pdf = Interp1DBuilder::new(array![0.0, 0.5, 1.0, 0.5, 0.0])
+    ///     .x(array![0.0, 0.5, 1.0, 1.5, 2.0])
+    ///     .build()
+    ///     .unwrap();
+    /// let cdf = pdf.to_cumulative();
+    /// assert_eq!(cdf.data(), array![0.0, 0.125, 0.5, 0.875, 1.0]);
+    ///
+    /// // the median is at the knot with index 2
+    /// assert_eq!(cdf.value_to_index(0.5).unwrap(), 2.0);
+    /// ```
+    pub fn to_cumulative(&self) -> Interp1D<OwnedRepr<Sd::Elem>, OwnedRepr<Sx::Elem>, D, Linear> {
+        Interp1D {
+            x: self.x.to_owned(),
+            data: self.integral_at_knots(),
+            strategy: Linear::new(),
+            gaps: self.gaps.clone(),
+            closed_upper: self.closed_upper,
+            output_transform: None,
+            data_monotonic: OnceLock::new(),
+        }
+    }
+
+    /// the integral from the first knot to each knot
+    fn integral_at_knots(&self) -> Array<Sd::Elem, D> {
+        let two: Sx::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let mut at_knots = Array::<Sd::Elem, D>::zeros(self.data.raw_dim());
+        for idx in 1..self.x.len() {
+            let (x_left, y_left) = self.index_point(idx - 1);
+            let (x_right, y_right) = self.index_point(idx);
+            let half_width = (x_right - x_left) / two;
+            // a masked gap adds nothing
+            let in_gap = self.check_gaps((x_left + x_right) / two).is_err();
+            let (done, mut next) = at_knots.view_mut().split_at(Axis(0), idx);
+            Zip::from(next.index_axis_mut(Axis(0), 0))
+                .and(done.index_axis(Axis(0), idx - 1))
+                .and(y_left)
+                .and(y_right)
+                .for_each(|integral, &prev, &y_left, &y_right| {
+                    *integral = if in_gap {
+                        prev
+                    } else {
+                        prev + (y_left + y_right) * half_width
+                    };
+                });
+        }
+        at_knots
+    }
+
+    /// Calculate the interpolated values at `x`, where `x` may have a
+    /// wider type than the x axis, e.g. `f64` queries on a `f32` axis.
+    ///
+    /// The segment lookup and the position within the segment are calculated in the
+    /// query type, only the relative position is cast to the axis type.
+    /// This avoids rounding the query to the axis type beforehand.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let data = array![0.0f32, 1.0, 0.0];
+    /// let x = array![1e6f32, 1e6 + 1.0, 1e6 + 2.0];
+    /// let interpolator = Interp1DBuilder::new(data).x(x).build().unwrap();
+    ///
+    /// // 1e6 + 0.03 can not be represented as f32
+    /// let result = interpolator.interp_wide(1e6 + 0.03f64).unwrap();
+    /// # assert_abs_diff_eq!(result.into_scalar(), 0.03, epsilon=1e-6);
+    /// ```
+    pub fn interp_wide<Q>(&self, x: Q) -> Result<Array<Sd::Elem, D::Smaller>, InterpolateError>
+    where
+        Q: Num + PartialOrd + NumCast + Copy + Debug,
+    {
+        let mut target = Array::zeros(self.data.raw_dim().remove_axis(Axis(0)));
+        self.interp_wide_into(x, target.view_mut()).map(|_| target)
+    }
+
+    /// Calculate the interpolated values at all points in `xs`,
+    /// where `xs` may have a wider type than the x axis.
+    /// See [`interp_wide`](Interp1D::interp_wide).
+    ///
+    /// The result has the shape of the data with the first axis
+    /// replaced by the lenght of `xs`.
+    pub fn interp_array_wide<Sq, Q>(
+        &self,
+        xs: &ArrayBase<Sq, Ix1>,
+    ) -> Result<Array<Sd::Elem, D>, InterpolateError>
+    where
+        Sq: Data<Elem = Q>,
+        Q: Num + PartialOrd + NumCast + Copy + Debug,
+    {
+        let mut dim = self.data.raw_dim();
+        dim[0] = xs.len();
+        let mut ys = Array::zeros(dim);
+        Zip::from(xs)
+            .and(ys.axis_iter_mut(Axis(0)))
+            .fold_while(Ok(()), |_, &x, target| {
+                match self.interp_wide_into(x, target) {
+                    Ok(_) => ndarray::FoldWhile::Continue(Ok(())),
+                    Err(e) => ndarray::FoldWhile::Done(Err(e)),
+                }
+            })
+            .into_inner()
+            .map(|_| ys)
+    }
+
+    fn interp_wide_into<Q>(
+        &self,
+        x: Q,
+        target: ArrayViewMut<'_, Sd::Elem, D::Smaller>,
+    ) -> Result<(), InterpolateError>
+    where
+        Q: Num + PartialOrd + NumCast + Copy + Debug,
+    {
+        let widen = |x: Sx::Elem| -> Q {
+            cast(x).unwrap_or_else(|| {
+                unimplemented!("the query type must be wider than the axis type")
+            })
+        };
+        let len = self.x.len();
+        let last = widen(self.x[len - 1]);
+        let in_range = widen(self.x[0]) <= x && (x < last || self.closed_upper && x == last);
+        let extrapolate = if x < widen(self.x[0]) {
+            self.strategy.extrapolate_low
+        } else {
+            self.strategy.extrapolate_high
+        };
+        if !extrapolate && !in_range {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is not in range",
+            )));
+        }
+        if let Some((left, right)) = self
+            .gaps
+            .iter()
+            .find(|&&(left, right)| widen(left) < x && x < widen(right))
+        {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is in the masked gap between {left:#?} and {right:#?}"
+            )));
+        }
+
+        // the index left of x, never the right most index
+        let idx = self
+            .x
+            .as_slice()
+            .map(|knots| knots.partition_point(|&knot| widen(knot) <= x))
+            .unwrap_or_else(|| self.x.iter().take_while(|&&knot| widen(knot) <= x).count())
+            .clamp(1, len - 1)
+            - 1;
+        let (x1, y1) = self.index_point(idx);
+        let (x2, y2) = self.index_point(idx + 1);
+        let t = (x - widen(x1)) / (widen(x2) - widen(x1));
+        let t: Sx::Elem = cast(t).unwrap_or_else(|| unimplemented!("failed to convert {t:?}"));
+
+        Zip::from(y1).and(y2).and(target).for_each(|&y1, &y2, y| {
+            *y = y1 + (y2 - y1) * t;
+        });
+        Ok(())
+    }
+}
+
+impl<Sd, Sx> Interp1D<Sd, Sx, Ix1, Linear>
+where
+    Sd: Data,
+    Sd::Elem: LinearData<Sx::Elem> + Num + PartialOrd + NumCast,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+{
+    /// The fractional knot index `i + t` at which the interpolant crosses `y`.
+    ///
+    /// This is the inverse of the interpolation in index space, the x values are not used.
+    /// The data must be strictly monotonic, either rising or falling.
+    /// At a knot the exact knot index is returned.
+    /// The monotonic property of the data is checked on the first call only.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let data = array![0.0, 1.0, 3.0, 7.0];
+    /// let interpolator = Interp1DBuilder::new(data).build().unwrap();
+    ///
+    /// assert_eq!(interpolator.value_to_index(2.0).unwrap(), 1.5);
+    /// assert_eq!(interpolator.value_to_index(7.0).unwrap(), 3.0);
+    /// ```
+    ///
+    /// # Errors
+    ///  - [`InterpolateError::OutOfBounds`] when `y` is outside of the data range
+    ///  - [`InterpolateError::Monotonic`] when the data is not strictly monotonic
+    pub fn value_to_index(&self, y: Sd::Elem) -> Result<Sd::Elem, InterpolateError> {
+        let monotonic = self
+            .data_monotonic
+            .get_or_init(|| self.data.monotonic_prop());
+        let rising = match monotonic {
+            Monotonic::Rising { strict: true } => true,
+            Monotonic::Falling { strict: true } => false,
+            _ => {
+                return Err(InterpolateError::Monotonic(
+                    "value_to_index needs strictly monotonic data".into(),
+                ))
+            }
+        };
+        let last = self.data.len() - 1;
+        let (low, high) = match rising {
+            true => (self.data[0], self.data[last]),
+            false => (self.data[last], self.data[0]),
+        };
+        // also rejects NaN
+        if !(low <= y && y <= high) {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "y = {y:?} is not in the data range [{low:?}, {high:?}]"
+            )));
+        }
+
+        // binary search for the segment, the knot at `left` is never beyond `y`
+        let not_beyond = |value: Sd::Elem| match rising {
+            true => value <= y,
+            false => value >= y,
+        };
+        let (mut left, mut right) = (0, last);
+        while right - left > 1 {
+            let mid = (left + right) / 2;
+            if not_beyond(self.data[mid]) {
+                left = mid;
+            } else {
+                right = mid;
+            }
+        }
+        let (y_left, y_right) = (self.data[left], self.data[left + 1]);
+        let t = (y - y_left) / (y_right - y_left);
+        let index: Sd::Elem = cast(left).unwrap_or_else(|| unimplemented!());
+        Ok(index + t)
+    }
+
+    /// The weights of all knots in the interpolated value at `x`.
+    ///
+    /// The interpolation is linear in the data, `interp_scalar(x) = sum_j basis(x)[j] * data[j]`.
+    /// So the weights are the derivatives `∂interp_scalar(x) / ∂data[j]`, e.g. for sensitivity
+    /// analysis or to backpropagate gradients to the data. Only the two knots of the segment
+    /// around `x` have a weight, extrapolated values have a negative weight at one of them.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let data = array![1.0, 3.0, 2.0];
+    /// let interpolator = Interp1DBuilder::new(data.view()).build().unwrap();
+    ///
+    /// let weights = interpolator.basis(1.25).unwrap();
+    /// assert_eq!(weights, array![0.0, 0.75, 0.25]);
+    /// assert_eq!(weights.dot(&data), interpolator.interp_scalar(1.25).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// When `x` is out of range and the strategy does not extrapolate,
+    /// or when `x` is inside a masked gap.
+    pub fn basis(&self, x: Sx::Elem) -> Result<Array1<Sd::Elem>, InterpolateError> {
+        self.check_range(
+            x,
+            self.strategy.extrapolate_low,
+            self.strategy.extrapolate_high,
+        )?;
+        self.check_gaps(x)?;
+
+        let idx = self.get_index_left_of(x);
+        let (x_left, x_right) = (self.x[idx], self.x[idx + 1]);
+        let (zero, one) = (Sd::Elem::zero(), Sd::Elem::one());
+        let mut weights = Array1::zeros(self.x.len());
+        weights[idx] = Linear::calc_frac((x_left, one), (x_right, zero), x);
+        weights[idx + 1] = Linear::calc_frac((x_left, zero), (x_right, one), x);
+        Ok(weights)
+    }
+
+    /// Is the interpolant monotonic (not strictly) over the range of x?
+    ///
+    /// For linear interpolation this is the case when the data is monotonic,
+    /// constant data is monotonic as well.
+    pub fn is_monotone(&self) -> bool {
+        let rising = self.data.windows(2).into_iter().all(|y| y[0] <= y[1]);
+        let falling = self.data.windows(2).into_iter().all(|y| y[0] >= y[1]);
+        rising || falling
+    }
+
+    /// All x in range where the interpolant is zero, in ascending order,
+    /// e.g. where a signal crosses a threshold after subtracting it.
+    ///
+    /// Each segment whose data changes the sign contributes the x of its crossing,
+    /// knots with zero data are returned as well. A segment with zero data at both
+    /// knots is zero everywhere, only its knots are returned.
+    /// Segments inside [masked gaps](crate::interp1d::Interp1DBuilder::mask) are skipped.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let data = array![1.0, -1.0, -2.0, 0.0, 2.0];
+    /// let interpolator = Interp1DBuilder::new(data).build().unwrap();
+    ///
+    /// assert_eq!(interpolator.zeros(), vec![0.5, 3.0]);
+    /// ```
+    pub fn zeros(&self) -> Vec<Sx::Elem> {
+        let zero = Sd::Elem::zero();
+        let two: Sx::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let last = self.x.len() - 1;
+        let mut zeros = Vec::new();
+        for idx in 0..last {
+            let (x_left, x_right) = (self.x[idx], self.x[idx + 1]);
+            let (y_left, y_right) = (self.data[idx], self.data[idx + 1]);
+            if y_left == zero {
+                zeros.push(x_left);
+            }
+            let crossing = y_left < zero && y_right > zero || y_left > zero && y_right < zero;
+            if crossing && self.check_gaps((x_left + x_right) / two).is_ok() {
+                let t: Sx::Elem =
+                    cast(y_left / (y_left - y_right)).unwrap_or_else(|| unimplemented!());
+                zeros.push(x_left + (x_right - x_left) * t);
+            }
+        }
+        if self.data[last] == zero && self.is_in_range(self.x[last]) {
+            zeros.push(self.x[last]);
+        }
+        zeros
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<Sd, Sx, D> approx::AbsDiffEq for Interp1D<Sd, Sx, D, Linear>
+where
+    Sd: Data,
+    Sd::Elem: LinearData<Sx::Elem> + approx::RelativeEq,
+    <Sd::Elem as approx::AbsDiffEq>::Epsilon: Clone,
+    Sx: Data,
+    Sx::Elem: Num
+        + PartialOrd
+        + NumCast
+        + Copy
+        + Debug
+        + Send
+        + approx::RelativeEq<Epsilon = <Sd::Elem as approx::AbsDiffEq>::Epsilon>,
+    D: Dimension + RemoveAxis,
+{
+    type Epsilon = <Sd::Elem as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Sd::Elem::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.strategy == other.strategy && self.base_abs_diff_eq(other, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<Sd, Sx, D> approx::RelativeEq for Interp1D<Sd, Sx, D, Linear>
+where
+    Sd: Data,
+    Sd::Elem: LinearData<Sx::Elem> + approx::RelativeEq,
+    <Sd::Elem as approx::AbsDiffEq>::Epsilon: Clone,
+    Sx: Data,
+    Sx::Elem: Num
+        + PartialOrd
+        + NumCast
+        + Copy
+        + Debug
+        + Send
+        + approx::RelativeEq<Epsilon = <Sd::Elem as approx::AbsDiffEq>::Epsilon>,
+    D: Dimension + RemoveAxis,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        Sd::Elem::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.strategy == other.strategy && self.base_relative_eq(other, epsilon, max_relative)
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt::{Debug, Display};
+
+use ndarray::{
+    Array, ArrayBase, ArrayViewMut, Axis, Data, Dimension, Ix1, RemoveAxis, Slice, ViewRepr, Zip,
+};
+use num_traits::{cast, One};
+
+use crate::{
+    interp1d::{ExtrapolateMode, Interp1D},
+    BuilderError, InterpolateError,
+};
+
+use super::{
+    cubic_spline::{CubicSpline, SplineNum},
+    describe_extrapolation, Interp1DStrategy, Interp1DStrategyBuilder,
+};
+
+const AX0: Axis = Axis(0);
+
+/// Local Spline Interpolation Strategy
+///
+/// Instead of one global cubic spline, a [`CubicSpline`] with not-a-knot
+/// boundaries is fitted to each window of `window` consecutive knots.
+/// Neighbouring windows share `overlap` knots, inside the overlap the two
+/// local splines are blended with a smoothstep weight (a partition of unity).
+/// Every query only uses the knots of at most two windows, and the build cost
+/// grows linearly with the number of knots.
+///
+/// # Continuity
+/// Inside a window the interpolant is twice continuously differentiable.
+/// The blend weight rises from `0` to `1` across the overlap and its derivative
+/// vanishes at both ends, so the interpolant is continuously differentiable
+/// at the window seams. The second derivative may jump there.
+///
+/// Data with at most `window` knots is interpolated by a single [`CubicSpline`].
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// let x = Array::linspace(0.0, 10.0, 101);
+/// let data = x.mapv(f64::sin);
+/// let interp = Interp1DBuilder::new(data)
+///     .x(x)
+///     .strategy(LocalSpline::new().window(16).overlap(4))
+///     .build()
+///     .unwrap();
+///
+/// let result = interp.interp_scalar(4.321).unwrap();
+/// assert!((result - 4.321f64.sin()).abs() < 1e-5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalSpline {
+    extrapolate_low: bool,
+    extrapolate_high: bool,
+    window: usize,
+    overlap: usize,
+}
+
+/// The Local Spline 1d interpolation Strategy (Implementation)
+///
+/// This is constructed by [`LocalSpline`]
+#[derive(Debug)]
+pub struct LocalSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    D: Dimension + RemoveAxis,
+{
+    /// the fitted windows, sorted by their first knot
+    windows: Vec<Window<Sd::Elem, D>>,
+    window: usize,
+    overlap: usize,
+    extrapolate_low: bool,
+    extrapolate_high: bool,
+}
+
+/// The cubic spline fitted to the knots `start..=end`
+#[derive(Debug, PartialEq)]
+struct Window<T, D: Dimension> {
+    start: usize,
+    end: usize,
+    /// spline coefficients of the segments in the window, see [`CubicSplineStrategy`](super::cubic_spline::CubicSplineStrategy)
+    a: Array<T, D>,
+    b: Array<T, D>,
+}
+
+impl LocalSpline {
+    /// create a local spline strategy with windows of 16 knots which overlap by 4 knots
+    pub fn new() -> Self {
+        Self {
+            extrapolate_low: false,
+            extrapolate_high: false,
+            window: 16,
+            overlap: 4,
+        }
+    }
+
+    /// does the strategy extrapolate? Default is `false`
+    ///
+    /// The splines of the first and last window are continued outside of the range.
+    /// This sets both sides, see [`extrapolate_low`](LocalSpline::extrapolate_low)
+    /// and [`extrapolate_high`](LocalSpline::extrapolate_high).
+    pub fn extrapolate(self, extrapolate: bool) -> Self {
+        self.extrapolate_low(extrapolate)
+            .extrapolate_high(extrapolate)
+    }
+
+    /// does the strategy extrapolate below the first knot? Default is `false`
+    pub fn extrapolate_low(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_low = extrapolate;
+        self
+    }
+
+    /// does the strategy extrapolate above the last knot? Default is `false`
+    pub fn extrapolate_high(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_high = extrapolate;
+        self
+    }
+
+    /// the number of knots in each window. Default is `16`
+    pub fn window(mut self, window: usize) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// the number of knots shared by neighbouring windows. Default is `4`
+    ///
+    /// The overlap needs to be at least `2` and at most half of the window.
+    pub fn overlap(mut self, overlap: usize) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// split `len` knots into windows, **returns** the first and last knot of each window
+    fn split(&self, len: usize) -> Vec<(usize, usize)> {
+        let step = self.window - self.overlap;
+        let mut windows = Vec::new();
+        let mut start = 0;
+        loop {
+            let end = start + self.window - 1;
+            if end >= len - 1 {
+                windows.push((start, len - 1));
+                return windows;
+            }
+            windows.push((start, end));
+            start += step;
+        }
+    }
+}
+
+impl Default for LocalSpline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategyBuilder<Sd, Sx, D> for LocalSpline
+where
+    Sd: Data,
+    Sd::Elem: SplineNum,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    const MINIMUM_DATA_LENGHT: usize = 3;
+    type FinishedStrat = LocalSplineStrategy<Sd, D>;
+
+    fn build<Sx2>(
+        self,
+        x: &ArrayBase<Sx2, Ix1>,
+        data: &ArrayBase<Sd, D>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        if self.overlap < 2 || 2 * self.overlap > self.window {
+            return Err(BuilderError::ValueError(format!(
+                "overlap needs to be at least 2 and at most half of the window, got window: {}, overlap: {}",
+                self.window, self.overlap
+            )));
+        }
+
+        let windows = self
+            .split(x.len())
+            .into_iter()
+            .map(|(start, end)| {
+                let knots = Slice::from(start..=end);
+                let strat = <CubicSpline<Sd::Elem, D> as Interp1DStrategyBuilder<
+                    ViewRepr<&Sd::Elem>,
+                    ViewRepr<&Sd::Elem>,
+                    D,
+                >>::build(
+                    CubicSpline::new(),
+                    &x.slice_axis(AX0, knots),
+                    &data.slice_axis(AX0, knots),
+                )?;
+                Ok(Window {
+                    start,
+                    end,
+                    a: strat.a,
+                    b: strat.b,
+                })
+            })
+            .collect::<Result<_, BuilderError>>()?;
+
+        Ok(LocalSplineStrategy {
+            windows,
+            window: self.window,
+            overlap: self.overlap,
+            extrapolate_low: self.extrapolate_low,
+            extrapolate_high: self.extrapolate_high,
+        })
+    }
+}
+
+impl<Sd, D> PartialEq for LocalSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    Sd::Elem: PartialEq,
+    D: Dimension + RemoveAxis,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.windows == other.windows
+            && self.window == other.window
+            && self.overlap == other.overlap
+            && self.extrapolate_low == other.extrapolate_low
+            && self.extrapolate_high == other.extrapolate_high
+    }
+}
+
+impl<Sd, D> Display for LocalSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    D: Dimension + RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LocalSpline (window: {}, overlap: {}, extrapolate: {})",
+            self.window,
+            self.overlap,
+            describe_extrapolation(self.extrapolate_low, self.extrapolate_high)
+        )
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategy<Sd, Sx, D> for LocalSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    Sd::Elem: SplineNum,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    const NAME: &'static str = "LocalSpline";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        ExtrapolateMode::from_sides(self.extrapolate_low, self.extrapolate_high)
+    }
+
+    fn memory_bytes(&self) -> usize {
+        let coefficients: usize = self
+            .windows
+            .iter()
+            .map(|window| window.a.len() + window.b.len())
+            .sum();
+        coefficients * std::mem::size_of::<Sd::Elem>()
+            + self.windows.capacity() * std::mem::size_of::<Window<Sd::Elem, D>>()
+    }
+
+    fn interp_into(
+        &self,
+        interpolator: &Interp1D<Sd, Sx, D, Self>,
+        mut target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
+        x: Sx::Elem,
+    ) -> Result<(), InterpolateError> {
+        let this = interpolator;
+        this.check_range(x, self.extrapolate_low, self.extrapolate_high)?;
+
+        let idx = this.get_index_left_of(x);
+        let (x_left, y_left) = this.index_point(idx);
+        let (x_right, y_right) = this.index_point(idx + 1);
+        let t = (x - x_left) / (x_right - x_left);
+        let one = Sd::Elem::one();
+
+        // the last window starting at or left of the segment
+        let k = self.windows.partition_point(|w| w.start <= idx) - 1;
+        let window = &self.windows[k];
+        Zip::from(&mut target)
+            .and(&y_left)
+            .and(&y_right)
+            .and(window.a.index_axis(AX0, idx - window.start))
+            .and(window.b.index_axis(AX0, idx - window.start))
+            .for_each(|y, &yl, &yr, &a, &b| {
+                *y = yl + (yr - yl) * t + (a * (one - t) + b * t) * (t * (one - t));
+            });
+
+        // inside the overlap with the previous window, blend from its spline into this one
+        let previous = match k.checked_sub(1).map(|k| &self.windows[k]) {
+            Some(previous) if idx < previous.end => previous,
+            _ => return Ok(()),
+        };
+        let (x_start, _) = this.index_point(window.start);
+        let (x_end, _) = this.index_point(previous.end);
+        let u = (x - x_start) / (x_end - x_start);
+        let two: Sd::Elem = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: Sd::Elem = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let weight = u * u * (three - two * u);
+        Zip::from(target)
+            .and(&y_left)
+            .and(&y_right)
+            .and(previous.a.index_axis(AX0, idx - previous.start))
+            .and(previous.b.index_axis(AX0, idx - previous.start))
+            .for_each(|y, &yl, &yr, &a, &b| {
+                let y_previous = yl + (yr - yl) * t + (a * (one - t) + b * t) * (t * (one - t));
+                *y = y_previous + (*y - y_previous) * weight;
+            });
+        Ok(())
+    }
+}
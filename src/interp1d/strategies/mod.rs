@@ -1,23 +1,42 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::Range};
 
 use ndarray::{ArrayBase, ArrayViewMut, Data, Dimension, Ix1};
 use num_traits::Num;
 
-use super::Interp1D;
-use crate::{BuilderError, InterpolateError};
+use super::{ExtrapolateMode, Interp1D};
+use crate::{linalg::Scratch, BuilderError, InterpolateError};
 
+pub mod angular;
+pub mod blend;
 pub mod cubic_spline;
+pub mod hermite;
 pub mod linear;
+pub mod local_spline;
+pub mod nearest;
+pub mod tension_spline;
+
+/// Describe the extrapolated sides in the [`Display`](std::fmt::Display) of a strategy
+pub(crate) fn describe_extrapolation(low: bool, high: bool) -> &'static str {
+    match (low, high) {
+        (true, true) => "true",
+        (false, false) => "false",
+        (true, false) => "low",
+        (false, true) => "high",
+    }
+}
 
 pub trait Interp1DStrategyBuilder<Sd, Sx, D>
 where
     Sd: Data,
-    Sd::Elem: Num + Debug + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + Debug + Send,
     D: Dimension,
     Self: Sized,
 {
     const MINIMUM_DATA_LENGHT: usize;
+    /// The name of the strategy, defaults to the name of the finished strategy
+    const NAME: &'static str = <Self::FinishedStrat as Interp1DStrategy<Sd, Sx, D>>::NAME;
     type FinishedStrat: Interp1DStrategy<Sd, Sx, D>;
 
     /// initialize the strategy by validating data and
@@ -36,17 +55,52 @@ where
         data: &ArrayBase<Sd, D>,
     ) -> Result<Self::FinishedStrat, BuilderError>
     where
-        Sx2: Data<Elem = Sd::Elem>;
+        Sx2: Data<Elem = Sx::Elem>;
+
+    /// Same as [`build`](Interp1DStrategyBuilder::build), but the workspace of a linear
+    /// solve is borrowed from `scratch` instead of being allocated.
+    /// This method is called in [`Interp1DBuilder::build_in`](crate::interp1d::Interp1DBuilder::build_in)
+    ///
+    /// The result must be the same as the one of [`build`](Interp1DStrategyBuilder::build).
+    /// The default implementation is for strategies without a linear solve and ignores `scratch`.
+    fn build_in<Sx2>(
+        self,
+        x: &ArrayBase<Sx2, Ix1>,
+        data: &ArrayBase<Sd, D>,
+        _scratch: &mut Scratch<Sx::Elem, Sd::Elem>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        self.build(x, data)
+    }
+
+    /// The strategy for the data columns `columns` along `Axis(1)`. This is called by
+    /// `Interp1DBuilder::build_par_chunks` (`rayon` feature) for each chunk of the data.
+    ///
+    /// Strategies with a configuration in the shape of the data select the columns of it.
+    /// The default implementation is for strategies without such a configuration and
+    /// returns the strategy unchanged.
+    fn select_columns(self, _columns: Range<usize>) -> Result<Self, BuilderError> {
+        Ok(self)
+    }
 }
 
 pub trait Interp1DStrategy<Sd, Sx, D>
 where
     Sd: Data,
-    Sd::Elem: Num + Debug + Send,
-    Sx: Data<Elem = Sd::Elem>,
+    Sd::Elem: Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + Debug + Send,
     D: Dimension,
     Self: Sized,
 {
+    /// The name of the strategy, e.g. for logging
+    ///
+    /// Defaults to `"custom"`. [`Interp1D::write_binary`] stores the name to check it
+    /// when reading, so custom strategies which are written should override it.
+    const NAME: &'static str = "custom";
+
     /// Interpolate the at position x into the target array.
     /// This is used internally by [`Interp1D`].
     ///
@@ -62,4 +116,46 @@ where
         target: ArrayViewMut<Sd::Elem, D::Smaller>,
         x: Sx::Elem,
     ) -> Result<(), InterpolateError>;
+
+    /// Interpolate at position x into the target array, where x is known to be in range.
+    /// This is used internally by [`Interp1D::interp_array_unchecked`].
+    ///
+    /// Strategies can skip their range check, the default implementation
+    /// calls [`interp_into`](Interp1DStrategy::interp_into) which checks the range again.
+    ///
+    /// In addition to the guarantees of [`interp_into`](Interp1DStrategy::interp_into),
+    /// x is inside the range of the interpolator, see [`Interp1D::is_in_range`].
+    fn interp_into_in_range(
+        &self,
+        interpolator: &Interp1D<Sd, Sx, D, Self>,
+        target: ArrayViewMut<Sd::Elem, D::Smaller>,
+        x: Sx::Elem,
+    ) -> Result<(), InterpolateError> {
+        self.interp_into(interpolator, target, x)
+    }
+
+    /// The bytes of the heap memory owned by the strategy, e.g. for coefficient arrays.
+    /// This is used by [`Interp1D::memory_bytes`].
+    ///
+    /// The default implementation is for strategies without coefficients and returns `0`.
+    fn memory_bytes(&self) -> usize {
+        0
+    }
+
+    /// The extrapolation behaviour of the strategy.
+    /// This is used by [`Interp1D::extrapolation_mode`].
+    ///
+    /// The default implementation is for strategies which do not extrapolate
+    /// and returns [`ExtrapolateMode::No`].
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        ExtrapolateMode::No
+    }
+
+    /// Receive the masked gaps and [`closed_upper`](crate::interp1d::Interp1DBuilder::closed_upper)
+    /// of the interpolator. This is called by [`Interp1DBuilder::build`](crate::interp1d::Interp1DBuilder::build)
+    /// after the strategy is built, e.g. to pass them on to inner interpolators.
+    ///
+    /// The default implementation is for strategies which only use the interpolator
+    /// and does nothing.
+    fn set_range(&mut self, _gaps: &[(Sx::Elem, Sx::Elem)], _closed_upper: bool) {}
 }
@@ -0,0 +1,147 @@
+use std::fmt::{Debug, Display};
+
+use ndarray::{ArrayBase, ArrayViewMut, Data, Dimension, Ix1, RemoveAxis};
+use num_traits::{Num, NumCast, Zero};
+
+use crate::{
+    interp1d::{ExtrapolateMode, Interp1D},
+    BuilderError, InterpolateError,
+};
+
+use super::{describe_extrapolation, Interp1DStrategy, Interp1DStrategyBuilder};
+
+/// Nearest Neighbour Interpolation Strategy
+///
+/// Every query returns the data of the closest knot, the data is copied and never blended.
+/// A query exactly between two knots snaps to the left knot.
+///
+/// This does not need any arithmetic on the data, so it is suited for lookup tables
+/// with sparse integer keys, e.g. mapping categories to rows of parameters.
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// let keys = array![10, 20, 50];
+/// let rows = array![[1.0, 0.1], [2.0, 0.2], [5.0, 0.5]];
+/// let lookup = Interp1DBuilder::new_with_x(rows, keys)
+///     .strategy(Nearest::new().extrapolate(true))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(lookup.interp(20).unwrap(), array![2.0, 0.2]);
+/// assert_eq!(lookup.interp(42).unwrap(), array![5.0, 0.5]);
+/// assert_eq!(lookup.interp(99).unwrap(), array![5.0, 0.5]);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Nearest {
+    extrapolate_low: bool,
+    extrapolate_high: bool,
+}
+
+impl Nearest {
+    /// create a nearest neighbour interpolation strategy
+    pub fn new() -> Self {
+        Self {
+            extrapolate_low: false,
+            extrapolate_high: false,
+        }
+    }
+
+    /// does the strategy extrapolate? Default is `false`
+    ///
+    /// Queries outside of the range snap to the first or the last knot.
+    /// This sets both sides, see [`extrapolate_low`](Nearest::extrapolate_low)
+    /// and [`extrapolate_high`](Nearest::extrapolate_high).
+    pub fn extrapolate(self, extrapolate: bool) -> Self {
+        self.extrapolate_low(extrapolate)
+            .extrapolate_high(extrapolate)
+    }
+
+    /// does the strategy extrapolate below the first knot? Default is `false`
+    pub fn extrapolate_low(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_low = extrapolate;
+        self
+    }
+
+    /// does the strategy extrapolate above the last knot? Default is `false`
+    pub fn extrapolate_high(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_high = extrapolate;
+        self
+    }
+}
+
+impl Default for Nearest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for Nearest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Nearest (extrapolate: {})",
+            describe_extrapolation(self.extrapolate_low, self.extrapolate_high)
+        )
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategyBuilder<Sd, Sx, D> for Nearest
+where
+    Sd: Data,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+{
+    const MINIMUM_DATA_LENGHT: usize = 2;
+    type FinishedStrat = Nearest;
+    fn build<Sx2>(
+        self,
+        _x: &ArrayBase<Sx2, Ix1>,
+        _data: &ArrayBase<Sd, D>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        Ok(self)
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategy<Sd, Sx, D> for Nearest
+where
+    Sd: Data,
+    Sd::Elem: Zero + Copy + Debug + Send,
+    Sx: Data,
+    Sx::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    D: Dimension + RemoveAxis,
+{
+    const NAME: &'static str = "Nearest";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        ExtrapolateMode::from_sides(self.extrapolate_low, self.extrapolate_high)
+    }
+
+    fn interp_into(
+        &self,
+        interpolator: &Interp1D<Sd, Sx, D, Self>,
+        mut target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
+        x: Sx::Elem,
+    ) -> Result<(), InterpolateError> {
+        let this = interpolator;
+        this.check_range(x, self.extrapolate_low, self.extrapolate_high)?;
+
+        let idx = this.get_index_left_of(x);
+        let (x_left, y_left) = this.index_point(idx);
+        let (x_right, y_right) = this.index_point(idx + 1);
+        // compare the distances without subtracting past the knots,
+        // which would underflow for unsigned keys
+        let nearest = if x <= x_left || x < x_right && x - x_left <= x_right - x {
+            y_left
+        } else {
+            y_right
+        };
+        target.assign(&nearest);
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Sub,
+};
+
+use ndarray::{Array, ArrayBase, ArrayViewMut, Axis, Data, Dimension, Ix1, RemoveAxis, Zip};
+use num_traits::{Num, NumCast, One, Zero};
+
+use crate::{
+    interp1d::{ExtrapolateMode, Interp1D},
+    BuilderError, InterpolateError,
+};
+
+use super::{describe_extrapolation, hermite, Interp1DStrategy, Interp1DStrategyBuilder};
+
+const AX0: Axis = Axis(0);
+
+/// Tension Spline Interpolation Strategy
+///
+/// A piecewise cubic hermite interpolation with a single smoothness parameter.
+/// The tangent at each knot is the Catmull-Rom tangent (the slope between the
+/// two neighbouring knots). Within each segment the tangents are blended
+/// with the slope of the segment by the `tension`:
+///  - `tension = 0` is a Catmull-Rom spline
+///  - `tension = 1` is linear interpolation
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray::*;
+/// # use approx::*;
+/// let data = array![0.0, 1.0, 3.0, 2.0];
+/// let interp = Interp1DBuilder::new(data)
+///     .strategy(TensionSpline::new().tension(0.5))
+///     .build()
+///     .unwrap();
+///
+/// let result = interp.interp_scalar(1.5).unwrap();
+/// # assert_abs_diff_eq!(result, 2.0625, epsilon=f64::EPSILON);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TensionSpline<T> {
+    extrapolate_low: bool,
+    extrapolate_high: bool,
+    tension: T,
+}
+
+/// The Tension Spline 1d interpolation Strategy (Implementation)
+///
+/// This is constructed by [`TensionSpline`]
+#[derive(Debug)]
+pub struct TensionSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    D: Dimension + RemoveAxis,
+{
+    /// the Catmull-Rom tangent at each knot
+    tangents: Array<Sd::Elem, D>,
+    tension: Sd::Elem,
+    extrapolate_low: bool,
+    extrapolate_high: bool,
+}
+
+impl<T> TensionSpline<T>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+{
+    /// create a tension spline stratgy with `tension = 0`
+    pub fn new() -> Self {
+        Self {
+            extrapolate_low: false,
+            extrapolate_high: false,
+            tension: T::zero(),
+        }
+    }
+
+    /// does the strategy extrapolate? Default is `false`
+    ///
+    /// This sets both sides, see [`extrapolate_low`](TensionSpline::extrapolate_low)
+    /// and [`extrapolate_high`](TensionSpline::extrapolate_high).
+    pub fn extrapolate(self, extrapolate: bool) -> Self {
+        self.extrapolate_low(extrapolate)
+            .extrapolate_high(extrapolate)
+    }
+
+    /// does the strategy extrapolate below the first knot? Default is `false`
+    pub fn extrapolate_low(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_low = extrapolate;
+        self
+    }
+
+    /// does the strategy extrapolate above the last knot? Default is `false`
+    pub fn extrapolate_high(mut self, extrapolate: bool) -> Self {
+        self.extrapolate_high = extrapolate;
+        self
+    }
+
+    /// set the tension in the range `[0, 1]`. Default is `0`
+    pub fn tension(mut self, tension: T) -> Self {
+        self.tension = tension;
+        self
+    }
+}
+
+impl<T> Default for TensionSpline<T>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Send,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategyBuilder<Sd, Sx, D> for TensionSpline<Sd::Elem>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    const MINIMUM_DATA_LENGHT: usize = 2;
+    type FinishedStrat = TensionSplineStrategy<Sd, D>;
+
+    fn build<Sx2>(
+        self,
+        x: &ArrayBase<Sx2, Ix1>,
+        data: &ArrayBase<Sd, D>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = Sx::Elem>,
+    {
+        let TensionSpline {
+            extrapolate_low,
+            extrapolate_high,
+            tension,
+        } = self;
+        if !(Sd::Elem::zero() <= tension && tension <= Sd::Elem::one()) {
+            return Err(BuilderError::ValueError(format!(
+                "tension needs to be in the range [0, 1], got: {tension:?}"
+            )));
+        }
+
+        // central differences for inner knots, one sided differences at the boundary
+        let len = x.len();
+        let mut tangents = Array::zeros(data.raw_dim());
+        for (i, tangent) in tangents.axis_iter_mut(AX0).enumerate() {
+            let left = i.saturating_sub(1);
+            let right = (i + 1).min(len - 1);
+            let dx = x[right] - x[left];
+            Zip::from(tangent)
+                .and(data.index_axis(AX0, left))
+                .and(data.index_axis(AX0, right))
+                .for_each(|m, &y_left, &y_right| *m = (y_right - y_left) / dx);
+        }
+
+        Ok(TensionSplineStrategy {
+            tangents,
+            tension,
+            extrapolate_low,
+            extrapolate_high,
+        })
+    }
+}
+
+impl<Sd, D> PartialEq for TensionSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    Sd::Elem: PartialEq,
+    D: Dimension + RemoveAxis,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.tangents == other.tangents
+            && self.tension == other.tension
+            && self.extrapolate_low == other.extrapolate_low
+            && self.extrapolate_high == other.extrapolate_high
+    }
+}
+
+impl<Sd, D> Display for TensionSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    Sd::Elem: Debug,
+    D: Dimension + RemoveAxis,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TensionSpline (tension: {:?}, extrapolate: {})",
+            self.tension,
+            describe_extrapolation(self.extrapolate_low, self.extrapolate_high)
+        )
+    }
+}
+
+impl<Sd, Sx, D> Interp1DStrategy<Sd, Sx, D> for TensionSplineStrategy<Sd, D>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    const NAME: &'static str = "TensionSpline";
+
+    fn extrapolation_mode(&self) -> ExtrapolateMode {
+        ExtrapolateMode::from_sides(self.extrapolate_low, self.extrapolate_high)
+    }
+
+    fn memory_bytes(&self) -> usize {
+        self.tangents.len() * std::mem::size_of::<Sd::Elem>()
+    }
+
+    fn interp_into(
+        &self,
+        interpolator: &Interp1D<Sd, Sx, D, Self>,
+        target: ArrayViewMut<'_, <Sd>::Elem, <D as Dimension>::Smaller>,
+        x: Sx::Elem,
+    ) -> Result<(), InterpolateError> {
+        let this = interpolator;
+        this.check_range(x, self.extrapolate_low, self.extrapolate_high)?;
+
+        let idx = this.get_index_left_of(x);
+        let (x_left, y_left) = this.index_point(idx);
+        let (x_right, y_right) = this.index_point(idx + 1);
+        let m_left = self.tangents.index_axis(AX0, idx);
+        let m_right = self.tangents.index_axis(AX0, idx + 1);
+
+        let one = Sd::Elem::one();
+        let h = x_right - x_left;
+        let t = (x - x_left) / h;
+        let [h00, h10, h01, h11] = hermite::basis(t);
+
+        let tension = self.tension;
+        Zip::from(target)
+            .and(y_left)
+            .and(y_right)
+            .and(m_left)
+            .and(m_right)
+            .for_each(|y, &yl, &yr, &ml, &mr| {
+                let secant = (yr - yl) / h;
+                let ml = tension * secant + (one - tension) * ml;
+                let mr = tension * secant + (one - tension) * mr;
+                *y = h00 * yl + h10 * h * ml + h01 * yr + h11 * h * mr;
+            });
+        Ok(())
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<Sd, Sx, D> approx::AbsDiffEq for Interp1D<Sd, Sx, D, TensionSplineStrategy<Sd, D>>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send + approx::RelativeEq,
+    <Sd::Elem as approx::AbsDiffEq>::Epsilon: Clone,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    type Epsilon = <Sd::Elem as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Sd::Elem::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.strategy.extrapolate_low == other.strategy.extrapolate_low
+            && self.strategy.extrapolate_high == other.strategy.extrapolate_high
+            && self
+                .strategy
+                .tension
+                .abs_diff_eq(&other.strategy.tension, epsilon.clone())
+            && self
+                .strategy
+                .tangents
+                .abs_diff_eq(&other.strategy.tangents, epsilon.clone())
+            && self.base_abs_diff_eq(other, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<Sd, Sx, D> approx::RelativeEq for Interp1D<Sd, Sx, D, TensionSplineStrategy<Sd, D>>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send + approx::RelativeEq,
+    <Sd::Elem as approx::AbsDiffEq>::Epsilon: Clone,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        Sd::Elem::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.strategy.extrapolate_low == other.strategy.extrapolate_low
+            && self.strategy.extrapolate_high == other.strategy.extrapolate_high
+            && self.strategy.tension.relative_eq(
+                &other.strategy.tension,
+                epsilon.clone(),
+                max_relative.clone(),
+            )
+            && self.strategy.tangents.relative_eq(
+                &other.strategy.tangents,
+                epsilon.clone(),
+                max_relative.clone(),
+            )
+            && self.base_relative_eq(other, epsilon, max_relative)
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt::Debug;
+
+use ndarray::{Array, ArrayView, ArrayViewMut, Axis, Data, Dimension, Ix1, RemoveAxis, Zip};
+use num_traits::{cast, Num, NumCast};
+
+use crate::{BuilderError, InterpolateError};
+
+use super::{Interp1D, Interp1DStrategy};
+
+/// One dimensional lookup table sampled from an [`Interp1D`]
+///
+/// The interpolator is evaluated once on `n` evenly spaced points over its
+/// x range. Queries compute the table index directly from `x`, so they take the
+/// same time for every strategy and do not search the x axis.
+///
+/// # Accuracy
+/// The table only approximates the interpolator it was sampled from.
+/// With the table spacing `h = (x_max - x_min) / (n - 1)`:
+///  - [`interp`](TableInterp1D::interp) blends linearly between neighbouring entries,
+///    which adds an error of up to `h² / 8 · max|f''|`.
+///    Kinks of the interpolator between two entries (e.g. at the knots of a [`Linear`](super::Linear)
+///    interpolation) are rounded off
+///  - [`nearest`](TableInterp1D::nearest) returns the closest entry,
+///    which adds an error of up to `h / 2 · max|f'|`
+///
+/// At the table entries both are exact.
+///
+/// This is created by [`Interp1D::with_table`].
+#[derive(Debug)]
+pub struct TableInterp1D<T, D>
+where
+    T: Debug,
+    D: Dimension,
+{
+    /// x value of the first table entry
+    x_min: T,
+    /// x value of the last table entry
+    x_max: T,
+    /// spacing of the table entries
+    step: T,
+    /// the sampled values, the first axis has one entry per table position
+    table: Array<T, D>,
+}
+
+impl<Sd, Sx, D, Strat> Interp1D<Sd, Sx, D, Strat>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+    Strat: Interp1DStrategy<Sd, Sx, D>,
+{
+    /// Sample the interpolator on `n` evenly spaced points over its x range
+    /// into a [`TableInterp1D`].
+    ///
+    /// The table needs memory for `n` rows of the data, in exchange each query
+    /// is a direct index into the table. See [`TableInterp1D`] for the accuracy.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp1d::*;
+    /// # use ndarray::*;
+    /// let x = Array::linspace(0.0, 10.0, 21);
+    /// let data = x.mapv(f64::sin);
+    /// let interp = Interp1DBuilder::new(data)
+    ///     .x(x)
+    ///     .strategy(cubic_spline::CubicSpline::new())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let table = interp.with_table(1001).unwrap();
+    /// let exact = interp.interp_scalar(4.321).unwrap();
+    /// assert!((table.interp_scalar(4.321).unwrap() - exact).abs() < 1e-4);
+    /// ```
+    ///
+    /// # Errors
+    ///  - When `n < 2`
+    ///  - When the interpolator can not be evaluated at a table position,
+    ///    e.g. inside a [gap](super::Interp1DBuilder::max_gap)
+    pub fn with_table(&self, n: usize) -> Result<TableInterp1D<Sd::Elem, D>, InterpolateError> {
+        if n < 2 {
+            return Err(BuilderError::ValueError(format!(
+                "a table needs at least 2 entries, got {n}"
+            ))
+            .into());
+        }
+        let x = self.x();
+        let (x_min, x_max) = (x[0], x[x.len() - 1]);
+        let table = self.to_uniform(x_min, x_max, n)?;
+        let step = (x_max - x_min)
+            / cast(n - 1).unwrap_or_else(|| {
+                unimplemented!("casting from usize to a number should always work")
+            });
+        Ok(TableInterp1D {
+            x_min,
+            x_max,
+            step,
+            table,
+        })
+    }
+}
+
+impl<T, D> TableInterp1D<T, D>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug,
+    D: Dimension + RemoveAxis,
+{
+    /// Calculate the values at `x` by blending linearly between the two
+    /// closest table entries.
+    ///
+    /// # Errors
+    /// When `x` is outside of the range of the table
+    pub fn interp(&self, x: T) -> Result<Array<T, D::Smaller>, InterpolateError> {
+        let mut target = Array::zeros(self.table.raw_dim().remove_axis(Axis(0)));
+        self.interp_into(x, target.view_mut()).map(|_| target)
+    }
+
+    /// Calculate the values at `x` like [`interp`](TableInterp1D::interp)
+    /// and store them into the provided buffer
+    ///
+    /// # Panics
+    /// When the buffer does not have the shape of the data with the first axis removed
+    pub fn interp_into(
+        &self,
+        x: T,
+        buffer: ArrayViewMut<'_, T, D::Smaller>,
+    ) -> Result<(), InterpolateError> {
+        let (idx, t) = self.locate(x)?;
+        Zip::from(buffer)
+            .and(self.table.index_axis(Axis(0), idx))
+            .and(self.table.index_axis(Axis(0), idx + 1))
+            .for_each(|y, &left, &right| *y = left + (right - left) * t);
+        Ok(())
+    }
+
+    /// A view of the table entry closest to `x`, without blending.
+    ///
+    /// # Errors
+    /// When `x` is outside of the range of the table
+    pub fn nearest(&self, x: T) -> Result<ArrayView<'_, T, D::Smaller>, InterpolateError> {
+        let (idx, t) = self.locate(x)?;
+        let half: T = cast(0.5).unwrap_or_else(|| unimplemented!());
+        let idx = if t < half { idx } else { idx + 1 };
+        Ok(self.table.index_axis(Axis(0), idx))
+    }
+
+    /// The sampled table, the first axis has one entry per table position
+    pub fn table(&self) -> ArrayView<'_, T, D> {
+        self.table.view()
+    }
+
+    /// The x range `(x_min, x_max)` covered by the table
+    pub fn x_range(&self) -> (T, T) {
+        (self.x_min, self.x_max)
+    }
+
+    /// **returns** the index of the table entry left of `x` and the position
+    /// of `x` between that entry and the next, normalized to `[0, 1]`
+    fn locate(&self, x: T) -> Result<(usize, T), InterpolateError> {
+        if !(x >= self.x_min && x <= self.x_max) {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:#?} is not in range of the table",
+            )));
+        }
+        let pos = (x - self.x_min) / self.step;
+        let last = self.table.len_of(Axis(0)) - 2;
+        let idx = pos.to_usize().unwrap_or(0).min(last);
+        let t = pos
+            - cast(idx).unwrap_or_else(|| {
+                unimplemented!("casting from usize to a number should always work")
+            });
+        Ok((idx, t))
+    }
+}
+
+impl<T> TableInterp1D<T, Ix1>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug,
+{
+    /// Calculate the value at `x` like [`interp`](TableInterp1D::interp)
+    /// when the data is one dimensional
+    pub fn interp_scalar(&self, x: T) -> Result<T, InterpolateError> {
+        let (idx, t) = self.locate(x)?;
+        let left = self.table[idx];
+        Ok(left + (self.table[idx + 1] - left) * t)
+    }
+}
@@ -11,11 +11,12 @@
 //! # Strategies
 //!  - [`Bilinear`] Linear interpolation strategy
 
-use std::{any::TypeId, fmt::Debug, ops::Sub};
+use std::{any::TypeId, cmp::Ordering, fmt::Debug, ops::Sub};
 
 use ndarray::{
-    Array, Array1, ArrayBase, ArrayView, ArrayViewMut, ArrayViewMut1, Axis, AxisDescription, Data,
-    DimAdd, Dimension, IntoDimension, Ix1, Ix2, OwnedRepr, RemoveAxis, Slice, Zip,
+    Array, Array1, Array2, ArrayBase, ArrayView, ArrayViewMut, ArrayViewMut1, Axis,
+    AxisDescription, Data, DimAdd, Dimension, IntoDimension, Ix1, Ix2, OwnedRepr, RemoveAxis,
+    Slice, Zip,
 };
 use num_traits::{cast, Num, NumCast};
 
@@ -29,7 +30,7 @@ use crate::{
 mod aliases;
 mod strategies;
 pub use aliases::*;
-pub use strategies::{Bilinear, Interp2DStrategy, Interp2DStrategyBuilder};
+pub use strategies::{Bilinear, Interp2DStrategy, Interp2DStrategyBuilder, ScanlineX};
 
 /// Two dimensional interpolator
 #[derive(Debug)]
@@ -145,6 +146,47 @@ where
             .map(|_| target)
     }
 
+    /// Convert the interpolator into a closure over `(x, y)`, e.g. to pass the
+    /// interpolated surface to a 2D integrator or optimizer.
+    ///
+    /// The closure returns the same as [`interp`](Interp2D::interp).
+    ///
+    /// ```rust
+    /// # use ndarray_interp::*;
+    /// # use ndarray_interp::interp2d::*;
+    /// # use ndarray::*;
+    /// // sample a surface on a n x n grid over [0, 1] x [0, 1]
+    /// fn sample<F>(f: F, n: usize) -> Result<Array2<f64>, InterpolateError>
+    /// where
+    ///     F: Fn(f64, f64) -> Result<Array0<f64>, InterpolateError>,
+    /// {
+    ///     let grid = Array::linspace(0.0, 1.0, n);
+    ///     let mut samples = Array2::zeros((n, n));
+    ///     for ((i, j), sample) in samples.indexed_iter_mut() {
+    ///         *sample = f(grid[i], grid[j])?.into_scalar();
+    ///     }
+    ///     Ok(samples)
+    /// }
+    ///
+    /// let data = array![
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ];
+    /// let surface = Interp2D::builder(data).build().unwrap().into_fn();
+    /// let samples = sample(surface, 3).unwrap();
+    /// # assert_eq!(samples, array![[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn into_fn(
+        self,
+    ) -> impl Fn(
+        Sx::Elem,
+        Sy::Elem,
+    )
+        -> Result<Array<Sd::Elem, <D::Smaller as Dimension>::Smaller>, InterpolateError> {
+        move |x, y| self.interp(x, y)
+    }
+
     /// Calculate the interpolated values at `(x, y)`.
     /// and stores the result into the provided buffer
     ///
@@ -284,6 +326,126 @@ where
         Ok(())
     }
 
+    /// Calculate the interpolated values on the grid spanned by `new_x` and `new_y`
+    ///
+    /// The result has the shape of the data with the first two axes replaced
+    /// by `[new_x.len(), new_y.len()]`, the value at `[i, j, ...]` is interpolated at
+    /// `(new_x[i], new_y[j])`.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp2d::*;
+    /// # use ndarray::*;
+    /// let data = array![
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ];
+    /// let interpolator = Interp2D::builder(data).build().unwrap();
+    ///
+    /// let grid = interpolator
+    ///     .interp_grid(&array![0.0, 0.5], &array![0.0, 0.5, 1.0])
+    ///     .unwrap();
+    /// assert_eq!(grid, array![[1.0, 1.5, 2.0], [2.0, 2.5, 3.0]]);
+    /// ```
+    pub fn interp_grid<Sqx, Sqy>(
+        &self,
+        new_x: &ArrayBase<Sqx, Ix1>,
+        new_y: &ArrayBase<Sqy, Ix1>,
+    ) -> Result<Array<Sd::Elem, D>, InterpolateError>
+    where
+        Sqx: Data<Elem = Sx::Elem>,
+        Sqy: Data<Elem = Sy::Elem>,
+    {
+        let mut dim = self.data.raw_dim();
+        dim[0] = new_x.len();
+        dim[1] = new_y.len();
+        let mut zs = Array::zeros(dim);
+        self.interp_grid_into(new_x, new_y, zs.view_mut())
+            .map(|_| zs)
+    }
+
+    /// Calculate the interpolated values on the grid spanned by `new_x` and `new_y`
+    /// and stores the result into the provided buffer
+    ///
+    /// This can improve performance compared to [`interp_grid`](Interp2D::interp_grid)
+    /// when the same buffer is reused for repeated queries,
+    /// because it does not allocate any memory for the result
+    ///
+    /// # panics
+    /// when the buffer shape is not the shape of the data with the first two axes
+    /// replaced by `[new_x.len(), new_y.len()]`
+    pub fn interp_grid_into<Sqx, Sqy>(
+        &self,
+        new_x: &ArrayBase<Sqx, Ix1>,
+        new_y: &ArrayBase<Sqy, Ix1>,
+        mut buffer: ArrayViewMut<'_, Sd::Elem, D>,
+    ) -> Result<(), InterpolateError>
+    where
+        Sqx: Data<Elem = Sx::Elem>,
+        Sqy: Data<Elem = Sy::Elem>,
+    {
+        let mut expect = self.data.raw_dim();
+        expect[0] = new_x.len();
+        expect[1] = new_y.len();
+        assert!(
+            buffer.raw_dim() == expect,
+            "wrong buffer shape, expected: {:?}, got: {:?}",
+            expect.slice(),
+            buffer.shape()
+        );
+
+        for (&x, mut row) in new_x.iter().zip(buffer.axis_iter_mut(Axis(0))) {
+            for (&y, target) in new_y.iter().zip(row.axis_iter_mut(Axis(0))) {
+                self.strategy.interp_into(self, target, x, y)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Calculate the interpolated values along a path, e.g. to extract
+    /// a profile along a diagonal or curved line across the surface.
+    ///
+    /// Each row of `path` is a point `(x, y)`. The result has the shape of the data with
+    /// the first two axes replaced by the number of points, so for a scalar surface
+    /// ([`type@Ix2`] data) it is one dimensional.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp2d::*;
+    /// # use ndarray::*;
+    /// let data = array![
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ];
+    /// let interpolator = Interp2D::builder(data).build().unwrap();
+    ///
+    /// let path = array![[0.0, 0.0], [0.5, 0.5], [1.0, 1.0]];
+    /// let profile = interpolator.interp_along(&path).unwrap();
+    /// assert_eq!(profile, array![1.0, 2.5, 4.0]);
+    /// ```
+    ///
+    /// # panics
+    /// when `path` does not have two columns
+    pub fn interp_along<Sp>(
+        &self,
+        path: &ArrayBase<Sp, Ix2>,
+    ) -> Result<Array<Sd::Elem, D::Smaller>, InterpolateError>
+    where
+        Sp: Data<Elem = Sd::Elem>,
+    {
+        assert!(
+            path.ncols() == 2,
+            "each row of the path needs to be a point `(x, y)`, got {} columns",
+            path.ncols()
+        );
+        let mut dim = self.data.raw_dim().remove_axis(Axis(0));
+        dim[0] = path.nrows();
+        let mut zs = Array::zeros(dim);
+        for (point, target) in path.rows().into_iter().zip(zs.axis_iter_mut(Axis(0))) {
+            self.strategy
+                .interp_into(self, target, point[0], point[1])?;
+        }
+        Ok(zs)
+    }
+
     fn interp_array_into_1d<Sqx, Sqy>(
         &self,
         xs: &ArrayBase<Sqx, Ix1>,
@@ -377,6 +539,11 @@ where
     pub fn is_in_y_range(&self, y: Sy::Elem) -> bool {
         self.y[0] <= y && y <= self.y[self.y.len() - 1]
     }
+
+    /// The name of the interpolation strategy, see [`Interp2DStrategy::NAME`]
+    pub fn strategy_name(&self) -> &'static str {
+        Strat::NAME
+    }
 }
 
 impl<Sd, D> Interp2DBuilder<Sd, OwnedRepr<Sd::Elem>, OwnedRepr<Sd::Elem>, D, Bilinear>
@@ -405,6 +572,98 @@ where
     }
 }
 
+impl<T> Interp2DBuilder<OwnedRepr<T>, OwnedRepr<T>, OwnedRepr<T>, Ix2, Bilinear>
+where
+    T: Num + PartialOrd + NumCast + Copy + Debug + Sub,
+{
+    /// Create a new [Interp2DBuilder] from a list of `[x, y, z]` points,
+    /// e.g. the rows of a CSV file.
+    ///
+    /// The points can be in any order, but they have to cover a regular grid:
+    /// every combination of the distinct x and y values must appear exactly once.
+    /// The distinct values become the x and y axis and `z` becomes the data.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp2d::*;
+    /// # use ndarray::*;
+    /// let xyz = array![
+    ///     [1.0, 0.0, 2.0],
+    ///     [0.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 1.0],
+    ///     [1.0, 1.0, 3.0],
+    /// ];
+    /// let interp = Interp2DBuilder::from_triples(&xyz).unwrap().build().unwrap();
+    /// assert_eq!(interp.interp_scalar(0.5, 0.5).unwrap(), 1.5);
+    /// ```
+    ///
+    /// # Errors
+    ///  - [`BuilderError::ShapeError`] when `xyz` does not have 3 columns
+    ///  - [`BuilderError::ValueError`] when a coordinate can not be compared (e.g. `NaN`),
+    ///    when a point appears twice or when the points do not form a grid.
+    ///    There is no strategy for scattered points.
+    pub fn from_triples<S>(xyz: &ArrayBase<S, Ix2>) -> Result<Self, BuilderError>
+    where
+        S: Data<Elem = T>,
+    {
+        if xyz.ncols() != 3 {
+            return Err(BuilderError::ShapeError(format!(
+                "every point needs the 3 coordinates [x, y, z], got {}",
+                xyz.ncols()
+            )));
+        }
+        let distinct = |column: usize, name: &str| {
+            let mut values = xyz.column(column).to_vec();
+            if let Some(value) = values.iter().find(|&v| v.partial_cmp(v).is_none()) {
+                return Err(BuilderError::ValueError(format!(
+                    "{name} = {value:?} can not be compared"
+                )));
+            }
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            values.dedup();
+            Ok(Array1::from(values))
+        };
+        let x = distinct(0, "x")?;
+        let y = distinct(1, "y")?;
+        if x.len() * y.len() != xyz.nrows() {
+            return Err(BuilderError::ValueError(format!(
+                "the {} points do not form a grid of {} x values and {} y values, scattered points are not supported",
+                xyz.nrows(),
+                x.len(),
+                y.len()
+            )));
+        }
+
+        let index = |axis: &Array1<T>, value: &T| {
+            axis.as_slice()
+                .and_then(|axis| {
+                    axis.binary_search_by(|v| v.partial_cmp(value).unwrap_or(Ordering::Equal))
+                        .ok()
+                })
+                .unwrap_or_else(|| unreachable!("the axis contains every coordinate"))
+        };
+        let mut data = Array2::zeros((x.len(), y.len()));
+        let mut seen = Array2::from_elem((x.len(), y.len()), false);
+        for point in xyz.rows() {
+            let idx = (index(&x, &point[0]), index(&y, &point[1]));
+            if seen[idx] {
+                return Err(BuilderError::ValueError(format!(
+                    "the point x = {:?}, y = {:?} appears more than once",
+                    point[0], point[1]
+                )));
+            }
+            seen[idx] = true;
+            data[idx] = point[2];
+        }
+
+        Ok(Interp2DBuilder {
+            x,
+            y,
+            data,
+            strategy: Bilinear::new(),
+        })
+    }
+}
+
 impl<Sd, Sx, Sy, D, Strat> Interp2DBuilder<Sd, Sx, Sy, D, Strat>
 where
     Sd: Data,
@@ -1,26 +1,68 @@
 use std::{fmt::Debug, ops::Sub};
 
-use ndarray::{Data, Dimension, RemoveAxis, Zip};
-use num_traits::{Num, NumCast};
+use ndarray::{
+    Array, ArrayBase, ArrayView, ArrayView1, ArrayViewMut, Axis, Data, Dimension, Ix1, RemoveAxis,
+    ViewRepr, Zip,
+};
+use num_traits::{cast, Num, NumCast};
 
-use crate::{interp1d::Linear, InterpolateError};
+use crate::{
+    interp1d::{Interp1D, Interp1DBuilder, Linear},
+    interp2d::Interp2D,
+    vector_extensions::VectorExtensions,
+    BuilderError, InterpolateError,
+};
 
 use super::{Interp2DStrategy, Interp2DStrategyBuilder};
 
 #[derive(Debug)]
 pub struct Bilinear {
     extrapolate: bool,
+    smooth_normals: bool,
 }
 
 impl Bilinear {
     pub fn new() -> Self {
-        Bilinear { extrapolate: false }
+        Bilinear {
+            extrapolate: false,
+            smooth_normals: false,
+        }
     }
 
     pub fn extrapolate(mut self, yes: bool) -> Self {
         self.extrapolate = yes;
         self
     }
+
+    /// Apply a smoothstep to the cell-local coordinates before blending.
+    ///
+    /// Plain bilinear interpolation has gradients which jump at the cell edges.
+    /// With this option the gradient normal to a cell edge goes to zero on both
+    /// sides of the edge, so the surface has continuous gradients without
+    /// solving for a bicubic surface. Values at the nodes are preserved exactly.
+    ///
+    /// The smoothed coordinate is clamped to the cell, so when extrapolating
+    /// the value of the closest edge is continued.
+    pub fn smooth_normals(mut self, yes: bool) -> Self {
+        self.smooth_normals = yes;
+        self
+    }
+
+    /// map `x` in `[x1, x2]` with the smoothstep `3t² - 2t³` of the local coordinate `t`
+    fn smoothstep<T>(x1: T, x2: T, x: T) -> T
+    where
+        T: Num + PartialOrd + NumCast + Copy,
+    {
+        let two: T = cast(2.0).unwrap_or_else(|| unimplemented!());
+        let three: T = cast(3.0).unwrap_or_else(|| unimplemented!());
+        let mut t = (x - x1) / (x2 - x1);
+        if t < T::zero() {
+            t = T::zero();
+        } else if t > T::one() {
+            t = T::one();
+        }
+        x1 + (x2 - x1) * t * t * (three - two * t)
+    }
 }
 
 impl Default for Bilinear {
@@ -61,6 +103,8 @@ where
     D: Dimension + RemoveAxis,
     D::Smaller: RemoveAxis,
 {
+    const NAME: &'static str = "Bilinear";
+
     fn interp_into(
         &self,
         interpolator: &crate::interp2d::Interp2D<Sd, Sx, Sy, D, Self>,
@@ -84,6 +128,11 @@ where
         let (_, _, z12) = interpolator.index_point(x_idx, y_idx + 1);
         let (_, _, z21) = interpolator.index_point(x_idx + 1, y_idx);
         let (x2, y2, z22) = interpolator.index_point(x_idx + 1, y_idx + 1);
+        let (x, y) = if self.smooth_normals {
+            (Self::smoothstep(x1, x2, x), Self::smoothstep(y1, y2, y))
+        } else {
+            (x, y)
+        };
 
         Zip::from(z11)
             .and(z12)
@@ -98,3 +147,227 @@ where
         Ok(())
     }
 }
+
+impl<Sd, Sx, Sy, D> Interp2D<Sd, Sx, Sy, D, Bilinear>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    Sy: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+    D::Smaller: RemoveAxis,
+{
+    /// A one dimensional interpolator along y at the fixed x index `x_index`,
+    /// e.g. to look at a single line of the surface.
+    ///
+    /// The bilinear surface is linear along each grid line, so the [`Linear`] interpolator
+    /// matches the surface at `x = x[x_index]`. It extrapolates when the surface does.
+    /// The interpolator borrows the data of the surface.
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp2d::*;
+    /// # use ndarray::*;
+    /// let data = array![
+    ///     [1.0, 2.0, 4.0],
+    ///     [3.0, 4.0, 8.0],
+    /// ];
+    /// let surface = Interp2D::builder(data).build().unwrap();
+    ///
+    /// let line = surface.row_interpolator(1).unwrap();
+    /// assert_eq!(line.interp_scalar(1.5).unwrap(), 6.0);
+    /// assert_eq!(surface.interp_scalar(1.0, 1.5).unwrap(), 6.0);
+    /// ```
+    ///
+    /// # Errors
+    /// When the surface uses [`smooth_normals`](Bilinear::smooth_normals),
+    /// it is not linear along the grid lines.
+    ///
+    /// # panics
+    /// When `x_index` is out of bounds
+    #[allow(clippy::type_complexity)]
+    pub fn row_interpolator(
+        &self,
+        x_index: usize,
+    ) -> Result<Interp1D<ViewRepr<&Sd::Elem>, ViewRepr<&Sy::Elem>, D::Smaller, Linear>, BuilderError>
+    {
+        self.line_interpolator(self.data.index_axis(Axis(0), x_index), self.y.view())
+    }
+
+    /// A one dimensional interpolator along x at the fixed y index `y_index`,
+    /// see [`row_interpolator`](Interp2D::row_interpolator).
+    ///
+    /// # Errors
+    /// When the surface uses [`smooth_normals`](Bilinear::smooth_normals),
+    /// it is not linear along the grid lines.
+    ///
+    /// # panics
+    /// When `y_index` is out of bounds
+    #[allow(clippy::type_complexity)]
+    pub fn col_interpolator(
+        &self,
+        y_index: usize,
+    ) -> Result<Interp1D<ViewRepr<&Sd::Elem>, ViewRepr<&Sx::Elem>, D::Smaller, Linear>, BuilderError>
+    {
+        self.line_interpolator(self.data.index_axis(Axis(1), y_index), self.x.view())
+    }
+
+    /// Prepare the queries of a vertical scan line at `x`, e.g. a column of an image.
+    ///
+    /// The range check, the x interval and the weight of `x` are calculated once
+    /// and reused by all queries along y, see [`ScanlineX`].
+    ///
+    /// ```rust
+    /// # use ndarray_interp::interp2d::*;
+    /// # use ndarray::*;
+    /// # use approx::*;
+    /// let data = array![
+    ///     [1.0, 2.0, 4.0],
+    ///     [3.0, 4.0, 8.0],
+    /// ];
+    /// let surface = Interp2D::builder(data).build().unwrap();
+    ///
+    /// let scanline = surface.scanline_x(0.25).unwrap();
+    /// let ys = array![0.0, 0.5, 1.5];
+    /// let values = scanline.interp_array(&ys).unwrap();
+    /// # assert_abs_diff_eq!(values, array![1.5, 2.0, 3.75], epsilon = 1e-14);
+    /// for (&y, &value) in ys.iter().zip(&values) {
+    ///     assert_abs_diff_eq!(value, surface.interp_scalar(0.25, y).unwrap(), epsilon = 1e-14);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// When `x` is out of range and the surface does not extrapolate
+    pub fn scanline_x(
+        &self,
+        x: Sx::Elem,
+    ) -> Result<ScanlineX<'_, Sd, Sx, Sy, D>, InterpolateError> {
+        if !self.strategy.extrapolate && !self.is_in_x_range(x) {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "x = {x:?} is not in range"
+            )));
+        }
+        let x_idx = self.x.get_lower_index(x);
+        let (x1, x2) = (self.x[x_idx], self.x[x_idx + 1]);
+        let x = if self.strategy.smooth_normals {
+            Bilinear::smoothstep(x1, x2, x)
+        } else {
+            x
+        };
+        Ok(ScanlineX {
+            interpolator: self,
+            x_idx,
+            weight: (x - x1) / (x2 - x1),
+        })
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn line_interpolator<'a>(
+        &self,
+        data: ArrayView<'a, Sd::Elem, D::Smaller>,
+        axis: ArrayView1<'a, Sd::Elem>,
+    ) -> Result<
+        Interp1D<ViewRepr<&'a Sd::Elem>, ViewRepr<&'a Sd::Elem>, D::Smaller, Linear>,
+        BuilderError,
+    > {
+        if self.strategy.smooth_normals {
+            return Err(BuilderError::ValueError(
+                "the surface is not linear along the grid lines with smooth normals".into(),
+            ));
+        }
+        Interp1DBuilder::new_with_x(data, axis)
+            .strategy(Linear::new().extrapolate(self.strategy.extrapolate))
+            .build()
+    }
+}
+
+/// The queries of a vertical scan line of a [`Bilinear`] surface at a fixed x,
+/// created by [`Interp2D::scanline_x`].
+///
+/// The results are the same as [`Interp2D::interp`] at `(x, y)`
+/// up to rounding, the x interval and the weight of x are not calculated again.
+#[derive(Debug)]
+pub struct ScanlineX<'a, Sd, Sx, Sy, D>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    Sy: Data<Elem = Sd::Elem>,
+    D: Dimension,
+{
+    interpolator: &'a Interp2D<Sd, Sx, Sy, D, Bilinear>,
+    /// the index of the knot left of x
+    x_idx: usize,
+    /// the weight of the knot right of x
+    weight: Sd::Elem,
+}
+
+impl<Sd, Sx, Sy, D> ScanlineX<'_, Sd, Sx, Sy, D>
+where
+    Sd: Data,
+    Sd::Elem: Num + PartialOrd + NumCast + Copy + Debug + Sub + Send,
+    Sx: Data<Elem = Sd::Elem>,
+    Sy: Data<Elem = Sd::Elem>,
+    D: Dimension + RemoveAxis,
+    D::Smaller: RemoveAxis,
+{
+    /// Calculate the interpolated values at `y` on the scan line into `target`,
+    /// like [`Interp2D::interp_into`].
+    ///
+    /// # panics
+    /// When `target` does not have the shape of the data without the first two axes
+    pub fn interp_into(
+        &self,
+        y: Sy::Elem,
+        target: ArrayViewMut<'_, Sd::Elem, <D::Smaller as Dimension>::Smaller>,
+    ) -> Result<(), InterpolateError> {
+        let interp = self.interpolator;
+        if !interp.strategy.extrapolate && !interp.is_in_y_range(y) {
+            return Err(InterpolateError::OutOfBounds(format!(
+                "y = {y:?} is not in range"
+            )));
+        }
+        let y_idx = interp.y.get_lower_index(y);
+        let (_, y1, z11) = interp.index_point(self.x_idx, y_idx);
+        let (_, y2, z12) = interp.index_point(self.x_idx, y_idx + 1);
+        let (_, _, z21) = interp.index_point(self.x_idx + 1, y_idx);
+        let (_, _, z22) = interp.index_point(self.x_idx + 1, y_idx + 1);
+        let y = if interp.strategy.smooth_normals {
+            Bilinear::smoothstep(y1, y2, y)
+        } else {
+            y
+        };
+
+        let weight = self.weight;
+        Zip::from(z11)
+            .and(z12)
+            .and(z21)
+            .and(z22)
+            .and(target)
+            .for_each(|&z11, &z12, &z21, &z22, z| {
+                let z1 = z11 + (z21 - z11) * weight;
+                let z2 = z12 + (z22 - z12) * weight;
+                *z = Linear::calc_frac((y1, z1), (y2, z2), y)
+            });
+        Ok(())
+    }
+
+    /// Calculate the interpolated values at all `ys` on the scan line.
+    ///
+    /// **returns** the values with the shape of the data without the first axis,
+    /// the first axis of the result has one entry for each value in `ys`
+    pub fn interp_array<Sq>(
+        &self,
+        ys: &ArrayBase<Sq, Ix1>,
+    ) -> Result<Array<Sd::Elem, D::Smaller>, InterpolateError>
+    where
+        Sq: Data<Elem = Sd::Elem>,
+    {
+        let mut dim = self.interpolator.data.raw_dim().remove_axis(Axis(0));
+        dim[0] = ys.len();
+        let mut zs = Array::zeros(dim);
+        for (&y, target) in ys.iter().zip(zs.axis_iter_mut(Axis(0))) {
+            self.interp_into(y, target)?;
+        }
+        Ok(zs)
+    }
+}
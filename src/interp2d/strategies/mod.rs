@@ -9,7 +9,7 @@ use super::Interp2D;
 
 mod bilinear;
 
-pub use bilinear::Bilinear;
+pub use bilinear::{Bilinear, ScanlineX};
 
 pub trait Interp2DStrategyBuilder<Sd, Sx, Sy, D>
 where
@@ -21,6 +21,8 @@ where
     D::Smaller: RemoveAxis,
 {
     const MINIMUM_DATA_LENGHT: usize;
+    /// The name of the strategy, defaults to the name of the finished strategy
+    const NAME: &'static str = <Self::FinishedStrat as Interp2DStrategy<Sd, Sx, Sy, D>>::NAME;
     type FinishedStrat: Interp2DStrategy<Sd, Sx, Sy, D>;
 
     /// initialize the strategy by validating data and
@@ -53,6 +55,9 @@ where
     D::Smaller: RemoveAxis,
     Self: Sized,
 {
+    /// The name of the strategy, e.g. for logging. Defaults to `"custom"`
+    const NAME: &'static str = "custom";
+
     /// Interpolate the at position `(x, y)` into the target array.
     /// This is used internally by [`Interp2D`].
     ///
@@ -12,6 +12,10 @@
 //! **1D Strategies**
 //!  - [`interp1d::Linear`] - Linear interpolation and extrapolation
 //!  - [`interp1d::cubic_spline`] - Cubic Spline interpolation with different boundary conditions.
+//!  - [`interp1d::TensionSpline`] - Blend between linear and Catmull-Rom interpolation
+//!  - [`interp1d::LocalSpline`] - Cubic splines fitted to overlapping windows, for long signals
+//!  - [`interp1d::Nearest`] - Nearest neighbour lookup, also for integer x axes
+//!  - [`interp1d::Angular`] - Linear interpolation of angles which wrap around after a period
 //!
 //! # 2D Interpolation
 //! The [interp2d] module provides the [`Interp2D`](interp2d::Interp2D) interpolator
@@ -31,6 +35,7 @@
 //! [`Interp2DStrategyBuilder`](interp2d::Interp2DStrategyBuilder) traits.
 //!
 //! See also the `custom_strategy.rs` example.
+//! Strategies which solve for their coefficients can use the banded solver in [linalg].
 //!
 //! # Examples
 //! **1D Example**
@@ -121,10 +126,11 @@ use thiserror::Error;
 mod dim_extensions;
 pub mod interp1d;
 pub mod interp2d;
+pub mod linalg;
 pub mod vector_extensions;
 
 /// Errors during Interpolator creation
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum BuilderError {
     /// Insufficient data for the chosen interpolation strategy
     #[error("{0}")]
@@ -143,6 +149,85 @@ pub enum BuilderError {
 pub enum InterpolateError {
     #[error("{0}")]
     OutOfBounds(String),
+    /// The data is not strictly monotonic, e.g. in
+    /// [`value_to_index`](interp1d::Interp1D::value_to_index)
+    #[error("{0}")]
+    Monotonic(String),
+    /// An interpolator which is built as part of the interpolation could not be built,
+    /// e.g. in [`interp_ragged`](interp1d::interp_ragged)
+    #[error(transparent)]
+    Build(#[from] BuilderError),
+}
+
+/// Build a one dimensional interpolator and evaluate it in a single expression
+///
+/// `interp!(x => y, at q)` interpolates the one dimensional data `y` over the x axis `x`
+/// at the query `q`. It expands to the [`Interp1DBuilder`](interp1d::Interp1DBuilder) chain,
+/// `x` and `y` are moved into the builder, pass views to keep them.
+///
+/// The strategy defaults to [`Linear`](interp1d::Linear),
+/// `linear` or `cubic` ([`CubicSpline`](interp1d::cubic_spline::CubicSpline)) can be given
+/// explicitly before `at`. Both are built with their default settings.
+///
+/// The query can be a single value or a list of values `at [q0, q1, ...]`.
+/// The macro evaluates to `Result<T, InterpolateError>` for a single value and to
+/// `Result<Vec<T>, InterpolateError>` for a list, errors of the build are returned as
+/// [`InterpolateError::Build`].
+///
+/// ```rust
+/// # use ndarray::*;
+/// use ndarray_interp::interp;
+///
+/// let x = array![0.0, 1.0, 2.0, 3.0];
+/// let y = array![0.0, 2.0, 1.0, 3.0];
+///
+/// let linear = interp!(x.view() => y.view(), at 0.5).unwrap();
+/// assert_eq!(linear, 1.0);
+///
+/// // the not-a-knot spline through four points is a single cubic polynomial
+/// let cubic: f64 = interp!(x.view() => y.view(), cubic, at 0.5).unwrap();
+/// # assert!((cubic - 1.75).abs() < 1e-12);
+///
+/// let values = interp!(x => y, at [0.5, 1.5, 2.5]).unwrap();
+/// assert_eq!(values, vec![1.0, 1.5, 2.0]);
+/// ```
+#[macro_export]
+macro_rules! interp {
+    (@build $x:expr, $y:expr, $strategy:expr, [$($q:expr),+ $(,)?]) => {
+        $crate::interp1d::Interp1DBuilder::new($y)
+            .x($x)
+            .strategy($strategy)
+            .build()
+            .map_err($crate::InterpolateError::from)
+            .and_then(|interp| {
+                [$($q),+]
+                    .into_iter()
+                    .map(|q| interp.interp_scalar(q))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+    };
+    (@build $x:expr, $y:expr, $strategy:expr, $q:expr) => {
+        $crate::interp1d::Interp1DBuilder::new($y)
+            .x($x)
+            .strategy($strategy)
+            .build()
+            .map_err($crate::InterpolateError::from)
+            .and_then(|interp| interp.interp_scalar($q))
+    };
+    ($x:expr => $y:expr, linear, at $($query:tt)+) => {
+        $crate::interp!(@build $x, $y, $crate::interp1d::Linear::new(), $($query)+)
+    };
+    ($x:expr => $y:expr, cubic, at $($query:tt)+) => {
+        $crate::interp!(
+            @build $x,
+            $y,
+            $crate::interp1d::cubic_spline::CubicSpline::new(),
+            $($query)+
+        )
+    };
+    ($x:expr => $y:expr, at $($query:tt)+) => {
+        $crate::interp!(@build $x, $y, $crate::interp1d::Linear::new(), $($query)+)
+    };
 }
 
 /// cast `a` from type `A` to type `B` without any safety checks
@@ -0,0 +1,309 @@
+//! Linear solvers for interpolation strategies which solve for their coefficients.
+
+use std::mem::size_of;
+
+use ndarray::{
+    ArrayBase, ArrayViewMut, ArrayViewMut2, Axis, DataMut, Dimension, Ix2, RemoveAxis, Zip,
+};
+use num_traits::Zero;
+
+use crate::interp1d::cubic_spline::{SplineData, SplineNum};
+
+const AX0: Axis = Axis(0);
+
+/// Reusable workspace for the linear solves of a strategy build.
+///
+/// Strategies like the [`CubicSpline`](crate::interp1d::cubic_spline::CubicSpline) solve a
+/// linear system for their coefficients. [`Interp1DBuilder::build_in`](crate::interp1d::Interp1DBuilder::build_in)
+/// borrows the memory for the solve from a `Scratch` instead of allocating it.
+/// The buffers grow to the largest system solved so far, so repeated builds of
+/// the same size do not allocate for the solve after the first one.
+/// Only the solve uses the scratch, the other temporaries of a build are still
+/// allocated, see [`build_in`](crate::interp1d::Interp1DBuilder::build_in).
+///
+/// `X` is the type of the x axis and `T` the type of the data.
+///
+/// ```rust
+/// # use ndarray_interp::interp1d::*;
+/// # use ndarray_interp::interp1d::cubic_spline::*;
+/// # use ndarray_interp::linalg::Scratch;
+/// # use ndarray::*;
+/// let mut scratch = Scratch::new();
+/// for offset in [0.0, 1.0, 2.0] {
+///     let interp = Interp1DBuilder::new(array![0.0, 1.0, 0.0, 1.0] + offset)
+///         .strategy(CubicSpline::new())
+///         .build_in(&mut scratch)
+///         .unwrap();
+///     # assert_eq!(interp.interp_scalar(1.0).unwrap(), 1.0 + offset);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scratch<X, T> {
+    pub(crate) diagonals: Vec<X>,
+    pub(crate) band: Vec<X>,
+    pub(crate) rhs: Vec<T>,
+    pub(crate) solution: Vec<T>,
+}
+
+impl<X, T> Scratch<X, T> {
+    /// create an empty scratch, the buffers are allocated by the first build
+    pub fn new() -> Self {
+        Self {
+            diagonals: Vec::new(),
+            band: Vec::new(),
+            rhs: Vec::new(),
+            solution: Vec::new(),
+        }
+    }
+
+    /// The bytes of the heap memory held by the scratch
+    pub fn memory_bytes(&self) -> usize {
+        (self.diagonals.capacity() + self.band.capacity()) * size_of::<X>()
+            + (self.rhs.capacity() + self.solution.capacity()) * size_of::<T>()
+    }
+
+    /// The buffers of a tridiagonal system with `dim[0]` unknowns: the upper, middle and
+    /// lower diagonal as the rows of the first view, the right hand side and the
+    /// buffer for the band storage of the solver
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn tridiagonal<D>(
+        &mut self,
+        dim: D,
+    ) -> (ArrayViewMut2<'_, X>, ArrayViewMut<'_, T, D>, &mut Vec<X>)
+    where
+        X: Clone + Zero,
+        T: Clone + Zero,
+        D: Dimension,
+    {
+        let len = dim[0];
+        (
+            zeroed_view(&mut self.diagonals, Ix2(3, len)),
+            zeroed_view(&mut self.rhs, dim),
+            &mut self.band,
+        )
+    }
+}
+
+impl<X, T> Default for Scratch<X, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A zeroed view of the shape `dim` on the start of `buffer`, the buffer grows when it is too short
+pub(crate) fn zeroed_view<E, D>(buffer: &mut Vec<E>, dim: D) -> ArrayViewMut<'_, E, D>
+where
+    E: Clone + Zero,
+    D: Dimension,
+{
+    buffer.clear();
+    buffer.resize(dim.size(), E::zero());
+    ArrayViewMut::from_shape(dim, buffer.as_mut_slice()).unwrap_or_else(|_| unreachable!())
+}
+
+/// Solve the banded linear system `A * x = rhs` in place.
+///
+/// `A` is a `n x n` matrix with `lower` diagonals below and `upper` diagonals above
+/// the main diagonal. It is passed in the compact band storage used by LAPACK
+/// and SciPy's `solve_banded`: `ab` has the shape `(lower + upper + 1, n)` and
+/// `ab[[upper + i - j, j]] = A[i, j]`. Entries of `ab` outside of the matrix are not used.
+/// `ab` is overwritten by the decomposition, it can be an owned array or a view.
+///
+/// `rhs` has `n` entries along the first axis, each further axis is an independent
+/// right hand side. It is overwritten with the solution `x`.
+///
+/// The system is solved by a LU decomposition without pivoting, this needs `A`
+/// to be e.g. diagonally dominant. A zero pivot is not detected and results in a
+/// division by zero. The cost is `O(n * lower * upper)` for the decomposition.
+///
+/// ```rust
+/// # use ndarray_interp::linalg::solve_banded;
+/// # use ndarray::*;
+/// # use approx::*;
+/// // the tridiagonal matrix
+/// // [[4, 1, 0],
+/// //  [1, 4, 1],
+/// //  [0, 1, 4]]
+/// let ab = array![
+///     [0.0, 1.0, 1.0], // upper diagonal, the first entry is not used
+///     [4.0, 4.0, 4.0], // main diagonal
+///     [1.0, 1.0, 0.0], // lower diagonal, the last entry is not used
+/// ];
+/// let mut x = array![5.0, 6.0, 5.0];
+/// solve_banded(1, 1, ab, x.view_mut());
+/// # assert_abs_diff_eq!(x, array![1.0, 1.0, 1.0], epsilon = 1e-15);
+/// ```
+///
+/// # panics
+/// When `ab` does not have the shape `(lower + upper + 1, n)`
+pub fn solve_banded<X, R, D, S>(
+    lower: usize,
+    upper: usize,
+    mut ab: ArrayBase<S, Ix2>,
+    mut rhs: ArrayViewMut<R, D>,
+) where
+    S: DataMut<Elem = X>,
+    X: SplineNum,
+    R: SplineData<X>,
+    D: Dimension + RemoveAxis,
+{
+    let n = rhs.len_of(AX0);
+    if ab.dim() != (lower + upper + 1, n) {
+        panic!(
+            "wrong band storage shape. Expected: {:?}, got: {:?}",
+            (lower + upper + 1, n),
+            ab.dim()
+        );
+    }
+    if n == 0 {
+        return;
+    }
+
+    // forward elimination, the multipliers are not stored because
+    // `rhs` is eliminated at the same time
+    for j in 0..n - 1 {
+        let pivot = ab[[upper, j]];
+        let (done, mut rest) = rhs.view_mut().split_at(AX0, j + 1);
+        let pivot_row = done.index_axis(AX0, j);
+        for i in j + 1..(j + lower + 1).min(n) {
+            let w = ab[[upper + i - j, j]] / pivot;
+            for c in j + 1..(j + upper + 1).min(n) {
+                let update = w * ab[[upper + j - c, c]];
+                ab[[upper + i - c, c]] -= update;
+            }
+            Zip::from(rest.index_axis_mut(AX0, i - j - 1))
+                .and(&pivot_row)
+                .for_each(|rhs, &rhs_pivot| *rhs = *rhs - rhs_pivot * w);
+        }
+    }
+
+    // back substitution
+    for i in (0..n).rev() {
+        let (mut todo, solved) = rhs.view_mut().split_at(AX0, i + 1);
+        let mut row = todo.index_axis_mut(AX0, i);
+        for c in i + 1..(i + upper + 1).min(n) {
+            let a = ab[[upper + i - c, c]];
+            Zip::from(&mut row)
+                .and(solved.index_axis(AX0, c - i - 1))
+                .for_each(|rhs, &x| *rhs = *rhs - x * a);
+        }
+        let diag = ab[[upper, i]];
+        row.map_inplace(|x| *x = *x / diag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array, Array1, Array2, Axis};
+
+    use super::solve_banded;
+
+    /// Gaussian elimination with partial pivoting on the dense matrix
+    fn dense_solve(mut a: Array2<f64>, mut b: Array1<f64>) -> Array1<f64> {
+        let n = b.len();
+        for j in 0..n {
+            let p = (j..n)
+                .max_by(|&k, &l| a[[k, j]].abs().total_cmp(&a[[l, j]].abs()))
+                .unwrap();
+            for c in 0..n {
+                a.swap([j, c], [p, c]);
+            }
+            b.swap(j, p);
+            for i in j + 1..n {
+                let w = a[[i, j]] / a[[j, j]];
+                for c in j..n {
+                    a[[i, c]] -= w * a[[j, c]];
+                }
+                b[i] -= w * b[j];
+            }
+        }
+        let mut x = Array1::zeros(n);
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|c| a[[i, c]] * x[c]).sum();
+            x[i] = (b[i] - sum) / a[[i, i]];
+        }
+        x
+    }
+
+    /// pack the bands of a dense matrix
+    fn to_bands(a: &Array2<f64>, lower: usize, upper: usize) -> Array2<f64> {
+        let n = a.nrows();
+        Array2::from_shape_fn((lower + upper + 1, n), |(r, j)| {
+            let i = (r + j).checked_sub(upper);
+            match i {
+                Some(i) if i < n => a[[i, j]],
+                _ => 0.0,
+            }
+        })
+    }
+
+    /// a diagonally dominant banded matrix
+    fn banded_matrix(n: usize, lower: usize, upper: usize) -> Array2<f64> {
+        Array2::from_shape_fn((n, n), |(i, j)| {
+            if i == j {
+                10.0 + i as f64 * 0.1
+            } else if (j < i && i - j <= lower) || (j > i && j - i <= upper) {
+                ((3 * i + 7 * j) % 5) as f64 - 2.5
+            } else {
+                0.0
+            }
+        })
+    }
+
+    #[test]
+    fn pentadiagonal() {
+        let n = 12;
+        let a = banded_matrix(n, 2, 2);
+        let b = Array::linspace(-3.0, 5.0, n).mapv(|x: f64| x.sin() + x);
+        let expect = dense_solve(a.clone(), b.clone());
+
+        let mut x = b.clone();
+        solve_banded(2, 2, to_bands(&a, 2, 2), x.view_mut());
+        assert_abs_diff_eq!(x, expect, epsilon = 1e-12);
+        assert_abs_diff_eq!(a.dot(&x), b, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn asymmetric_bandwidth() {
+        for (lower, upper) in [(0, 2), (3, 1), (1, 0), (2, 4)] {
+            let n = 9;
+            let a = banded_matrix(n, lower, upper);
+            let b = Array::linspace(1.0, 2.0, n);
+            let mut x = b.clone();
+            solve_banded(lower, upper, to_bands(&a, lower, upper), x.view_mut());
+            assert_abs_diff_eq!(x, dense_solve(a, b), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn multiple_right_hand_sides() {
+        let n = 7;
+        let a = banded_matrix(n, 2, 2);
+        let b = Array::from_shape_fn((n, 2, 3), |(i, j, k)| (i * 3 + j * 2 + k) as f64);
+        let mut x = b.clone();
+        solve_banded(2, 2, to_bands(&a, 2, 2), x.view_mut());
+        for j in 0..2 {
+            for k in 0..3 {
+                let column = b.index_axis(Axis(1), j).index_axis(Axis(1), k).to_owned();
+                let expect = dense_solve(a.clone(), column);
+                let result = x.index_axis(Axis(1), j).index_axis(Axis(1), k).to_owned();
+                assert_abs_diff_eq!(result, expect, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn single_equation() {
+        let mut x = array![6.0];
+        solve_banded(1, 1, array![[0.0], [3.0], [0.0]], x.view_mut());
+        assert_eq!(x, array![2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong band storage shape. Expected: (5, 4), got: (3, 4)")]
+    fn wrong_shape() {
+        let mut x = Array1::<f64>::zeros(4);
+        solve_banded(2, 2, Array2::<f64>::zeros((3, 4)), x.view_mut());
+    }
+}
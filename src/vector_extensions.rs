@@ -79,7 +79,7 @@ where
                 .unwrap_or_else(|| unimplemented!("casting from usize should always work!")),
         );
 
-        let mid = Linear::calc_frac(p1, p2, x);
+        let mid: S::Elem = Linear::calc_frac(p1, p2, x);
         let mid_idx: usize =
             cast(mid).unwrap_or_else(|| unimplemented!("failed to convert {mid:?} to usize"));
 
@@ -0,0 +1,96 @@
+use std::f64::consts::{PI, TAU};
+
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array};
+use ndarray_interp::interp1d::{Angular, Interp1DBuilder};
+use ndarray_interp::{BuilderError, InterpolateError};
+
+#[test]
+fn short_way_around() {
+    // 350° to 10° goes through 0°, 10° to 340° goes back through 0° as well
+    let data = array![350.0, 10.0, 340.0, 20.0];
+    let interp = Interp1DBuilder::new(data)
+        .strategy(Angular::new().period(360.0))
+        .build()
+        .unwrap();
+
+    let query = Array::linspace(0.0, 1.0, 5);
+    let expect = array![350.0, 355.0, 0.0, 5.0, 10.0];
+    assert_abs_diff_eq!(
+        interp.interp_array(&query).unwrap(),
+        expect,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(interp.interp_scalar(1.5).unwrap(), 355.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(interp.interp_scalar(2.5).unwrap(), 0.0, epsilon = 1e-12);
+    assert!(matches!(
+        interp.interp_scalar(3.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn half_period_goes_forward() {
+    // 0° to 180° and 180° to 0° are both half a period, each goes forward
+    let data = array![0.0, 180.0, 0.0];
+    let interp = Interp1DBuilder::new(data)
+        .strategy(Angular::new().period(360.0))
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(interp.interp_scalar(0.5).unwrap(), 90.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(interp.interp_scalar(1.5).unwrap(), 270.0, epsilon = 1e-12);
+}
+
+#[test]
+fn radians() {
+    let x = array![0.0, 1.0, 2.0];
+    let data = array![[TAU - 0.1, 0.5], [0.1, -0.5], [PI - 0.2, 0.5]];
+    let interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(Angular::new().extrapolate(true))
+        .build()
+        .unwrap();
+
+    // the results are wrapped into [0, 2π)
+    assert_abs_diff_eq!(
+        interp.interp(0.5).unwrap(),
+        array![0.0, 0.0],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        interp.interp(0.25).unwrap(),
+        array![TAU - 0.05, 0.25],
+        epsilon = 1e-12
+    );
+    // the second segment of the first column goes forward by π - 0.3
+    assert_abs_diff_eq!(
+        interp.interp(1.5).unwrap()[0],
+        0.1 + (PI - 0.3) / 2.0,
+        epsilon = 1e-12
+    );
+    // extrapolation continues along the shorter way
+    assert_abs_diff_eq!(interp.interp(-1.0).unwrap()[0], TAU - 0.3, epsilon = 1e-12);
+}
+
+#[test]
+fn invalid_period() {
+    for period in [0.0, -1.0, f64::NAN] {
+        let result = Interp1DBuilder::new(array![0.0, 1.0])
+            .strategy(Angular::new().period(period))
+            .build();
+        assert!(matches!(result, Err(BuilderError::ValueError(_))));
+    }
+}
+
+#[test]
+fn output_transform_after_wrapping() {
+    // 350° to 10° passes 0°, scaling the angles first would pass 90°
+    let interp = Interp1DBuilder::new(array![350.0, 10.0])
+        .strategy(Angular::new().period(360.0))
+        .output_transform(0.5, 0.0)
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(interp.interp_scalar(0.25).unwrap(), 177.5, epsilon = 1e-12);
+    assert_abs_diff_eq!(interp.interp_scalar(0.5).unwrap(), 0.0, epsilon = 1e-12);
+    assert_eq!(interp.data(), array![350.0, 10.0]);
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "approx")]
+
+use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+use ndarray::array;
+use ndarray_interp::interp1d::cubic_spline::{BoundaryCondition, CubicSpline};
+use ndarray_interp::interp1d::{Interp1DBuilder, Linear, TensionSpline};
+
+#[test]
+fn linear() {
+    let a = Interp1DBuilder::new(array![1.0, 2.0, 1.5])
+        .x(array![0.0, 1.0, 3.0])
+        .build()
+        .unwrap();
+    let b = Interp1DBuilder::new(array![1.0, 2.0 + 1e-12, 1.5])
+        .x(array![0.0, 1.0, 3.0])
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+    assert_relative_eq!(a, b, max_relative = 1e-9);
+    assert_ne!(a, b);
+
+    let extrapolating = Interp1DBuilder::new(array![1.0, 2.0, 1.5])
+        .x(array![0.0, 1.0, 3.0])
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_abs_diff_ne!(a, extrapolating);
+}
+
+#[test]
+fn cubic_spline() {
+    let build = |data| {
+        Interp1DBuilder::new(data)
+            .x(array![0.0, 0.5, 1.5, 2.0])
+            .strategy(CubicSpline::new().boundary(BoundaryCondition::Natural))
+            .build()
+            .unwrap()
+    };
+    let original = build(array![[0.0, 1.0], [1.0, 3.0], [2.0, 2.0], [3.0, 7.0]]);
+    let rebuilt = build(original.interp_array(&array![0.0, 0.5, 1.5, 2.0]).unwrap());
+    assert_abs_diff_eq!(original, rebuilt, epsilon = 1e-12);
+
+    let perturbed = build(array![[0.0, 1.0], [1.0, 3.0], [2.0, 2.1], [3.0, 7.0]]);
+    assert_abs_diff_ne!(original, perturbed, epsilon = 1e-3);
+}
+
+#[test]
+fn tension_spline() {
+    let build = |tension| {
+        Interp1DBuilder::new(array![0.0, 1.0, 3.0, 2.0])
+            .strategy(TensionSpline::new().tension(tension))
+            .build()
+            .unwrap()
+    };
+    assert_relative_eq!(build(0.5), build(0.5 + 1e-13), max_relative = 1e-9);
+    assert_abs_diff_ne!(build(0.5), build(0.6), epsilon = 1e-3);
+}
@@ -0,0 +1,105 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array};
+use ndarray_interp::interp1d::cubic_spline::CubicSpline;
+use ndarray_interp::interp1d::{Blend, Interp1DBuilder, Linear};
+use ndarray_interp::{BuilderError, InterpolateError};
+
+#[test]
+fn alpha_ends_match_strategies() {
+    let x = array![0.0, 0.5, 2.0, 3.0, 4.5];
+    let data = array![[1.0, 0.0], [3.0, 2.0], [2.0, 1.0], [0.0, 4.0], [1.0, 3.0]];
+    let query = Array::linspace(0.0, 4.5, 37);
+    let build = |alpha| {
+        Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(Blend::new(Linear::new(), CubicSpline::new()).alpha(alpha))
+            .build()
+            .unwrap()
+    };
+    let linear = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    let cubic = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    let linear = linear.interp_array(&query).unwrap();
+    let cubic = cubic.interp_array(&query).unwrap();
+    assert_eq!(build(0.0).interp_array(&query).unwrap(), linear);
+    assert_eq!(build(1.0).interp_array(&query).unwrap(), cubic);
+    assert_abs_diff_eq!(
+        build(0.3).interp_array(&query).unwrap(),
+        linear * 0.7 + cubic * 0.3,
+        epsilon = 1e-14
+    );
+}
+
+#[test]
+fn range_and_alpha() {
+    let data = array![0.0, 1.0, 0.0, 1.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .strategy(Blend::new(
+            Linear::new().extrapolate(true),
+            CubicSpline::new(),
+        ))
+        .build()
+        .unwrap();
+    // the spline does not extrapolate
+    assert!(matches!(
+        interp.interp_scalar(-0.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+
+    for alpha in [-0.1, 1.1, f64::NAN] {
+        let result = Interp1DBuilder::new(data.view())
+            .strategy(Blend::new(Linear::new(), CubicSpline::new()).alpha(alpha))
+            .build();
+        assert!(matches!(result, Err(BuilderError::ValueError(_))));
+    }
+    // the spline needs three knots
+    let result = Interp1DBuilder::new(array![0.0, 1.0])
+        .strategy(Blend::new(Linear::new(), CubicSpline::new()))
+        .build();
+    assert!(matches!(result, Err(BuilderError::NotEnoughData(_))));
+}
+
+#[test]
+fn closed_upper_and_gaps() {
+    let data = array![0.0, 1.0, 0.0, 1.0, 0.0, 1.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .strategy(Blend::new(Linear::new(), CubicSpline::new()))
+        .closed_upper(false)
+        .build()
+        .unwrap();
+    assert!(interp.interp_scalar(4.5).is_ok());
+    assert!(matches!(
+        interp.interp_scalar(5.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    // both strategies extrapolate above the range, so the last knot is evaluated
+    let interp = Interp1DBuilder::new(data.view())
+        .strategy(Blend::new(
+            Linear::new().extrapolate_high(true),
+            CubicSpline::new().extrapolate_high(true),
+        ))
+        .closed_upper(false)
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(5.0).unwrap(), 1.0);
+
+    let interp = Interp1DBuilder::new(data.view())
+        .strategy(Blend::new(Linear::new(), CubicSpline::new()))
+        .mask(array![true, true, false, false, true, true])
+        .unwrap()
+        .max_gap(1.5)
+        .build()
+        .unwrap();
+    assert!(interp.interp_scalar(0.5).is_ok());
+    assert!(matches!(
+        interp.interp_scalar(2.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
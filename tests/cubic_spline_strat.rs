@@ -1,10 +1,16 @@
-use approx::assert_relative_eq;
-use ndarray::{array, stack, Array1, Axis};
+use std::time::Duration;
+
+use approx::{assert_abs_diff_eq, assert_relative_eq};
+use ndarray::{array, concatenate, s, stack, Array, Array1, Axis, Ix1, OwnedRepr, Zip};
 use ndarray_interp::interp1d::cubic_spline::{
-    BoundaryCondition, CubicSpline, RowBoundary, SingleBoundary,
+    BoundaryCondition, CubicSpline, CubicSplineStrategy, RowBoundary, SingleBoundary, Symmetry,
+};
+use ndarray_interp::interp1d::{
+    ExtrapolateMode, Interp1D, Interp1DBuilder, Interp1DScalar, Interp1DStrategyBuilder,
 };
-use ndarray_interp::interp1d::{Interp1D, Interp1DBuilder};
+use ndarray_interp::linalg::Scratch;
 use ndarray_interp::{BuilderError, InterpolateError};
+use num_complex::Complex;
 
 #[test]
 fn interp_natural() {
@@ -187,6 +193,172 @@ fn not_a_knot_3_values() {
     assert_relative_eq!(res, expect, epsilon = f64::EPSILON, max_relative = 0.001);
 }
 
+#[test]
+fn not_a_knot_non_uniform() {
+    // the not-a-knot spline reproduces a cubic on any x axis,
+    // the spacing of the last two segments differs
+    let x = array![0.0, 1.0, 1.5, 3.0, 4.5, 5.0];
+    let poly = |x: f64| x * x * x - 2.0 * x * x + 1.0;
+    let interp = Interp1DBuilder::new(x.mapv(poly))
+        .x(x.view())
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::NotAKnot))
+        .build()
+        .unwrap();
+
+    let q = Array1::linspace(0.0, 5.0, 51);
+    assert_abs_diff_eq!(
+        interp.interp_array(&q).unwrap(),
+        q.mapv(poly),
+        epsilon = 1e-12
+    );
+    assert!(interp.is_single_polynomial(0, 1));
+    assert!(interp.is_single_polynomial(3, 4));
+}
+
+/// build a spline through `(0, 0), (1, 1), (3, 0)`
+fn spline_3_values(
+    boundary: BoundaryCondition<f64, Ix1>,
+) -> Interp1DScalar<f64, CubicSplineStrategy<OwnedRepr<f64>, Ix1>> {
+    Interp1DBuilder::new(array![0.0, 1.0, 0.0])
+        .x(array![0.0, 1.0, 3.0])
+        .strategy(CubicSpline::new().boundary(boundary))
+        .build()
+        .unwrap()
+}
+
+/// the slopes at the knots `[0, 1, 3]`
+fn slopes_3_values(
+    interp: &Interp1DScalar<f64, CubicSplineStrategy<OwnedRepr<f64>, Ix1>>,
+) -> Array1<f64> {
+    array![0.0, 1.0, 3.0].mapv(|x| interp.interp_value_and_slope(x).unwrap().1.into_scalar())
+}
+
+#[test]
+fn natural_3_values() {
+    // second derivatives M0 = M2 = 0 and 2 * (1 + 2) * M1 = 6 * (-1/2 - 1)
+    let interp = spline_3_values(BoundaryCondition::Natural);
+    assert_abs_diff_eq!(
+        interp.second_derivatives_at_knots(),
+        array![0.0, -1.5, 0.0],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        interp.interp_array(&array![0.5, 2.0]).unwrap(),
+        array![0.59375, 0.875],
+        epsilon = 1e-12
+    );
+
+    let individual = spline_3_values(BoundaryCondition::Individual(array![RowBoundary::Natural]));
+    assert_eq!(individual, interp);
+}
+
+#[test]
+fn clamped_3_values() {
+    // slopes k0 = k2 = 0 and 6 * k1 = 3 * (2 - 1/2)
+    let interp = spline_3_values(BoundaryCondition::Clamped);
+    assert_abs_diff_eq!(
+        slopes_3_values(&interp),
+        array![0.0, 0.75, 0.0],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        interp.interp_array(&array![0.5, 2.0]).unwrap(),
+        array![0.40625, 0.6875],
+        epsilon = 1e-12
+    );
+
+    let individual = spline_3_values(BoundaryCondition::Individual(array![RowBoundary::Clamped]));
+    assert_eq!(individual, interp);
+}
+
+#[test]
+fn mixed_derivatives_3_values() {
+    // k0 = 1, 2 * k0 + 6 * k1 + k2 = 4.5 and 2 * k1 + 4 * k2 = -3 + 2 * 2² / 2
+    let interp = spline_3_values(BoundaryCondition::Individual(array![RowBoundary::Mixed {
+        left: SingleBoundary::FirstDeriv(1.0),
+        right: SingleBoundary::SecondDeriv(2.0),
+    }]));
+    assert_abs_diff_eq!(
+        slopes_3_values(&interp),
+        array![1.0, 9.0 / 22.0, 1.0 / 22.0],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        interp.second_derivatives_at_knots()[2],
+        2.0,
+        epsilon = 1e-12
+    );
+
+    // the mirrored conditions
+    let interp = spline_3_values(BoundaryCondition::Individual(array![RowBoundary::Mixed {
+        left: SingleBoundary::SecondDeriv(2.0),
+        right: SingleBoundary::FirstDeriv(1.0),
+    }]));
+    // 2 * k0 + k1 = 3 - 2 * 1² / 2, 2 * k0 + 6 * k1 + k2 = 4.5 and k2 = 1
+    assert_abs_diff_eq!(
+        slopes_3_values(&interp),
+        array![0.85, 0.3, 1.0],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        interp.second_derivatives_at_knots()[0],
+        2.0,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn data_slope_boundary() {
+    let x = array![0.0, 1.0, 3.0, 4.5];
+    let y = array![[1.0, 0.0], [3.0, -1.0], [2.0, 4.0], [5.0, 1.0]];
+    let data_slope = RowBoundary::Mixed {
+        left: SingleBoundary::DataSlope,
+        right: SingleBoundary::DataSlope,
+    };
+    let boundary = BoundaryCondition::Individual(array![[data_slope.clone(), data_slope]]);
+    let interp = Interp1DBuilder::new(y.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().boundary(boundary))
+        .build()
+        .unwrap();
+
+    let (_, left) = interp.interp_value_and_slope(0.0).unwrap();
+    let (_, right) = interp.interp_value_and_slope(4.5).unwrap();
+    let expected_left = (&y.row(1) - &y.row(0)) / (x[1] - x[0]);
+    let expected_right = (&y.row(3) - &y.row(2)) / (x[3] - x[2]);
+    assert_abs_diff_eq!(left, expected_left, epsilon = 1e-12);
+    assert_abs_diff_eq!(right, expected_right, epsilon = 1e-12);
+
+    // the same as setting the slopes by hand
+    let boundary = BoundaryCondition::Individual(array![RowBoundary::Mixed {
+        left: SingleBoundary::FirstDeriv(2.0),
+        right: SingleBoundary::FirstDeriv(2.0),
+    }]);
+    let by_hand = Interp1DBuilder::new(y.column(0))
+        .x(x.view())
+        .strategy(CubicSpline::new().boundary(boundary))
+        .build()
+        .unwrap();
+    let query = Array::linspace(0.0, 4.5, 20);
+    assert_abs_diff_eq!(
+        interp.interp_array(&query).unwrap().column(0),
+        by_hand.interp_array(&query).unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn not_a_knot_and_clamped_3_values() {
+    // a single cubic through all points, p(x) = 2.25x - 1.5x² + 0.25x³ with p'(3) = 0
+    let interp = spline_3_values(BoundaryCondition::Individual(array![RowBoundary::Mixed {
+        left: SingleBoundary::NotAKnot,
+        right: SingleBoundary::Clamped,
+    }]));
+    let q = Array1::linspace(0.0, 3.0, 13);
+    let expect = q.mapv(|x: f64| 2.25 * x - 1.5 * x.powi(2) + 0.25 * x.powi(3));
+    assert_abs_diff_eq!(interp.interp_array(&q).unwrap(), expect, epsilon = 1e-12);
+}
+
 #[test]
 fn multidim_multi_bounds() {
     let y = array![[0.5, 1.0], [0.0, 1.5], [3.0, 0.5],];
@@ -439,6 +611,38 @@ fn bounds_shape_error2() {
         .unwrap();
 }
 
+#[test]
+fn bounds_dimensionality_error() {
+    let y = array![[0.5, 1.0], [0.0, 1.5], [3.0, 0.5],].into_dyn();
+    let build = |boundaries| {
+        Interp1DBuilder::new(y.view())
+            .strategy(CubicSpline::new().boundary(BoundaryCondition::Individual(boundaries)))
+            .build()
+    };
+
+    // wrong rank
+    let boundaries = array![RowBoundary::Natural, RowBoundary::Clamped].into_dyn();
+    let Err(BuilderError::ShapeError(msg)) = build(boundaries) else {
+        panic!("expected a shape error");
+    };
+    assert!(msg.contains("Expected 2 dimensional array"), "{msg}");
+    assert!(msg.contains("got 1 dimensions"), "{msg}");
+
+    // right rank, wrong extent
+    let boundaries = array![[
+        RowBoundary::Natural,
+        RowBoundary::Clamped,
+        RowBoundary::Natural
+    ]];
+    let Err(BuilderError::ShapeError(msg)) = build(boundaries.into_dyn()) else {
+        panic!("expected a shape error");
+    };
+    assert!(msg.contains("Expected: [1, 2], got: [1, 3]"), "{msg}");
+
+    let boundaries = array![[RowBoundary::Natural, RowBoundary::Clamped]];
+    assert!(build(boundaries.into_dyn()).is_ok());
+}
+
 #[test]
 #[should_panic(
     expected = "First: [0.5, 1.0], shape=[2], strides=[1], layout=CFcf (0xf), const ndim=1, last: [0.5, 1.1]"
@@ -607,3 +811,1613 @@ fn extrapolate_periodic_len3_multidim() {
     ];
     assert_relative_eq!(res, expect, epsilon = f64::EPSILON, max_relative = 0.001);
 }
+
+#[test]
+fn value_and_slope() {
+    // The example from wikipedia, with not-a-knot boundary the spline
+    // is the parabola p(x) = 0.375x² - 0.125x through the three points
+    let y = array![0.5, 0.0, 3.0];
+    let x = array![-1.0, 0.0, 3.0];
+    let interp = Interp1DBuilder::new(y)
+        .x(x)
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+
+    for q in Array1::linspace(-2.0, 4.0, 25) {
+        let (value, slope) = interp.interp_value_and_slope(q).unwrap();
+        assert_relative_eq!(
+            value.into_scalar(),
+            interp.interp_scalar(q).unwrap(),
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(slope.into_scalar(), 0.75 * q - 0.125, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn value_and_slope_multidim() {
+    let y = array![[0.5, -1.0], [0.0, 0.0], [3.0, -6.0]];
+    let x = array![-1.0, 0.0, 3.0];
+    let interp = Interp1DBuilder::new(y)
+        .x(x)
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    let (value, slope) = interp.interp_value_and_slope(1.0).unwrap();
+    assert_relative_eq!(value, interp.interp(1.0).unwrap(), epsilon = f64::EPSILON);
+    // second column is -2 times the first parabola
+    assert_relative_eq!(slope, array![0.625, -1.25], epsilon = 1e-12);
+    assert!(matches!(
+        interp.interp_value_and_slope(3.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn large_offset_axis() {
+    // e.g. unix timestamps: large absolute values with a small spacing
+    let x0 = 1.7e9;
+    let x = Array1::from_iter((0..6).map(|i| x0 + i as f64 * 0.1));
+    let x_rel = x.mapv(|x| x - x0);
+    let data = array![0.5, 1.0, 0.0, -1.0, 0.25, 0.5];
+    let query = Array1::from_iter((0..40).map(|i| x0 - 0.7 + i as f64 * 0.037));
+    let query_rel = query.mapv(|x| x - x0);
+
+    for periodic in [false, true] {
+        let boundary = || {
+            if periodic {
+                BoundaryCondition::Periodic
+            } else {
+                BoundaryCondition::NotAKnot
+            }
+        };
+        let shifted = Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(CubicSpline::new().boundary(boundary()).extrapolate(true))
+            .build()
+            .unwrap();
+        let reference = Interp1DBuilder::new(data.view())
+            .x(x_rel.view())
+            .strategy(CubicSpline::new().boundary(boundary()).extrapolate(true))
+            .build()
+            .unwrap();
+        let res = shifted.interp_array(&query).unwrap();
+        let expect = reference.interp_array(&query_rel).unwrap();
+        assert_relative_eq!(res, expect, epsilon = 1e-9);
+    }
+
+    // far below the axis the queries have a finer resolution than the knots,
+    // the periodic remap must not round them to the resolution of `x0`
+    let periodic = |x: Array1<f64>| {
+        Interp1DBuilder::new(data.view())
+            .x(x)
+            .strategy(
+                CubicSpline::new()
+                    .boundary(BoundaryCondition::Periodic)
+                    .extrapolate(true),
+            )
+            .build()
+            .unwrap()
+    };
+    let query = Array1::from_iter((0..40).map(|i| 1.0e9 + i as f64 * 0.037));
+    let query_rel = query.mapv(|x| x - x0);
+    let res = periodic(x.clone()).interp_array(&query).unwrap();
+    let expect = periodic(x_rel.clone()).interp_array(&query_rel).unwrap();
+    assert_relative_eq!(res, expect, epsilon = 1e-12);
+}
+
+#[test]
+fn display_summary() {
+    let interp = Interp1DBuilder::new(array![[0.5, 1.0], [0.0, 0.0], [0.5, 1.0]])
+        .x(array![-1.0, 0.0, 3.0])
+        .strategy(
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Periodic)
+                .extrapolate(true),
+        )
+        .build()
+        .unwrap();
+    let summary = interp.to_string();
+    assert!(summary.contains("3 knots"));
+    assert!(summary.contains("x in [-1.0, 3.0]"));
+    assert!(summary.contains("data shape [3, 2]"));
+    assert!(summary.contains("CubicSpline (extrapolate: periodic)"));
+}
+
+#[test]
+fn strategy_name() {
+    let interp = Interp1DBuilder::new(array![0.5, 0.0, 0.5])
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert_eq!(interp.strategy_name(), "CubicSpline");
+    type Builder = CubicSpline<f64, Ix1>;
+    assert_eq!(
+        <Builder as Interp1DStrategyBuilder<OwnedRepr<f64>, OwnedRepr<f64>, Ix1>>::NAME,
+        "CubicSpline"
+    );
+}
+
+#[test]
+fn extrapolation_mode() {
+    let data = array![0.5, 0.0, 0.5];
+    let mode = |strategy: CubicSpline<f64, Ix1>| {
+        Interp1DBuilder::new(data.view())
+            .strategy(strategy)
+            .build()
+            .unwrap()
+            .extrapolation_mode()
+    };
+    assert_eq!(mode(CubicSpline::new()), ExtrapolateMode::No);
+    assert_eq!(
+        mode(CubicSpline::new().extrapolate(true)),
+        ExtrapolateMode::Yes
+    );
+    assert_eq!(
+        mode(CubicSpline::new().boundary(BoundaryCondition::Periodic)),
+        ExtrapolateMode::No
+    );
+    assert_eq!(
+        mode(
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Periodic)
+                .extrapolate(true)
+        ),
+        ExtrapolateMode::Periodic
+    );
+}
+
+#[test]
+fn bending_energy_natural_is_minimal() {
+    let x = array![0.0, 0.5, 1.5, 2.0, 3.5];
+    let data = array![0.0, 1.0, -0.5, 2.0, 0.5];
+    let energy = |boundary| {
+        Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(CubicSpline::new().boundary(boundary))
+            .build()
+            .unwrap()
+            .bending_energy()
+            .unwrap()
+            .into_scalar()
+    };
+    let natural = energy(BoundaryCondition::Natural);
+    let clamped = energy(BoundaryCondition::Clamped);
+    let not_a_knot = energy(BoundaryCondition::NotAKnot);
+    assert!(natural < clamped);
+    assert!(natural < not_a_knot);
+}
+
+#[test]
+fn bending_energy_multidim() {
+    let interp = Interp1DBuilder::new(array![[0.0, 1.0], [1.0, 3.0], [2.0, 2.0], [3.0, 7.0]])
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Natural))
+        .build()
+        .unwrap();
+    let energy = interp.bending_energy().unwrap();
+    assert_eq!(energy.shape(), &[2]);
+    // a straight line does not bend
+    assert_relative_eq!(energy[0], 0.0, epsilon = 1e-12);
+    assert!(energy[1] > 0.0);
+}
+
+#[test]
+fn second_derivatives_natural_ends() {
+    let x: Array1<f64> = array![0.0, 1.0, 2.5, 3.0, 4.5];
+    let data = array![[1.0, 0.0], [2.0, 3.0], [1.5, 1.0], [4.0, 2.0], [3.0, 6.0]];
+    let interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Natural))
+        .build()
+        .unwrap();
+    let second_deriv = interp.second_derivatives_at_knots();
+    assert_eq!(second_deriv.shape(), &[5, 2]);
+    for row in [0, 4] {
+        for col in [0, 1] {
+            assert_relative_eq!(second_deriv[[row, col]], 0.0, epsilon = 1e-12);
+        }
+    }
+    assert!(second_deriv.iter().any(|&v: &f64| v.abs() > 1e-3));
+
+    // compare the inner knots to a finite difference of the slope
+    let h = 1e-6;
+    for (idx, &x) in [1.0, 2.5, 3.0].iter().enumerate() {
+        let (_, right) = interp.interp_value_and_slope(x + h).unwrap();
+        let (_, left) = interp.interp_value_and_slope(x - h).unwrap();
+        let expect = (right - left) / (2.0 * h);
+        for col in [0, 1] {
+            assert_relative_eq!(
+                second_deriv[[idx + 1, col]],
+                expect[col],
+                epsilon = 1e-5,
+                max_relative = 1e-5
+            );
+        }
+    }
+}
+
+#[test]
+fn phase_origin_quarter_period() {
+    // one period of a sine-like signal over [0, 8]
+    let x = Array::linspace(0.0, 8.0, 9);
+    let mut col: Array1<f64> = x.mapv(|x: f64| (x * std::f64::consts::PI / 4.0).sin());
+    col[8] = col[0];
+    let data = stack![Axis(1), col, col.mapv(|v| 2.0 * v)];
+    let mut interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Periodic)
+                .extrapolate(true),
+        )
+        .build()
+        .unwrap();
+    let query = Array::linspace(-3.0, 11.0, 57);
+    let unshifted = interp.interp_array(&query).unwrap();
+
+    interp.set_phase_origin(2.0);
+    assert_eq!(interp.phase_origin(), 2.0);
+    let shifted = interp.interp_array(&query).unwrap();
+    // the query step is a quarter of a period / 8
+    assert_relative_eq!(
+        shifted.slice(s![..-8, ..]),
+        unshifted.slice(s![8.., ..]),
+        epsilon = 1e-12
+    );
+    // at the knots a quarter period rotates the sine into the cosine
+    let knots = Array::linspace(-8.0, 16.0, 25);
+    let cos = knots.mapv(|x: f64| (x * std::f64::consts::PI / 4.0).cos());
+    let res = interp.interp_array(&knots).unwrap();
+    assert_relative_eq!(res.index_axis(Axis(1), 0), cos, epsilon = 1e-12);
+    assert_relative_eq!(res.index_axis(Axis(1), 1), 2.0 * &cos, epsilon = 1e-12);
+
+    interp.set_phase_origin(0.0);
+    assert_eq!(interp.interp_array(&query).unwrap(), unshifted);
+}
+
+#[test]
+fn build_timed() {
+    let x = Array::linspace(0.0, 100.0, 1000);
+    let data = x.mapv(f64::sin);
+    let builder = || {
+        Interp1DBuilder::new(data.clone())
+            .x(x.clone())
+            .strategy(CubicSpline::new())
+    };
+    let (timed, timing) = builder().build_timed().unwrap();
+    assert!(timing.monotonic_check > Duration::ZERO);
+    assert!(timing.strategy_build > Duration::ZERO);
+    assert_eq!(timed, builder().build().unwrap());
+}
+
+#[test]
+fn constant_data_is_exact() {
+    let c = 0.1 + 0.2;
+    let x: Array1<f64> = array![0.0, 0.3, 1.7, 2.0, 3.1, 5.0];
+    let data = stack![Axis(1), Array1::from_elem(6, c), x.mapv(f64::sin)];
+    let query = Array::linspace(-1.0, 6.0, 71);
+    for boundary in [
+        BoundaryCondition::NotAKnot,
+        BoundaryCondition::Natural,
+        BoundaryCondition::Clamped,
+    ] {
+        let interp = Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(CubicSpline::new().boundary(boundary).extrapolate(true))
+            .build()
+            .unwrap();
+        let second_deriv = interp.second_derivatives_at_knots();
+        assert!(second_deriv.column(0).iter().all(|&v| v == 0.0));
+        for &q in query.iter() {
+            let (value, slope) = interp.interp_value_and_slope(q).unwrap();
+            assert_eq!(value[0], c);
+            assert_eq!(slope[0], 0.0);
+        }
+        // the other row is not affected
+        assert!(second_deriv.column(1).iter().any(|&v| v != 0.0));
+    }
+
+    // a non zero boundary derivative bends constant data
+    let boundary = array![[RowBoundary::Mixed {
+        left: SingleBoundary::FirstDeriv(0.5),
+        right: SingleBoundary::NotAKnot
+    }]];
+    let interp = Interp1DBuilder::new(Array1::from_elem(6, c).insert_axis(Axis(1)))
+        .x(x)
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Individual(boundary)))
+        .build()
+        .unwrap();
+    let (_, slope) = interp.interp_value_and_slope(0.0).unwrap();
+    assert_relative_eq!(slope[0], 0.5, epsilon = 1e-12);
+}
+
+#[test]
+fn complex_spiral() {
+    let spiral = |t: f64| Complex::from_polar(t, t);
+    let t: Array1<f64> = Array::linspace(0.0, 4.0 * std::f64::consts::PI, 40);
+    let data = t.mapv(spiral);
+    let interp = Interp1DBuilder::new_with_x(data.view(), t.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    // the spline is linear in the data, so it matches independent splines of both components
+    let component = |part: fn(&Complex<f64>) -> f64| {
+        Interp1DBuilder::new(data.map(part))
+            .x(t.view())
+            .strategy(CubicSpline::new())
+            .build()
+            .unwrap()
+    };
+    let re = component(|z| z.re);
+    let im = component(|z| z.im);
+    let query = Array::linspace(0.0, 4.0 * std::f64::consts::PI, 301);
+    let res = interp.interp_array(&query).unwrap();
+    assert_relative_eq!(
+        res.mapv(|z| z.re),
+        re.interp_array(&query).unwrap(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        res.mapv(|z| z.im),
+        im.interp_array(&query).unwrap(),
+        epsilon = 1e-12
+    );
+    for (z, &q) in res.iter().zip(query.iter()) {
+        assert!((z - spiral(q)).norm() < 1e-2);
+    }
+
+    // the first derivative is continuous at the inner knots
+    let h = 1e-9;
+    for &knot in t.iter().skip(1).take(t.len() - 2) {
+        let (_, left) = interp.interp_value_and_slope(knot - h).unwrap();
+        let (_, right) = interp.interp_value_and_slope(knot + h).unwrap();
+        let (left, right) = (left.into_scalar(), right.into_scalar());
+        assert_relative_eq!(left.re, right.re, epsilon = 1e-6);
+        assert_relative_eq!(left.im, right.im, epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn breaks_solve_independent_runs() {
+    let x: Array1<f64> = array![0.0, 1.0, 2.0, 3.0, 3.5, 5.0, 6.0];
+    let data = array![0.0, 1.0, 0.5, 2.0, 4.0, 3.0, 5.0];
+    let broken = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().breaks(&[3]))
+        .build()
+        .unwrap();
+    let left = Interp1DBuilder::new(data.slice(s![..4]))
+        .x(x.slice(s![..4]))
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let right = Interp1DBuilder::new(data.slice(s![3..]))
+        .x(x.slice(s![3..]))
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    let query_left = Array1::linspace(0.0, 3.0, 13);
+    let query_right = Array1::linspace(3.0, 6.0, 13);
+    assert_relative_eq!(
+        broken.interp_array(&query_left).unwrap(),
+        left.interp_array(&query_left).unwrap(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        broken.interp_array(&query_right).unwrap(),
+        right.interp_array(&query_right).unwrap(),
+        epsilon = 1e-12
+    );
+
+    // exactly at the break the right run is used
+    let (value, slope) = broken.interp_value_and_slope(3.0).unwrap();
+    let (_, slope_right) = right.interp_value_and_slope(3.0).unwrap();
+    let (_, slope_left) = left.interp_value_and_slope(3.0).unwrap();
+    assert_relative_eq!(value.into_scalar(), 2.0, epsilon = 1e-12);
+    assert_relative_eq!(slope, slope_right, epsilon = 1e-12);
+    assert!((slope.into_scalar() - slope_left.into_scalar()).abs() > 1e-3);
+}
+
+#[test]
+fn breaks_errors() {
+    let data = array![0.0, 1.0, 0.5, 2.0, 4.0, 3.0, 5.0];
+    let build =
+        |spline: CubicSpline<f64, Ix1>| Interp1DBuilder::new(data.view()).strategy(spline).build();
+    assert!(matches!(
+        build(CubicSpline::new().breaks(&[0])),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        build(CubicSpline::new().breaks(&[6])),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        build(CubicSpline::new().breaks(&[1])),
+        Err(BuilderError::NotEnoughData(_))
+    ));
+    assert!(matches!(
+        build(CubicSpline::new().breaks(&[2, 3])),
+        Err(BuilderError::NotEnoughData(_))
+    ));
+    assert!(matches!(
+        build(
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Periodic)
+                .breaks(&[3])
+        ),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(build(CubicSpline::new().breaks(&[2, 4])).is_ok());
+}
+
+#[test]
+fn extrapolation_order() {
+    let x = array![0.0, 1.0, 2.0, 3.0, 4.0];
+    let data = array![0.0, 1.0, 0.5, 2.0, 1.0];
+    let build = |order| {
+        Interp1DBuilder::new(data.clone())
+            .x(x.clone())
+            .strategy(
+                CubicSpline::new()
+                    .extrapolate(true)
+                    .extrapolation_order(order),
+            )
+            .build()
+            .unwrap()
+    };
+    let linear = build(1);
+    let quadratic = build(2);
+    let cubic = build(3);
+
+    // all orders agree inside the range and at the boundary
+    for x in [0.0, 0.3, 1.7, 3.9, 4.0] {
+        let y = cubic.interp_scalar(x).unwrap();
+        assert_abs_diff_eq!(linear.interp_scalar(x).unwrap(), y, epsilon = 1e-12);
+        assert_abs_diff_eq!(quadratic.interp_scalar(x).unwrap(), y, epsilon = 1e-12);
+    }
+    // order 3 is the continuation of the boundary segment
+    let no_order = Interp1DBuilder::new(data.clone())
+        .x(x.clone())
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(
+        no_order.interp_scalar(-3.0).unwrap(),
+        cubic.interp_scalar(-3.0).unwrap()
+    );
+
+    // the slope is continuous at the boundary for all orders
+    for interp in [&linear, &quadratic, &cubic] {
+        let (_, inner) = interp.interp_value_and_slope(4.0 - 1e-9).unwrap();
+        let (_, outer) = interp.interp_value_and_slope(4.0 + 1e-9).unwrap();
+        assert_abs_diff_eq!(inner, outer, epsilon = 1e-6);
+    }
+
+    // far field growth: doubling the distance scales the deviation from the
+    // boundary value by 2^order
+    for (order, interp) in [(1, &linear), (2, &quadratic), (3, &cubic)] {
+        for (boundary, dir) in [(0.0, -1.0), (4.0, 1.0)] {
+            let y0 = interp.interp_scalar(boundary).unwrap();
+            let d1 = interp.interp_scalar(boundary + dir * 1e4).unwrap() - y0;
+            let d2 = interp.interp_scalar(boundary + dir * 2e4).unwrap() - y0;
+            assert_abs_diff_eq!(d2 / d1, 2f64.powi(order), epsilon = 1e-2);
+        }
+    }
+
+    assert!(matches!(
+        Interp1DBuilder::new(data.clone())
+            .strategy(CubicSpline::new().extrapolation_order(0))
+            .build(),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        Interp1DBuilder::new(data)
+            .strategy(CubicSpline::new().extrapolation_order(4))
+            .build(),
+        Err(BuilderError::ValueError(_))
+    ));
+}
+
+#[test]
+fn endpoints_in_all_extrapolation_modes() {
+    let x = array![0.1, 0.4, 0.7, 1.0, 1.3];
+    let data = array![
+        [1.0, -2.0],
+        [3.0, 0.5],
+        [2.0, 1.0],
+        [-1.0, 0.0],
+        [1.0, -2.0]
+    ];
+    let build = |extrapolate, boundary| {
+        Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(
+                CubicSpline::new()
+                    .extrapolate(extrapolate)
+                    .boundary(boundary),
+            )
+            .build()
+            .unwrap()
+    };
+    let no = build(false, BoundaryCondition::Natural);
+    let yes = build(true, BoundaryCondition::Natural);
+    let periodic = build(true, BoundaryCondition::Periodic);
+    let (x0, xn) = (x[0], x[4]);
+    let period = xn - x0;
+
+    for interp in [&no, &yes, &periodic] {
+        assert_eq!(interp.interp(x0).unwrap(), data.row(0));
+        assert_eq!(interp.interp(xn).unwrap(), data.row(4));
+        let (_, slope_x0) = interp.interp_value_and_slope(x0).unwrap();
+        let (_, slope_xn) = interp.interp_value_and_slope(xn).unwrap();
+        assert!(slope_x0
+            .iter()
+            .chain(slope_xn.iter())
+            .all(|dy: &f64| dy.is_finite()));
+    }
+
+    assert!(matches!(
+        no.interp(x0 - 1e-12),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        no.interp(xn + 1e-12),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert_relative_eq!(yes.interp(xn + 1e-12).unwrap(), data.row(4), epsilon = 1e-9);
+
+    // remapped queries which land on or next to the first knot
+    let queries = [
+        x0 - period,
+        x0 + period * 3.0,
+        xn + period,
+        xn - period * 2.0,
+        x0 - f64::EPSILON * 1e-3,
+        xn + f64::EPSILON * 1e-3,
+        x0 - 1e-300,
+    ];
+    for q in queries {
+        let res = periodic.interp(q).unwrap();
+        assert_relative_eq!(res, data.row(0), epsilon = 1e-9);
+        let (value, slope) = periodic.interp_value_and_slope(q).unwrap();
+        assert_relative_eq!(value, data.row(0), epsilon = 1e-9);
+        let (_, slope_x0) = periodic.interp_value_and_slope(x0).unwrap();
+        assert_relative_eq!(slope, slope_x0, epsilon = 1e-6);
+    }
+}
+
+/// reference B-spline evaluation with de Boor's algorithm
+fn de_boor(t: &[f64], c: &[f64], k: usize, x: f64) -> f64 {
+    // the last non-empty knot interval left of x, the right end belongs to the last interval
+    let l = (k..c.len())
+        .rev()
+        .find(|&l| t[l] <= x && t[l] < t[l + 1])
+        .unwrap();
+    let mut d: Vec<f64> = c[l - k..=l].to_vec();
+    for r in 1..=k {
+        for j in (r..=k).rev() {
+            let alpha = (x - t[j + l - k]) / (t[j + 1 + l - r] - t[j + l - k]);
+            d[j] = (1.0 - alpha) * d[j - 1] + alpha * d[j];
+        }
+    }
+    d[k]
+}
+
+#[test]
+fn to_tck() {
+    let x = array![0.0, 0.5, 1.7, 2.0, 3.1, 4.0, 4.2, 5.5, 7.0];
+    let y = array![1.0, 2.0, 0.5, -1.0, 0.0, 3.0, 2.5, 1.0, 4.0];
+    let query = Array::linspace(0.0, 7.0, 141);
+    for (strategy, num_knots) in [
+        (CubicSpline::new(), 9 + 6),
+        (
+            CubicSpline::new().boundary(BoundaryCondition::Natural),
+            9 + 6,
+        ),
+        (CubicSpline::new().breaks(&[3, 5]), 9 + 6 + 4),
+    ] {
+        let interp = Interp1DBuilder::new(y.view())
+            .x(x.view())
+            .strategy(strategy)
+            .build()
+            .unwrap();
+        let (t, c, k) = interp.to_tck().unwrap();
+        assert_eq!(k, 3);
+        assert_eq!(t.len(), num_knots);
+        assert_eq!(c.len(), t.len() - k - 1);
+
+        let t = t.to_vec();
+        let c = c.to_vec();
+        let expect = interp.interp_array(&query).unwrap();
+        let result = query.mapv(|x| de_boor(&t, &c, k, x));
+        assert_abs_diff_eq!(result, expect, epsilon = 1e-12);
+    }
+
+    // multidimensional data is exported row by row
+    let data = stack![Axis(1), y, y.mapv(|y| y * y)];
+    let interp = Interp1DBuilder::new(data)
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let (t, c, k) = interp.to_tck().unwrap();
+    let t = t.to_vec();
+    for (row, expect) in c
+        .axis_iter(Axis(1))
+        .zip(interp.interp_array(&query).unwrap().axis_iter(Axis(1)))
+    {
+        let row = row.to_vec();
+        let result = query.mapv(|x| de_boor(&t, &row, k, x));
+        assert_abs_diff_eq!(result, expect, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn is_single_polynomial() {
+    let x = array![0.0, 0.7, 1.5, 3.0, 3.2, 4.5];
+    let y = array![1.0, -2.0, 0.5, 2.0, 1.5, -1.0];
+    let build = |boundary| {
+        Interp1DBuilder::new(stack![Axis(1), y, &y * 10.0])
+            .x(x.view())
+            .strategy(CubicSpline::new().boundary(boundary))
+            .build()
+            .unwrap()
+    };
+
+    let not_a_knot = build(BoundaryCondition::NotAKnot);
+    assert!(not_a_knot.is_single_polynomial(0, 1));
+    assert!(not_a_knot.is_single_polynomial(4, 3));
+    assert!(!not_a_knot.is_single_polynomial(1, 2));
+    assert!(!not_a_knot.is_single_polynomial(2, 3));
+
+    let natural = build(BoundaryCondition::Natural);
+    for seg in 0..4 {
+        assert!(!natural.is_single_polynomial(seg, seg + 1));
+    }
+
+    // a single cubic is one polynomial everywhere, even with a natural boundary
+    let cubic = Interp1DBuilder::new(x.mapv(|x| x * x * x - 2.0 * x))
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    for seg in 0..4 {
+        assert!(cubic.is_single_polynomial(seg, seg + 1));
+    }
+}
+
+#[test]
+#[should_panic(expected = "1 and 3 are not adjacent segments of the spline")]
+fn is_single_polynomial_not_adjacent() {
+    let interp = Interp1DBuilder::new(array![0.0, 2.0, 1.0, 3.0, 0.0])
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    interp.is_single_polynomial(1, 3);
+}
+
+#[test]
+fn one_sided_extrapolation() {
+    let data = array![0.5, 0.0, 1.5, 1.0];
+    let both = Interp1DBuilder::new(data.view())
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let low = Interp1DBuilder::new(data.view())
+        .strategy(CubicSpline::new().extrapolate_low(true))
+        .build()
+        .unwrap();
+    assert_eq!(low.extrapolation_mode(), ExtrapolateMode::Low);
+    assert_eq!(
+        low.interp_scalar(-0.5).unwrap(),
+        both.interp_scalar(-0.5).unwrap()
+    );
+    assert!(matches!(
+        low.interp_scalar(3.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+
+    let periodic = Interp1DBuilder::new(array![0.0, 1.0, 0.0, -1.0, 0.0])
+        .strategy(
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Periodic)
+                .extrapolate_high(true),
+        )
+        .build();
+    assert!(matches!(periodic, Err(BuilderError::ValueError(_))));
+}
+
+#[test]
+fn derivative_smoothing() {
+    let n = 101;
+    let x = Array::linspace(0.0, 2.0 * std::f64::consts::PI, n);
+    // deterministic noise in [-0.05, 0.05] from a linear congruential generator
+    let mut state = 12345u64;
+    let noise = Array::from_shape_fn(n, |_| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) as f64 / (1u64 << 31) as f64 - 0.5) * 0.1
+    });
+    let y = x.mapv(f64::sin) + noise;
+    let interp = Interp1DBuilder::new(y.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().derivative_smoothing(0.1))
+        .build()
+        .unwrap();
+
+    let xm = Zip::from(x.windows(2)).map_collect(|x| (x[0] + x[1]) / 2.0);
+    let finite_differences = Zip::from(x.windows(2))
+        .and(y.windows(2))
+        .map_collect(|x, y| (y[1] - y[0]) / (x[1] - x[0]));
+    let smoothed = xm.mapv(|x| interp.interp_derivative(x).unwrap().into_scalar());
+    let rms = |dy: &Array1<f64>| {
+        (Zip::from(dy)
+            .and(&xm)
+            .fold(0.0, |acc, &dy, &x| acc + (dy - x.cos()).powi(2))
+            / dy.len() as f64)
+            .sqrt()
+    };
+    let (rms_smoothed, rms_finite_differences) = (rms(&smoothed), rms(&finite_differences));
+    assert!(rms_smoothed < 0.1 * rms_finite_differences);
+
+    // without smoothing the derivative is the slope of the natural spline
+    let natural = Interp1DBuilder::new(y.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Natural))
+        .build()
+        .unwrap();
+    let unsmoothed = Interp1DBuilder::new(y.view())
+        .x(x.view())
+        .strategy(
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Natural)
+                .derivative_smoothing(0.0),
+        )
+        .build()
+        .unwrap();
+    for &x in xm.iter() {
+        assert_abs_diff_eq!(
+            unsmoothed.interp_derivative(x).unwrap(),
+            natural.interp_derivative(x).unwrap(),
+            epsilon = 1e-9
+        );
+    }
+
+    let invalid = Interp1DBuilder::new(y.view())
+        .strategy(CubicSpline::new().derivative_smoothing(-1.0))
+        .build();
+    assert!(matches!(invalid, Err(BuilderError::ValueError(_))));
+
+    // lambda is converted to the type of the x axis
+    let single = Interp1DBuilder::new(y.mapv(|y| y as f32))
+        .x(x.mapv(|x| x as f32))
+        .strategy(CubicSpline::new().derivative_smoothing(0.1))
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(
+        single.interp_derivative(1.0).unwrap().into_scalar(),
+        interp.interp_derivative(1.0).unwrap().into_scalar() as f32,
+        epsilon = 1e-3
+    );
+    let invalid = Interp1DBuilder::new(y.mapv(|y| y as f32))
+        .x(x.mapv(|x| x as f32))
+        .strategy(CubicSpline::new().derivative_smoothing(1e300))
+        .build();
+    assert!(matches!(invalid, Err(BuilderError::ValueError(_))));
+
+    // complex data is smoothed component wise
+    let complex = Interp1DBuilder::new_with_x(y.mapv(|y| Complex::new(y, -y)), x.view())
+        .strategy(CubicSpline::new().derivative_smoothing(0.1))
+        .build()
+        .unwrap();
+    let slope = complex.interp_derivative(1.0).unwrap().into_scalar();
+    let expected = interp.interp_derivative(1.0).unwrap().into_scalar();
+    assert_abs_diff_eq!(slope.re, expected, epsilon = 1e-12);
+    assert_abs_diff_eq!(slope.im, -expected, epsilon = 1e-12);
+}
+
+#[test]
+fn cumulative_integral() {
+    // the not-a-knot spline reproduces the cubic y = x³ - 2x² + 1 exactly
+    let x = array![-1.0, 0.0, 0.5, 2.0, 2.5, 4.0];
+    let poly = |x: f64| x * x * x - 2.0 * x * x + 1.0;
+    let antiderivative = |x: f64| x.powi(4) / 4.0 - 2.0 * x.powi(3) / 3.0 + x;
+    let data = stack![Axis(1), x.mapv(poly), x.mapv(poly) * 3.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    let xs = array![3.1, -1.0, 0.25, 2.0, 4.0];
+    let integrals = interp.cumulative_integral(&xs).unwrap();
+    for (&x, integral) in xs.iter().zip(integrals.rows()) {
+        let expect = antiderivative(x) - antiderivative(-1.0);
+        assert_abs_diff_eq!(integral[0], expect, epsilon = 1e-10);
+        assert_abs_diff_eq!(integral[1], 3.0 * expect, epsilon = 1e-10);
+    }
+    // the last value is the integral over the full range
+    let full = antiderivative(4.0) - antiderivative(-1.0);
+    assert_abs_diff_eq!(integrals[[4, 0]], full, epsilon = 1e-10);
+
+    assert!(matches!(
+        interp.cumulative_integral(&array![-1.5]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn non_finite_boundary_derivative() {
+    let y = array![[0.5, 1.0], [0.0, 1.5], [3.0, 0.5]];
+    let build = |deriv: SingleBoundary<f64>| {
+        let boundaries = array![[
+            RowBoundary::Natural,
+            RowBoundary::Mixed {
+                left: SingleBoundary::NotAKnot,
+                right: deriv,
+            }
+        ]];
+        Interp1DBuilder::new(y.view())
+            .strategy(CubicSpline::new().boundary(BoundaryCondition::Individual(boundaries)))
+            .build()
+    };
+
+    for deriv in [
+        SingleBoundary::FirstDeriv(f64::NAN),
+        SingleBoundary::SecondDeriv(f64::INFINITY),
+        SingleBoundary::FirstDeriv(f64::NEG_INFINITY),
+    ] {
+        let Err(BuilderError::ValueError(msg)) = build(deriv) else {
+            panic!("expected a ValueError");
+        };
+        assert!(msg.contains("right boundary derivative"), "{msg}");
+        assert!(msg.contains("(0, 1)"), "{msg}");
+    }
+    assert!(build(SingleBoundary::FirstDeriv(0.5)).is_ok());
+
+    // complex data is checked as well
+    let complex: Array1<Complex<f64>> = array![
+        Complex::new(0.0, 1.0),
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, 2.0)
+    ];
+    let boundary = BoundaryCondition::Individual(array![RowBoundary::Mixed {
+        left: SingleBoundary::FirstDeriv(Complex::new(0.0, f64::NAN)),
+        right: SingleBoundary::Natural,
+    }]);
+    let result = Interp1DBuilder::new_with_x(complex, array![0.0, 1.0, 2.0])
+        .strategy(CubicSpline::new().boundary(boundary))
+        .build();
+    assert!(matches!(result, Err(BuilderError::ValueError(_))));
+}
+
+#[test]
+fn wide_x_range() {
+    // the spline is invariant to an affine map of the x axis, an axis spanning
+    // 1e-9 to 1e9 is as accurate as the same spline on [0, 1]
+    let n = 40;
+    let x = Array::linspace(1e-9, 1e9, n);
+    let unit = Array::linspace(0.0, 1.0, n);
+    let f = |t: f64| (3.0 * t).sin();
+    let y = unit.mapv(f);
+    let wide = Interp1DBuilder::new(y.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let normalized = Interp1DBuilder::new(y.view())
+        .x(unit.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    for i in 0..n - 1 {
+        let q = (x[i] + x[i + 1]) / 2.0;
+        let t = (unit[i] + unit[i + 1]) / 2.0;
+        let value = wide.interp_scalar(q).unwrap();
+        assert_abs_diff_eq!(value, f(t), epsilon = 1e-6);
+        assert_abs_diff_eq!(value, normalized.interp_scalar(t).unwrap(), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn close_period() {
+    let x = array![0.0, 0.5, 1.5, 2.0, 3.0];
+    let y = array![[1.0, 0.0], [2.0, 0.5], [0.5, -1.0], [-1.0, 0.0], [0.0, 2.0]];
+    let closed = Interp1DBuilder::new(y.view())
+        .x(x.view())
+        .close_period(4.0)
+        .unwrap()
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic))
+        .build()
+        .unwrap();
+
+    let manual = Interp1DBuilder::new(concatenate![Axis(0), y, y.slice(s![..1, ..])])
+        .x(array![0.0, 0.5, 1.5, 2.0, 3.0, 4.0])
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic))
+        .build()
+        .unwrap();
+    assert_eq!(closed, manual);
+
+    let too_short = Interp1DBuilder::new(y.view()).x(x.view()).close_period(3.0);
+    assert!(matches!(too_short, Err(BuilderError::ValueError(_))));
+
+    // an x axis set afterwards includes the appended knot
+    let late_x = Interp1DBuilder::new(y.view())
+        .close_period(5.0)
+        .unwrap()
+        .x(array![0.0, 0.5, 1.5, 2.0, 3.0, 4.0])
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic))
+        .build()
+        .unwrap();
+    assert_eq!(late_x, manual);
+    let late_x = Interp1DBuilder::new(y.view())
+        .close_period(5.0)
+        .unwrap()
+        .x(x.view())
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic))
+        .build();
+    assert!(matches!(late_x, Err(BuilderError::ShapeError(_))));
+}
+
+#[test]
+fn basis() {
+    let x = array![0.0, 0.5, 2.0, 3.0, 4.5, 5.0, 6.0];
+    let data = array![1.0, -2.0, 0.5, 3.0, 2.0, 2.5, 1.0];
+    let periodic = array![1.0, -2.0, 0.5, 3.0, 2.0, 2.5, 1.0];
+    let clamped = RowBoundary::Mixed {
+        left: SingleBoundary::FirstDeriv(0.0),
+        right: SingleBoundary::DataSlope,
+    };
+    let splines = [
+        (CubicSpline::new().extrapolate(true), data.view()),
+        (
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Natural)
+                .breaks(&[3])
+                .extrapolate(true)
+                .extrapolation_order(1),
+            data.view(),
+        ),
+        (
+            CubicSpline::new().boundary(BoundaryCondition::Individual(array![clamped])),
+            data.view(),
+        ),
+        (
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Periodic)
+                .extrapolate(true),
+            periodic.view(),
+        ),
+    ];
+    for (spline, data) in splines {
+        let interp = Interp1DBuilder::new(data)
+            .x(x.view())
+            .strategy(spline)
+            .build()
+            .unwrap();
+        for q in [0.0, 0.3, 1.1, 2.0, 3.7, 5.5, 6.0] {
+            let weights = interp.basis(q).unwrap();
+            assert_abs_diff_eq!(weights.sum(), 1.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(
+                weights.dot(&data),
+                interp.interp_scalar(q).unwrap(),
+                epsilon = 1e-12
+            );
+        }
+        if interp.extrapolation_mode() != ExtrapolateMode::No {
+            for q in [-1.0, 7.5] {
+                assert_abs_diff_eq!(
+                    interp.basis(q).unwrap().dot(&data),
+                    interp.interp_scalar(q).unwrap(),
+                    epsilon = 1e-12
+                );
+            }
+        }
+    }
+
+    // a boundary derivative which is not zero adds an offset independent of the data
+    let offset = BoundaryCondition::Individual(array![RowBoundary::Mixed {
+        left: SingleBoundary::FirstDeriv(1.0),
+        right: SingleBoundary::NotAKnot,
+    }]);
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().boundary(offset))
+        .build()
+        .unwrap();
+    assert!(matches!(
+        interp.basis(1.0),
+        Err(InterpolateError::Build(BuilderError::ValueError(_)))
+    ));
+}
+
+#[test]
+fn is_monotone() {
+    // monotonic data with a step, the spline overshoots around the step
+    let x = array![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let step = array![0.0, 0.1, 0.2, 5.0, 5.1, 5.2];
+    let spline = Interp1DBuilder::new(step.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert!(!spline.is_monotone().unwrap());
+    // the overshoot is real
+    let dense = Array::linspace(0.0, 5.0, 501);
+    let values = spline.interp_array(&dense).unwrap();
+    assert!(values.windows(2).into_iter().any(|y| y[1] < y[0]));
+
+    // linear interpolation of the same data can not overshoot
+    let linear = Interp1DBuilder::new(step.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    assert!(linear.is_monotone());
+
+    // smooth monotonic data, rising and falling
+    for data in [x.mapv(|x| x * x * x), x.mapv(|x: f64| (-x).exp())] {
+        let spline = Interp1DBuilder::new(data)
+            .x(x.view())
+            .strategy(CubicSpline::new())
+            .build()
+            .unwrap();
+        assert!(spline.is_monotone().unwrap());
+    }
+    let constant = Interp1DBuilder::new(array![2.0, 2.0, 2.0, 2.0])
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert!(constant.is_monotone().unwrap());
+}
+
+#[test]
+fn interp_interval() {
+    let x = array![0.0, 1.0, 2.5, 3.0, 4.5, 6.0];
+    let data = array![
+        [0.0, 1.0, 0.0],
+        [2.0, 1.0, 1.0],
+        [1.0, 1.0, 2.0],
+        [-1.0, 1.0, 3.0],
+        [0.5, 1.0, 4.0],
+        [0.0, 1.0, 5.0]
+    ];
+    // the brute force min and max on a dense sampling of the interval
+    let sampled = |spline: &Interp1D<_, _, _, _>, x: f64, delta: f64| {
+        let values = spline
+            .interp_array(&Array::linspace(x - delta, x + delta, 20001))
+            .unwrap();
+        let min = values.fold_axis(Axis(0), f64::INFINITY, |&a, &b| a.min(b));
+        let max = values.fold_axis(Axis(0), f64::NEG_INFINITY, |&a, &b| a.max(b));
+        (min, max)
+    };
+
+    for (strategy, periodic) in [
+        (CubicSpline::new().extrapolate(true), false),
+        (
+            CubicSpline::new().extrapolate(true).extrapolation_order(2),
+            false,
+        ),
+        (
+            CubicSpline::new()
+                .extrapolate(true)
+                .boundary(BoundaryCondition::Periodic),
+            true,
+        ),
+    ] {
+        let mut data = data.clone();
+        if periodic {
+            let first = data.row(0).to_owned();
+            data.row_mut(5).assign(&first);
+        }
+        let spline = Interp1DBuilder::new(data)
+            .x(x.view())
+            .strategy(strategy)
+            .build()
+            .unwrap();
+        for (x, delta) in [(0.5, 0.5), (1.7, 0.6), (3.0, 2.0), (-0.5, 1.0), (5.5, 1.5)] {
+            let (min, max) = spline.interp_interval(x, delta).unwrap();
+            let (sampled_min, sampled_max) = sampled(&spline, x, delta);
+            assert_abs_diff_eq!(min, sampled_min, epsilon = 1e-6);
+            assert_abs_diff_eq!(max, sampled_max, epsilon = 1e-6);
+        }
+    }
+
+    // on a curved segment the extremes are not at the ends of the interval
+    let spline = Interp1DBuilder::new(data.column(0))
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let (min, max) = spline.interp_interval(1.7, 0.6).unwrap();
+    let ends = spline.interp_array(&array![1.1, 2.3]).unwrap();
+    assert!(max.into_scalar() > ends[0].max(ends[1]));
+    assert!(min.into_scalar() >= ends[0].min(ends[1]));
+    let (min, max) = spline.interp_interval(3.4, 0.3).unwrap();
+    let ends = spline.interp_array(&array![3.1, 3.7]).unwrap();
+    assert!(min.into_scalar() < ends[0].min(ends[1]));
+    assert!(max.into_scalar() >= ends[0].max(ends[1]));
+
+    // no interval
+    let (min, max) = spline.interp_interval(1.7, 0.0).unwrap();
+    assert_eq!(min, spline.interp(1.7).unwrap());
+    assert_eq!(max, spline.interp(1.7).unwrap());
+
+    assert!(matches!(
+        spline.interp_interval(1.0, -0.5),
+        Err(InterpolateError::Build(BuilderError::ValueError(_)))
+    ));
+    assert!(matches!(
+        spline.interp_interval(5.5, 1.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn symmetry() {
+    let x = Array::linspace(0.0, 3.0, 7);
+    // the same samples mirrored at the first knot
+    let mirrored_x = concatenate![Axis(0), x.slice(s![1..;-1]).mapv(|x| -x), x];
+
+    let cosine = Interp1DBuilder::new(x.mapv(f64::cos))
+        .x(x.view())
+        .strategy(CubicSpline::new().symmetry(Symmetry::Even))
+        .build()
+        .unwrap();
+    let not_a_knot = Interp1DBuilder::new(x.mapv(f64::cos))
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let (value, slope) = cosine.interp_value_and_slope(0.0).unwrap();
+    assert_eq!(value.into_scalar(), 1.0);
+    assert_abs_diff_eq!(slope.into_scalar(), 0.0, epsilon = 1e-12);
+    let (_, slope) = not_a_knot.interp_value_and_slope(0.0).unwrap();
+    assert!(slope.into_scalar().abs() > 1e-3);
+    // the symmetry improves the interpolation near the boundary
+    let query = Array::linspace(0.0, 0.5, 11);
+    let error = |interp: &Interp1D<_, _, _, _>| {
+        (interp.interp_array(&query).unwrap() - query.mapv(f64::cos))
+            .mapv(f64::abs)
+            .fold(0.0, |a: f64, &b| a.max(b))
+    };
+    assert!(error(&cosine) < error(&not_a_knot) / 2.0);
+
+    // the same as the spline through the mirrored data
+    let query = Array::linspace(0.0, 3.0, 31);
+    for (symmetry, f) in [
+        (Symmetry::Even, f64::cos as fn(f64) -> f64),
+        (Symmetry::Odd, |x: f64| 1.0 + x.sin()),
+    ] {
+        let mirrored_y = mirrored_x.mapv(|x| match symmetry {
+            Symmetry::Even => f(x.abs()),
+            Symmetry::Odd => 2.0 * f(0.0) - f(-x),
+        });
+        for boundary in [BoundaryCondition::NotAKnot, BoundaryCondition::Natural] {
+            let symmetric = Interp1DBuilder::new(x.mapv(f))
+                .x(x.view())
+                .strategy(
+                    CubicSpline::new()
+                        .boundary(boundary.clone())
+                        .symmetry(symmetry),
+                )
+                .build()
+                .unwrap();
+            let mirrored = Interp1DBuilder::new(mirrored_y.view())
+                .x(mirrored_x.view())
+                .strategy(CubicSpline::new().boundary(boundary))
+                .build()
+                .unwrap();
+            assert_abs_diff_eq!(
+                symmetric.interp_array(&query).unwrap(),
+                mirrored.interp_array(&query).unwrap(),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    let build = |strategy: CubicSpline<f64, Ix1>| {
+        Interp1DBuilder::new(x.mapv(f64::cos))
+            .x(x.view())
+            .strategy(strategy)
+            .build()
+    };
+    assert!(matches!(
+        build(CubicSpline::new().symmetry(Symmetry::Even).breaks(&[3])),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        build(
+            CubicSpline::new()
+                .symmetry(Symmetry::Even)
+                .boundary(BoundaryCondition::Periodic)
+        ),
+        Err(BuilderError::ValueError(_))
+    ));
+}
+
+#[test]
+fn clamp_to_data() {
+    let x = array![0.0, 1.0, 2.0, 2.5, 4.0, 5.0, 6.0];
+    // steps and spikes make the spline overshoot
+    let data = array![
+        [0.0_f64, 1.0],
+        [0.0, 0.0],
+        [0.0, 5.0],
+        [1.0, 0.0],
+        [1.0, 0.2],
+        [1.0, 0.1],
+        [3.0, 0.0]
+    ];
+    let build = |clamp| {
+        Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(CubicSpline::new().extrapolate(true).clamp_to_data(clamp))
+            .build()
+            .unwrap()
+    };
+    let (spline, clamped) = (build(false), build(true));
+
+    let query = Array::linspace(0.0, 6.0, 601);
+    let values = clamped.interp_array(&query).unwrap();
+    let unclamped = spline.interp_array(&query).unwrap();
+    let mut overshoots = false;
+    for ((&q, row), unclamped) in query.iter().zip(values.rows()).zip(unclamped.rows()) {
+        let idx = clamped.get_index_left_of(q);
+        for col in 0..2 {
+            let (a, b) = (data[[idx, col]], data[[idx + 1, col]]);
+            let (low, high) = (a.min(b), a.max(b));
+            assert!(low <= row[col] && row[col] <= high);
+            if unclamped[col] < low || unclamped[col] > high {
+                overshoots = true;
+            } else {
+                // inside of the bounds the spline is not changed
+                assert_eq!(row[col], unclamped[col]);
+            }
+        }
+    }
+    assert!(overshoots);
+
+    // the knots are still interpolated and extrapolation is not clamped
+    assert_eq!(clamped.interp_array(&x).unwrap(), data);
+    assert_eq!(clamped.interp(6.5).unwrap(), spline.interp(6.5).unwrap());
+    assert!(clamped != spline);
+
+    // the clamped spline has no linear basis
+    let clamped = Interp1DBuilder::new(data.column(1))
+        .x(x.view())
+        .strategy(CubicSpline::new().clamp_to_data(true))
+        .build()
+        .unwrap();
+    assert!(matches!(
+        clamped.basis(1.5),
+        Err(InterpolateError::Build(BuilderError::ValueError(_)))
+    ));
+}
+
+#[test]
+fn clamp_to_data_evaluators() {
+    let step = array![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+    let clamped = Interp1DBuilder::new(step.view())
+        .strategy(CubicSpline::new().clamp_to_data(true))
+        .build()
+        .unwrap();
+
+    // the value and the slope describe the clamped spline
+    for x in Array::linspace(0.0, 5.0, 51) {
+        let (value, slope) = clamped.interp_value_and_slope(x).unwrap();
+        assert_eq!(value.into_scalar(), clamped.interp_scalar(x).unwrap());
+        if x > 1.0 && x < 2.0 {
+            assert_eq!(slope.into_scalar(), 0.0);
+        }
+    }
+    let (value, slope) = clamped.interp_value_and_slope(1.5).unwrap();
+    assert_eq!((value.into_scalar(), slope.into_scalar()), (0.0, 0.0));
+
+    // the interval does not leave the data
+    let (min, max) = clamped.interp_interval(1.5, 0.2).unwrap();
+    assert_eq!(min.into_scalar(), 0.0);
+    assert_eq!(max.into_scalar(), 0.0);
+    let (min, max) = clamped.interp_interval(2.5, 2.0).unwrap();
+    assert_eq!((min.into_scalar(), max.into_scalar()), (0.0, 1.0));
+
+    // the coefficients of the cubic do not describe the clamped spline
+    let is_value_error = |err| matches!(err, Some(BuilderError::ValueError(_)));
+    assert!(is_value_error(clamped.to_tck().err()));
+    assert!(is_value_error(clamped.bending_energy().err()));
+    assert!(is_value_error(clamped.is_monotone().err()));
+    assert!(is_value_error(clamped.to_cumulative().err()));
+    assert!(matches!(
+        clamped.cumulative_integral(&array![1.5]),
+        Err(InterpolateError::Build(BuilderError::ValueError(_)))
+    ));
+}
+
+#[test]
+fn to_cumulative() {
+    let x = array![0.0, 0.5, 1.5, 2.0, 3.0, 4.5, 5.0];
+    let data = array![
+        [0.2, 1.0],
+        [2.0, 1.0],
+        [1.5, 1.0],
+        [1.0, 1.0],
+        [1.0, 1.0],
+        [3.0, 1.0],
+        [0.5, 1.0]
+    ];
+    let interp = Interp1DBuilder::new(data)
+        .x(x.view())
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Natural))
+        .build()
+        .unwrap();
+    let cumulative = interp.to_cumulative().unwrap();
+
+    // the knots hold the exact integrals, the endpoint is the full integral
+    assert_eq!(cumulative.data(), interp.cumulative_integral(&x).unwrap());
+    let total = cumulative.interp(5.0).unwrap();
+    assert_abs_diff_eq!(total[1], 5.0, epsilon = 1e-14);
+    let fine = Array::linspace(0.0, 5.0, 50_001);
+    let values = interp.interp_array(&fine).unwrap();
+    let riemann = (values.sum_axis(Axis(0)) - (&values.row(0) + &values.row(50_000)) / 2.0) * 1e-4;
+    assert_abs_diff_eq!(total, riemann, epsilon = 1e-6);
+
+    // the spline stays positive, so the cumulative is monotonic rising
+    assert!(values.iter().all(|&value| value > 0.0));
+    for series in cumulative.data().columns() {
+        assert!(series.windows(2).into_iter().all(|w| w[0] < w[1]));
+    }
+
+    // masked gaps are not integrated
+    let interp = Interp1DBuilder::new(Array::from_elem(5, 1.0))
+        .mask(array![true, true, false, true, true])
+        .unwrap()
+        .max_gap(1.5)
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let cumulative = interp.to_cumulative().unwrap();
+    assert_abs_diff_eq!(
+        cumulative.data(),
+        array![0.0, 1.0, 1.0, 2.0],
+        epsilon = 1e-14
+    );
+    assert_abs_diff_eq!(
+        interp.cumulative_integral(&array![3.0, 3.5]).unwrap(),
+        array![1.0, 1.5],
+        epsilon = 1e-14
+    );
+}
+
+#[test]
+fn effective_boundaries() {
+    let data = array![
+        [0.0, 1.0, 2.0],
+        [1.0, 0.0, 2.0],
+        [3.0, 2.0, 1.0],
+        [0.0, 1.0, 2.0]
+    ];
+    let natural = RowBoundary::Mixed {
+        left: SingleBoundary::SecondDeriv(0.0),
+        right: SingleBoundary::SecondDeriv(0.0),
+    };
+    let interp = Interp1DBuilder::new(data.view())
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Natural))
+        .build()
+        .unwrap();
+    assert_eq!(
+        interp.effective_boundaries(),
+        Array::from_elem((1, 3), natural.clone())
+    );
+
+    let bounds = array![[
+        RowBoundary::Natural,
+        RowBoundary::NotAKnot,
+        RowBoundary::Mixed {
+            left: SingleBoundary::Clamped,
+            right: SingleBoundary::DataSlope
+        }
+    ]];
+    let interp = Interp1DBuilder::new(data.view())
+        .strategy(
+            CubicSpline::new()
+                .boundary(BoundaryCondition::Individual(bounds))
+                .symmetry(Symmetry::Odd),
+        )
+        .build()
+        .unwrap();
+    let expect = array![[
+        natural,
+        RowBoundary::Mixed {
+            left: SingleBoundary::SecondDeriv(0.0),
+            right: SingleBoundary::NotAKnot
+        },
+        RowBoundary::Mixed {
+            left: SingleBoundary::SecondDeriv(0.0),
+            right: SingleBoundary::DataSlope
+        }
+    ]];
+    assert_eq!(interp.effective_boundaries(), expect);
+
+    // the reported slopes of a periodic spline give the same spline
+    let periodic = Interp1DBuilder::new(data.view())
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic))
+        .build()
+        .unwrap();
+    let clamped = Interp1DBuilder::new(data.view())
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Individual(
+            periodic.effective_boundaries(),
+        )))
+        .build()
+        .unwrap();
+    let xs = Array::linspace(0.0, 3.0, 31);
+    assert_abs_diff_eq!(
+        periodic.interp_array(&xs).unwrap(),
+        clamped.interp_array(&xs).unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn limit_monotone() {
+    let x = array![0.0, 1.0, 1.5, 2.0, 4.0, 5.0, 6.0];
+    let data = array![0.0, 0.1, 0.2, 2.0, 2.05, 2.1, 5.0];
+    let build = |limit| {
+        Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(CubicSpline::new().limit_monotone(limit))
+            .build()
+            .unwrap()
+    };
+    let spline = build(false);
+    let limited = build(true);
+    assert!(!spline.is_monotone().unwrap());
+    assert!(limited.is_monotone().unwrap());
+    assert!(!spline.monotone_limited().into_scalar());
+    assert!(limited.monotone_limited().into_scalar());
+    assert_eq!(limited.interp_array(&x).unwrap(), data);
+    let fine = Array::linspace(0.0, 6.0, 601);
+    let values = limited.interp_array(&fine).unwrap();
+    assert!(values.windows(2).into_iter().all(|w| w[0] <= w[1]));
+    assert!(matches!(
+        limited.basis(1.0),
+        Err(InterpolateError::Build(BuilderError::ValueError(_)))
+    ));
+
+    // only the monotonic column is limited, falling data as well
+    let columns = stack![Axis(1), -&data, array![0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0]];
+    let build = |limit| {
+        Interp1DBuilder::new(columns.view())
+            .x(x.view())
+            .strategy(CubicSpline::new().limit_monotone(limit))
+            .build()
+            .unwrap()
+    };
+    let (spline, limited) = (build(false), build(true));
+    assert_eq!(limited.monotone_limited(), array![true, false]);
+    let values = limited.interp_array(&fine).unwrap();
+    assert!(values
+        .column(0)
+        .windows(2)
+        .into_iter()
+        .all(|w| w[0] >= w[1]));
+    assert_eq!(
+        values.column(1),
+        spline.interp_array(&fine).unwrap().column(1)
+    );
+}
+
+#[test]
+fn zeros() {
+    // two sign changes of a cosine, at pi/2 and 3pi/2
+    let x = Array::linspace(0.0, 5.0, 11);
+    let interp = Interp1DBuilder::new(x.mapv(f64::cos))
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let zeros = interp.zeros();
+    assert_eq!(zeros.len(), 2);
+    assert_abs_diff_eq!(zeros[0], std::f64::consts::FRAC_PI_2, epsilon = 1e-3);
+    assert_abs_diff_eq!(zeros[1], 3.0 * std::f64::consts::FRAC_PI_2, epsilon = 1e-3);
+    for zero in zeros {
+        assert_abs_diff_eq!(interp.interp_scalar(zero).unwrap(), 0.0, epsilon = 1e-15);
+    }
+
+    // the knots with zero data are returned once,
+    // the overshoot between them touches zero only at the knots
+    let data = array![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+    let interp = Interp1DBuilder::new(data)
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert!(interp.interp_scalar(1.5).unwrap() < 0.0);
+    assert_eq!(interp.zeros(), vec![0.0, 1.0, 2.0]);
+
+    // the overshoot below zero is cut off by clamping to the data
+    let data = array![2.0, 2.0, 0.02, 0.02, 2.0, 2.0];
+    let build = |clamp| {
+        Interp1DBuilder::new(data.view())
+            .strategy(CubicSpline::new().clamp_to_data(clamp))
+            .build()
+            .unwrap()
+    };
+    assert!(!build(false).zeros().is_empty());
+    assert_eq!(build(true).zeros(), vec![]);
+}
+
+#[test]
+fn build_in() {
+    let mut scratch = Scratch::new();
+    let x = array![0.0, 0.5, 1.75, 2.0, 3.5, 4.0, 6.0];
+    let data = array![1.0, 2.0, 2.5, -1.0, 3.0, 0.5, 1.0];
+    let data_2d = array![
+        [1.0, 0.0],
+        [2.0, 1.0],
+        [2.5, -2.0],
+        [-1.0, 0.5],
+        [3.0, 0.25]
+    ];
+    let bounds = array![[
+        RowBoundary::Natural,
+        RowBoundary::Mixed {
+            left: SingleBoundary::FirstDeriv(1.0),
+            right: SingleBoundary::NotAKnot,
+        }
+    ]];
+    let boundaries = [
+        BoundaryCondition::NotAKnot,
+        BoundaryCondition::Natural,
+        BoundaryCondition::Clamped,
+        BoundaryCondition::Periodic,
+    ];
+
+    let mut memory_bytes = None;
+    for _ in 0..3 {
+        for boundary in boundaries.iter() {
+            let builder = || {
+                Interp1DBuilder::new(data.clone())
+                    .x(x.clone())
+                    .strategy(CubicSpline::new().boundary(boundary.clone()))
+            };
+            let reused = builder().build_in(&mut scratch).unwrap();
+            assert_eq!(reused, builder().build().unwrap());
+        }
+        // the rows with individual boundaries are solved one by one
+        let builder = || {
+            Interp1DBuilder::new(data_2d.clone())
+                .x(x.slice(s![..5]).to_owned())
+                .strategy(
+                    CubicSpline::new().boundary(BoundaryCondition::Individual(bounds.clone())),
+                )
+        };
+        let reused = builder().build_in(&mut scratch).unwrap();
+        assert_eq!(reused, builder().build().unwrap());
+
+        // the scratch does not grow after the first round
+        let bytes = scratch.memory_bytes();
+        assert_eq!(*memory_bytes.get_or_insert(bytes), bytes);
+    }
+    assert!(scratch.memory_bytes() > 0);
+
+    // a failed build leaves the scratch usable
+    let result = Interp1DBuilder::new(array![0.0, 1.0, 2.0])
+        .strategy(CubicSpline::new().boundary(BoundaryCondition::Periodic))
+        .build_in(&mut scratch);
+    assert!(matches!(result, Err(BuilderError::ValueError(_))));
+    let reused = Interp1DBuilder::new(data.clone())
+        .strategy(CubicSpline::new())
+        .build_in(&mut scratch)
+        .unwrap();
+    assert_eq!(
+        reused,
+        Interp1DBuilder::new(data.clone())
+            .strategy(CubicSpline::new())
+            .build()
+            .unwrap()
+    );
+}
@@ -2,12 +2,23 @@
 // Because the linear strategy is used to test a lot of
 // different behaviour for `Interp1D`
 
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Add, Div, Mul, Sub};
+
 use approx::assert_abs_diff_eq;
 use ndarray::array;
 use ndarray::s;
-use num_traits::NumCast;
+use ndarray::{Array, Array1, ArrayBase, ArrayViewMut0, CowArray, Data, Ix1, OwnedRepr};
+use num_traits::{NumCast, Zero};
 
-use ndarray_interp::interp1d::{Interp1D, Interp1DBuilder, Linear};
+use ndarray_interp::interp;
+use ndarray_interp::interp1d::cubic_spline::CubicSpline;
+use ndarray_interp::interp1d::{
+    Angular, Blend, DynInterp1D, ExtrapolateMode, Interp1D, Interp1DBuilder, Interp1DCow,
+    Interp1DStrategy, Interp1DStrategyBuilder, Linear, LocalSpline, NanPolicy, Nearest,
+    TensionSpline, Warning,
+};
+use ndarray_interp::linalg::Scratch;
 use ndarray_interp::BuilderError;
 use ndarray_interp::InterpolateError;
 
@@ -203,3 +214,2045 @@ fn interp_array_with_differnt_repr() {
     let y_expect = array![[2.0, 3.0, 1.0], [5.0, 5.0, 2.5]];
     assert_eq!(interp.interp_array(&x_query.view()).unwrap(), y_expect);
 }
+
+#[test]
+fn value_and_slope() {
+    let interp = Interp1DBuilder::new(array![1.0, 0.0, 1.5])
+        .x(array![0.0, 1.0, 1.5])
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let (value, slope) = interp.interp_value_and_slope(0.5).unwrap();
+    assert_eq!(value.into_scalar(), 0.5);
+    assert_eq!(slope.into_scalar(), -1.0);
+    let (value, slope) = interp.interp_value_and_slope(2.0).unwrap();
+    assert_eq!(value.into_scalar(), 3.0);
+    assert_eq!(slope.into_scalar(), 3.0);
+    // at a knot the slope of the right segment is used
+    let (_, slope) = interp.interp_value_and_slope(1.0).unwrap();
+    assert_eq!(slope.into_scalar(), 3.0);
+}
+
+#[test]
+fn value_and_slope_out_of_bounds() {
+    let interp = Interp1D::builder(array![1.0, 2.0, 3.0]).build().unwrap();
+    assert!(matches!(
+        interp.interp_value_and_slope(-0.1),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn display_summary() {
+    let interp = Interp1DBuilder::new(array![1.0, 0.0, 1.5, 2.0])
+        .x(array![-1.0, 1.0, 1.5, 4.0])
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let summary = interp.to_string();
+    assert!(summary.contains("4 knots"));
+    assert!(summary.contains("x in [-1.0, 4.0]"));
+    assert!(summary.contains("extrapolate: true"));
+}
+
+#[test]
+fn interp_array_2d_grid() {
+    let data = array![[0.0, 1.0], [1.0, -1.0], [3.0, 2.0], [2.5, 0.0]];
+    let interp = Interp1DBuilder::new(data)
+        .x(array![0.0, 1.0, 2.0, 4.0])
+        .build()
+        .unwrap();
+    let grid = Array::linspace(0.0, 4.0, 12).into_shape((3, 4)).unwrap();
+    let res = interp.interp_array(&grid).unwrap();
+    assert_eq!(res.shape(), &[3, 4, 2]);
+
+    // same as flattening the query and reshaping the result
+    let flat = interp
+        .interp_array(&grid.iter().copied().collect::<Array1<f64>>())
+        .unwrap();
+    assert_eq!(res, flat.into_shape((3, 4, 2)).unwrap());
+}
+
+#[test]
+fn interp_array_descending_and_unsorted() {
+    let data = array![[0.0, 1.0], [1.0, -1.0], [3.0, 2.0], [2.5, 0.0], [1.0, 1.0]];
+    let x = array![0.0, 1.0, 2.0, 4.0, 5.0];
+    let linear = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    let spline = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    let descending = Array::linspace(5.0, 0.0, 23);
+    let unsorted = array![2.5, 0.0, 4.75, 1.0, 5.0, 0.3, 3.1, 2.0];
+    for query in [descending, unsorted] {
+        let res = linear.interp_array(&query).unwrap();
+        for (&x, row) in query.iter().zip(res.outer_iter()) {
+            assert_eq!(row, linear.interp(x).unwrap());
+        }
+        let res = spline.interp_array(&query).unwrap();
+        for (&x, row) in query.iter().zip(res.outer_iter()) {
+            assert_eq!(row, spline.interp(x).unwrap());
+        }
+    }
+}
+
+#[test]
+fn segment_widths() {
+    let x = array![-3.0, -1.0, 0.25, 0.5, 4.0, 10.0];
+    let interp = Interp1DBuilder::new(Array::zeros((6, 2)))
+        .x(x.clone())
+        .build()
+        .unwrap();
+    let widths = interp.segment_widths();
+    assert_eq!(widths.len(), x.len() - 1);
+    assert!(widths.iter().all(|&dx| dx > 0.0));
+    assert_abs_diff_eq!(widths.sum(), x[5] - x[0], epsilon = f64::EPSILON);
+
+    let interp = Interp1DBuilder::new(array![1, 2, 3]).build().unwrap();
+    assert_eq!(interp.segment_widths(), array![1, 1]);
+}
+
+#[test]
+fn interp_with_staleness() {
+    let data = array![[1.0, 2.0], [3.0, 5.0], [4.0, 4.5]];
+    let x = array![0.0, 1.0, 3.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        interp.interp_with_staleness(0.5).unwrap(),
+        (array![2.0, 3.5], false)
+    );
+    assert_eq!(
+        interp.interp_with_staleness(3.0).unwrap(),
+        (array![4.0, 4.5], false)
+    );
+    assert_eq!(
+        interp.interp_with_staleness(3.5).unwrap(),
+        (array![4.0, 4.5], true)
+    );
+    assert_eq!(
+        interp.interp_with_staleness(-1.0).unwrap(),
+        (array![1.0, 2.0], true)
+    );
+    // NaN is not out of range on either side
+    assert!(matches!(
+        interp.interp_with_staleness(f64::NAN),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+
+    // with extrapolation enabled the endpoint is still held
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(
+        interp.interp_with_staleness(10.0).unwrap(),
+        (array![4.0, 4.5], true)
+    );
+}
+
+#[test]
+fn concat() {
+    let x = array![0.0, 0.5, 1.5, 2.0, 3.5, 4.0, 6.0];
+    let data = array![
+        [1.0, 0.0],
+        [2.0, 0.5],
+        [1.5, 1.0],
+        [3.0, 2.0],
+        [2.5, 1.5],
+        [0.0, 0.5],
+        [1.0, 0.0]
+    ];
+    let whole = Interp1DBuilder::new(data.clone())
+        .x(x.clone())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let half = |range: std::ops::Range<usize>| {
+        Interp1DBuilder::new(data.slice(s![range.clone(), ..]))
+            .x(x.slice(s![range]))
+            .strategy(CubicSpline::new())
+            .build()
+            .unwrap()
+    };
+
+    // disjoint halves, and halves sharing the knot at index 3, in either order
+    for (left, right) in [(half(0..3), half(3..7)), (half(0..4), half(3..7))] {
+        for combined in [
+            left.concat(&right, CubicSpline::new()),
+            right.concat(&left, CubicSpline::new()),
+        ] {
+            assert_eq!(combined.unwrap(), whole);
+        }
+    }
+
+    // overlapping ranges
+    assert!(matches!(
+        half(0..4).concat(&half(2..7), Linear::new()),
+        Err(BuilderError::Monotonic(_))
+    ));
+    // shared knot with different data
+    let other = Interp1DBuilder::new(array![[0.0, 0.0], [1.0, 1.0]])
+        .x(array![2.0, 3.0])
+        .build()
+        .unwrap();
+    assert!(matches!(
+        half(0..4).concat(&other, Linear::new()),
+        Err(BuilderError::ValueError(_))
+    ));
+    // data shapes do not match
+    let other = Interp1DBuilder::new(array![[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]])
+        .x(array![7.0, 8.0])
+        .build()
+        .unwrap();
+    assert!(matches!(
+        half(0..4).concat(&other, Linear::new()),
+        Err(BuilderError::ShapeError(_))
+    ));
+
+    // closed_upper and the output transform have to match
+    let right =
+        |builder: Interp1DBuilder<_, _, _, Linear>| builder.x(array![6.0, 7.0]).build().unwrap();
+    let tail = || Interp1DBuilder::new(array![[1.0, 0.0], [2.0, 2.0]]);
+    assert!(matches!(
+        half(0..7).concat(&right(tail().closed_upper(false)), Linear::new()),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        half(0..7).concat(&right(tail().output_transform(2.0, 0.0)), Linear::new()),
+        Err(BuilderError::ValueError(_))
+    ));
+
+    // masked gaps are kept
+    let masked = Interp1DBuilder::new(array![0.0, 9.0, 2.0])
+        .mask(array![true, false, true])
+        .unwrap()
+        .max_gap(1.5)
+        .build()
+        .unwrap();
+    let tail = Interp1DBuilder::new(array![3.0, 4.0])
+        .x(array![3.0, 4.0])
+        .build()
+        .unwrap();
+    let combined = tail.concat(&masked, Linear::new()).unwrap();
+    assert!(combined.interp_scalar(1.0).is_err());
+    assert_eq!(combined.interp_scalar(2.5).unwrap(), 2.5);
+}
+
+#[test]
+fn interp_ragged() {
+    let curves = [
+        (array![0.0, 1.0, 2.5, 4.0], array![1.0, 3.0, 2.0, 0.0]),
+        (
+            array![-1.0, 0.0, 0.5, 1.5, 3.0, 3.5],
+            array![0.0, 0.5, 1.0, 4.0, 2.0, 1.0],
+        ),
+    ];
+    let out_x = Array::linspace(0.0, 3.5, 8);
+    let strategy = CubicSpline::new().extrapolate(true);
+    let resampled =
+        ndarray_interp::interp1d::interp_ragged(&curves, &out_x, strategy.clone()).unwrap();
+    assert_eq!(resampled.shape(), &[2, 8]);
+    for ((x, data), row) in curves.iter().zip(resampled.outer_iter()) {
+        let interp = Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(strategy.clone())
+            .build()
+            .unwrap();
+        assert_eq!(row, interp.interp_array(&out_x).unwrap());
+    }
+
+    // without extrapolation, the first curve does not reach x = 4.5
+    assert!(matches!(
+        ndarray_interp::interp1d::interp_ragged(&curves, &array![4.5], Linear::new()),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    let curves = [(array![0.0, 1.0], array![1.0, 2.0])];
+    assert!(matches!(
+        ndarray_interp::interp1d::interp_ragged(&curves, &out_x, CubicSpline::new()),
+        Err(InterpolateError::Build(BuilderError::NotEnoughData(_)))
+    ));
+}
+
+#[test]
+fn dyn_interp1d() {
+    let data = array![0.0, 1.0, 4.0, 9.0, 16.0];
+    let linear = Interp1DBuilder::new(data.clone()).build().unwrap();
+    let cubic = Interp1DBuilder::new(data)
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let expect = [
+        linear.interp_scalar(2.5).unwrap(),
+        cubic.interp_scalar(2.5).unwrap(),
+    ];
+
+    let interpolators: Vec<Box<dyn DynInterp1D<f64>>> = vec![linear.boxed(), cubic.boxed()];
+    let names: Vec<_> = interpolators.iter().map(|i| i.strategy_name()).collect();
+    assert_eq!(names, ["Linear", "CubicSpline"]);
+    for (interp, expect) in interpolators.iter().zip(expect) {
+        let res = interp.interp_scalar(2.5).unwrap();
+        assert_eq!(res.ndim(), 0);
+        assert_eq!(res.first(), Some(&expect));
+        assert!(matches!(
+            interp.interp_scalar(5.0),
+            Err(InterpolateError::OutOfBounds(_))
+        ));
+    }
+    assert_eq!(expect[1], 6.25);
+
+    // multidimensional data returns the full row
+    let interp: Box<dyn DynInterp1D<f64>> = Interp1DBuilder::new(array![[0.0, 1.0], [2.0, 3.0]])
+        .build()
+        .unwrap()
+        .boxed();
+    assert_eq!(
+        interp.interp_scalar(0.5).unwrap(),
+        array![1.0, 2.0].into_dyn()
+    );
+}
+
+#[test]
+fn with_table() {
+    let x = Array::linspace(0.0, 10.0, 41);
+    let data = x.mapv(f64::sin);
+    let interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    // spacing h = 0.01, the spline of sin has |f''| <= 1.1
+    let table = interp.with_table(1001).unwrap();
+    assert_eq!(table.table().len(), 1001);
+    assert_eq!(table.x_range(), (0.0, 10.0));
+
+    let query = Array::linspace(0.0, 10.0, 3333);
+    for &x in query.iter() {
+        let exact = interp.interp_scalar(x).unwrap();
+        let blended = table.interp_scalar(x).unwrap();
+        assert!(
+            (blended - exact).abs() <= 1.1 * 0.01 * 0.01 / 8.0,
+            "x = {x}"
+        );
+        assert_eq!(table.interp(x).unwrap().into_scalar(), blended);
+        let nearest = table.nearest(x).unwrap().into_scalar();
+        assert!((nearest - exact).abs() <= 1.1 * 0.01 / 2.0, "x = {x}");
+    }
+    // the table entries are exact
+    assert_eq!(
+        table.interp_scalar(10.0).unwrap(),
+        interp.interp_scalar(10.0).unwrap()
+    );
+    assert_eq!(
+        table.nearest(5.0).unwrap().into_scalar(),
+        &interp.interp_scalar(5.0).unwrap()
+    );
+
+    assert!(matches!(
+        table.interp_scalar(-0.1),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        table.nearest(10.1),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        interp.with_table(1),
+        Err(InterpolateError::Build(BuilderError::ValueError(_)))
+    ));
+
+    // multidimensional data
+    let data = array![[0.0, 1.0], [2.0, 0.0], [4.0, -1.0]];
+    let interp = Interp1DBuilder::new(data).build().unwrap();
+    let table = interp.with_table(5).unwrap();
+    assert_eq!(table.interp(0.25).unwrap(), array![0.5, 0.75]);
+    assert_eq!(table.nearest(1.3).unwrap(), array![3.0, -0.5]);
+}
+
+/// least squares fit of a constant, it does not pass through the data
+#[derive(Debug)]
+struct MeanFit(f64);
+
+impl<Sd, Sx> Interp1DStrategyBuilder<Sd, Sx, Ix1> for MeanFit
+where
+    Sd: Data<Elem = f64>,
+    Sx: Data<Elem = f64>,
+{
+    const MINIMUM_DATA_LENGHT: usize = 1;
+    type FinishedStrat = MeanFit;
+
+    fn build<Sx2>(
+        self,
+        _x: &ArrayBase<Sx2, Ix1>,
+        data: &ArrayBase<Sd, Ix1>,
+    ) -> Result<Self::FinishedStrat, BuilderError>
+    where
+        Sx2: Data<Elem = f64>,
+    {
+        Ok(MeanFit(data.mean().unwrap_or_default()))
+    }
+}
+
+impl<Sd, Sx> Interp1DStrategy<Sd, Sx, Ix1> for MeanFit
+where
+    Sd: Data<Elem = f64>,
+    Sx: Data<Elem = f64>,
+{
+    fn interp_into(
+        &self,
+        _interpolator: &Interp1D<Sd, Sx, Ix1, Self>,
+        mut target: ArrayViewMut0<f64>,
+        _x: f64,
+    ) -> Result<(), InterpolateError> {
+        target.fill(self.0);
+        Ok(())
+    }
+}
+
+#[test]
+fn residuals() {
+    let data = array![[1.0, 0.0], [3.0, -1.0], [2.0, 4.0], [0.0, 2.0], [1.5, 1.0]];
+    let x = array![0.0, 0.5, 2.0, 2.5, 4.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    assert_eq!(interp.residuals().unwrap(), Array::zeros((5, 2)));
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(
+        interp.residuals().unwrap(),
+        Array::zeros((5, 2)),
+        epsilon = 1e-14
+    );
+    // the last knot is not in an open range, its residual is zero
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .closed_upper(false)
+        .build()
+        .unwrap();
+    assert!(interp.interp(4.0).is_err());
+    assert_abs_diff_eq!(
+        interp.residuals().unwrap(),
+        Array::zeros((5, 2)),
+        epsilon = 1e-14
+    );
+
+    let data = array![1.0, 3.0, 2.0, 0.0, 4.0];
+    let interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(MeanFit(0.0))
+        .build()
+        .unwrap();
+    assert_eq!(
+        interp.residuals().unwrap(),
+        array![1.0, -1.0, 0.0, 2.0, -2.0]
+    );
+    // `MeanFit` does not set a name
+    assert_eq!(interp.strategy_name(), "custom");
+}
+
+#[test]
+fn interp_macro() {
+    let x = array![0.0, 1.0, 2.0, 4.0];
+    let y = array![1.0, 3.0, 2.0, 0.0];
+
+    let linear = Interp1DBuilder::new(y.view()).x(x.view()).build().unwrap();
+    let value = interp!(x.view() => y.view(), at 1.25).unwrap();
+    assert_eq!(value, linear.interp_scalar(1.25).unwrap());
+    let value = interp!(x.view() => y.view(), linear, at 3.0).unwrap();
+    assert_eq!(value, linear.interp_scalar(3.0).unwrap());
+
+    let cubic = Interp1DBuilder::new(y.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let value = interp!(x.view() => y.view(), cubic, at 2.5).unwrap();
+    assert_eq!(value, cubic.interp_scalar(2.5).unwrap());
+
+    // multiple points
+    let values = interp!(x.view() => y.view(), at [0.0, 0.5, 3.0]).unwrap();
+    assert_eq!(values, vec![1.0, 2.0, 1.0]);
+    let values = interp!(x.view() => y.view(), cubic, at [0.5, 1.5, 3.5,]).unwrap();
+    assert_eq!(
+        values,
+        cubic.interp_array(&array![0.5, 1.5, 3.5]).unwrap().to_vec()
+    );
+
+    // build and query errors
+    assert!(matches!(
+        interp!(x.view() => y.slice(s![..3]), at 0.5),
+        Err(InterpolateError::Build(BuilderError::ShapeError(_)))
+    ));
+    assert!(matches!(
+        interp!(x.view() => y.view(), cubic, at [0.5, 5.0]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn min_segment_width() {
+    let data = array![0.0, 1.0, 2.0, 3.0];
+    let res = Interp1DBuilder::new(data.view())
+        .x(array![0.0, 1.0, 1.0 + 1e-15, 2.0])
+        .build();
+    match res {
+        Err(BuilderError::ValueError(msg)) => assert!(msg.starts_with("segment 1 "), "{msg}"),
+        _ => panic!("nearly coincident knots must be rejected"),
+    }
+
+    let x = array![0.0, 1.0, 1.1, 2.0];
+    let res = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .min_segment_width(0.5)
+        .build();
+    assert!(matches!(res, Err(BuilderError::ValueError(_))));
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .min_segment_width(0.05)
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(1.1).unwrap(), 2.0);
+}
+
+#[test]
+fn interp_array_unchecked() {
+    let data = array![[0.0, 1.0], [2.0, -1.0], [1.0, 3.0], [5.0, 0.0]];
+    let x = array![0.0, 0.5, 2.0, 3.0];
+    let query = Array::linspace(0.0, 3.0, 31);
+    let linear = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    assert_eq!(
+        linear.interp_array_unchecked(&query).unwrap(),
+        linear.interp_array(&query).unwrap()
+    );
+    let cubic = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert_eq!(
+        cubic.interp_array_unchecked(&query).unwrap(),
+        cubic.interp_array(&query).unwrap()
+    );
+
+    // the range is checked once for the whole query, even when extrapolating
+    let extrapolate = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    for query in [
+        array![1.0, 3.5, 2.0],
+        array![1.0, -0.5],
+        array![1.0, f64::NAN, 2.0],
+    ] {
+        assert!(matches!(
+            extrapolate.interp_array_unchecked(&query),
+            Err(InterpolateError::OutOfBounds(_))
+        ));
+    }
+    assert_eq!(
+        linear.interp_array_unchecked(&array![]).unwrap().shape(),
+        &[0, 2]
+    );
+}
+
+#[test]
+fn n_series() {
+    let data = Array::from_shape_fn((5, 3, 2), |(i, j, k)| (i * j + k) as f64);
+    let interp = Interp1DBuilder::new(data).build().unwrap();
+    assert_eq!(interp.n_series(), 6);
+    assert_eq!(interp.interp(1.5).unwrap().len(), interp.n_series());
+
+    let scalar = Interp1DBuilder::new(array![1.0, 2.0]).build().unwrap();
+    assert_eq!(scalar.n_series(), 1);
+}
+
+#[test]
+fn midpoints() {
+    let x = array![-3.0, -1.0, 0.25, 0.5, 4.0, 10.0];
+    let data = array![1.0, -2.0, 0.5, 3.0, 2.5, 0.0];
+    let interp = Interp1DBuilder::new(data)
+        .x(x.clone())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let midpoints = interp.midpoints();
+    assert_eq!(midpoints.len(), x.len() - 1);
+    assert_eq!(midpoints, array![-2.0, -0.375, 0.375, 2.25, 7.0]);
+
+    let values = interp.interp_at_midpoints().unwrap();
+    assert_eq!(values.len(), x.len() - 1);
+    for (&xm, &y) in midpoints.iter().zip(values.iter()) {
+        assert_eq!(y, interp.interp_scalar(xm).unwrap());
+    }
+
+    let interp = Interp1DBuilder::new(array![1, 2, 3])
+        .x(array![0u8, 3, 250])
+        .build()
+        .unwrap();
+    assert_eq!(interp.midpoints(), array![1, 126]);
+}
+
+#[test]
+fn one_sided_extrapolation() {
+    let interp = Interp1D::builder(array![[1.0, 0.0], [2.0, 1.0], [3.0, 4.0]])
+        .strategy(Linear::new().extrapolate_high(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::High);
+    assert!(matches!(
+        interp.interp(-0.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert_eq!(interp.interp(3.0).unwrap(), array![4.0, 7.0]);
+    assert_eq!(interp.interp(0.0).unwrap(), array![1.0, 0.0]);
+
+    let interp = Interp1D::builder(array![1.0, 2.0, 3.0])
+        .strategy(Linear::new().extrapolate(true).extrapolate_high(false))
+        .build()
+        .unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::Low);
+    assert_eq!(interp.interp_scalar(-1.0).unwrap(), 0.0);
+    assert!(interp.interp_scalar(2.5).is_err());
+}
+
+#[test]
+fn cumulative_integral() {
+    let x = array![0.0, 0.5, 2.0, 2.5, 4.0];
+    let data = array![[1.0, 0.0], [3.0, -1.0], [2.0, 0.5], [0.0, 2.0], [1.5, 1.0]];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+
+    // the full-range integral is the trapezoidal rule
+    let mut full = Array1::zeros(2);
+    for i in 0..4 {
+        let trapezoid = (&data.row(i) + &data.row(i + 1)) * (x[i + 1] - x[i]) / 2.0;
+        full += &trapezoid;
+    }
+    let xs = array![3.0, 0.0, 1.25, 4.0];
+    let integrals = interp.cumulative_integral(&xs).unwrap();
+    assert_eq!(integrals.shape(), &[4, 2]);
+    assert_abs_diff_eq!(integrals.row(3), full, epsilon = 1e-12);
+    assert_eq!(integrals.row(1), array![0.0, 0.0]);
+
+    // the derivative of the integral is the interpolant
+    let eps = 1e-6;
+    for x in [0.25, 1.25, 3.0] {
+        let steps = interp
+            .cumulative_integral(&array![x - eps, x + eps])
+            .unwrap();
+        let slope = (&steps.row(1) - &steps.row(0)) / (2.0 * eps);
+        assert_abs_diff_eq!(slope, interp.interp(x).unwrap(), epsilon = 1e-6);
+    }
+
+    assert!(matches!(
+        interp.cumulative_integral(&array![1.0, 4.5]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn hold_last() {
+    let data = array![[0.0, 1.0], [2.0, 3.0], [4.0, 1.0]];
+    let interp = Interp1DBuilder::new(data.view())
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let mut stream = interp.hold_last();
+
+    // nothing to hold before the first query in range
+    assert!(matches!(
+        stream.next(-0.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(stream.last().is_none());
+
+    let queries = [0.5, 3.0, -2.0, 1.5, 2.0, 2.5, 10.0, 0.0];
+    let expect = [
+        array![1.0, 2.0],
+        array![1.0, 2.0],
+        array![1.0, 2.0],
+        array![3.0, 2.0],
+        array![4.0, 1.0],
+        array![4.0, 1.0],
+        array![4.0, 1.0],
+        array![0.0, 1.0],
+    ];
+    for (&x, expect) in queries.iter().zip(expect) {
+        let values = stream.next(x).unwrap();
+        assert_eq!(values, expect);
+        assert_eq!(stream.last().unwrap(), expect);
+        if (0.0..=2.0).contains(&x) {
+            assert_eq!(values, stream.interpolator().interp(x).unwrap());
+        }
+    }
+
+    stream.reset();
+    assert!(stream.next(5.0).is_err());
+}
+
+#[test]
+fn value_to_index() {
+    let data = array![-2.0, 0.5, 1.0, 4.0, 10.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(array![0.0, 0.1, 5.0, 6.0, 100.0])
+        .build()
+        .unwrap();
+    for (k, &y) in data.iter().enumerate() {
+        assert_eq!(interp.value_to_index(y).unwrap(), k as f64);
+    }
+    assert_eq!(interp.value_to_index(2.5).unwrap(), 2.5);
+
+    // falling data
+    let falling = Interp1DBuilder::new(array![3.0, 2.0, 0.0]).build().unwrap();
+    assert_eq!(falling.value_to_index(0.0).unwrap(), 2.0);
+    assert_eq!(falling.value_to_index(1.0).unwrap(), 1.5);
+
+    for y in [-2.5, 10.5, f64::NAN] {
+        assert!(matches!(
+            interp.value_to_index(y),
+            Err(InterpolateError::OutOfBounds(_))
+        ));
+    }
+    let not_monotonic = Interp1DBuilder::new(array![0.0, 1.0, 0.5]).build().unwrap();
+    assert!(matches!(
+        not_monotonic.value_to_index(0.7),
+        Err(InterpolateError::Monotonic(_))
+    ));
+    // the cached monotonic property is reused
+    assert!(matches!(
+        not_monotonic.value_to_index(0.2),
+        Err(InterpolateError::Monotonic(_))
+    ));
+    assert_eq!(interp.value_to_index(2.5).unwrap(), 2.5);
+}
+
+#[test]
+fn memory_bytes() {
+    let data = Array::linspace(0.0, 1.0, 20).mapv(|x: f64| x.sin());
+    let linear = Interp1DBuilder::new(data.view()).build().unwrap();
+    let cubic = Interp1DBuilder::new(data.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    // x is the index axis of usize
+    let stored = 20 * std::mem::size_of::<usize>() + 20 * std::mem::size_of::<f64>();
+    assert_eq!(linear.memory_bytes(), stored);
+    // the cubic spline also stores the coefficients `a` and `b` of each segment
+    assert_eq!(
+        cubic.memory_bytes(),
+        stored + 2 * 19 * std::mem::size_of::<f64>()
+    );
+    assert!(cubic.memory_bytes() > linear.memory_bytes());
+}
+
+#[test]
+fn common_grid() {
+    let x_a = array![0.0, 1.0, 2.5, 4.0];
+    let data_a = array![[0.0, 1.0], [1.0, 3.0], [2.0, 0.0], [5.0, 1.0]];
+    let x_b = array![0.5, 1.0, 3.0, 4.0, 6.0];
+    let data_b = array![2.0, 1.0, 0.0, 4.0, 3.0];
+    let a = Interp1DBuilder::new(data_a.view())
+        .x(x_a.view())
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let b = Interp1DBuilder::new(data_b.view())
+        .x(x_b.view())
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+
+    let (grid_a, grid_b, x) = ndarray_interp::interp1d::common_grid(&a, &b).unwrap();
+    assert_eq!(x, array![0.0, 0.5, 1.0, 2.5, 3.0, 4.0, 6.0]);
+    assert_eq!(grid_a.shape(), &[7, 2]);
+    assert_eq!(grid_b.shape(), &[7]);
+    // at their own knots both reproduce the original data
+    let position = |knot: f64| x.iter().position(|&x| x == knot).unwrap();
+    for (&knot, data) in x_a.iter().zip(data_a.rows()) {
+        assert_abs_diff_eq!(grid_a.row(position(knot)), data, epsilon = 1e-12);
+    }
+    for (&knot, &data) in x_b.iter().zip(data_b.iter()) {
+        assert_abs_diff_eq!(grid_b[position(knot)], data, epsilon = 1e-12);
+    }
+    assert_eq!(grid_a.row(6), a.interp(6.0).unwrap());
+
+    // without extrapolation the union must be inside both ranges
+    let b = Interp1DBuilder::new(data_b.view())
+        .x(x_b.view())
+        .build()
+        .unwrap();
+    assert!(matches!(
+        ndarray_interp::interp1d::common_grid(&a, &b),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn basis() {
+    let x = array![0.0, 0.5, 2.0, 3.0, 4.5];
+    let data = array![1.0, -2.0, 0.5, 3.0, 2.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    for q in [-1.0, 0.0, 0.3, 1.1, 2.0, 3.7, 4.5, 5.0] {
+        let weights = interp.basis(q).unwrap();
+        assert_eq!(weights.len(), 5);
+        assert_abs_diff_eq!(weights.sum(), 1.0, epsilon = 1e-14);
+        assert_abs_diff_eq!(
+            weights.dot(&data),
+            interp.interp_scalar(q).unwrap(),
+            epsilon = 1e-14
+        );
+    }
+    assert_eq!(interp.basis(2.0).unwrap(), array![0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    let no_extrapolation = Interp1DBuilder::new(data.view()).build().unwrap();
+    assert!(matches!(
+        no_extrapolation.basis(5.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn basis_is_data_jacobian() {
+    let x = array![0.0, 0.5, 2.0, 3.0, 4.5];
+    let data = array![1.0, -2.0, 0.5, 3.0, 2.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+
+    // the two non zero entries are the weights of the interval around the query
+    let q = 2.6;
+    let (left, right) = (x[2], x[3]);
+    let mut expected = Array1::zeros(5);
+    expected[2] = (right - q) / (right - left);
+    expected[3] = (q - left) / (right - left);
+    let jacobian = interp.basis(q).unwrap();
+    assert_abs_diff_eq!(jacobian, expected, epsilon = 1e-14);
+
+    // each entry is the change of the interpolated value for a unit change of one data value
+    for j in 0..data.len() {
+        let mut shifted = data.clone();
+        shifted[j] += 1.0;
+        let shifted = Interp1DBuilder::new(shifted).x(x.view()).build().unwrap();
+        assert_abs_diff_eq!(
+            shifted.interp_scalar(q).unwrap() - interp.interp_scalar(q).unwrap(),
+            jacobian[j],
+            epsilon = 1e-14
+        );
+    }
+}
+
+#[test]
+fn build_checked() {
+    let (_, warnings) = Interp1DBuilder::new(array![0.0, 1.0, 4.0, 2.0])
+        .x(array![0.0, 1.0, 2.5, 4.0])
+        .build_checked()
+        .unwrap();
+    assert!(warnings.is_empty());
+
+    // a huge spacing ratio still builds
+    let x = array![0.0, 1.0, 2.0, 1e5];
+    let (interp, warnings) = Interp1DBuilder::new(array![0.0, 1.0, 4.0, 2.0])
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build_checked()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(1e5).unwrap(), 2.0);
+    assert_eq!(
+        warnings,
+        vec![Warning::SpacingRatio {
+            ratio: (1e5 - 2.0) / 1.0,
+            narrowest: 0,
+            widest: 2
+        }]
+    );
+
+    let (_, warnings) = Interp1DBuilder::new(array![0.0, 1.0, 4.0, 2.0])
+        .x(array![1e6, 1e6 + 1e-4, 2e6, 3e6])
+        .build_checked()
+        .unwrap();
+    assert!(matches!(
+        warnings[..],
+        [
+            Warning::NearDuplicateX { index: 0, .. },
+            Warning::SpacingRatio { narrowest: 0, .. }
+        ]
+    ));
+
+    let (_, warnings) = Interp1DBuilder::new(array![0.0, 1.0, 4.0, 2.0])
+        .x(array![0.0, 1e-7, 1.0, 2.0])
+        .build_checked()
+        .unwrap();
+    assert!(matches!(
+        warnings[..],
+        [
+            Warning::NarrowSegment { index: 0, .. },
+            Warning::SpacingRatio { .. }
+        ]
+    ));
+
+    let (_, warnings) = Interp1DBuilder::new(array![1e-9, 0.0, -2e4, 1.0])
+        .build_checked()
+        .unwrap();
+    assert_eq!(
+        warnings,
+        vec![Warning::DataMagnitudes {
+            smallest: 1e-9,
+            largest: 2e4
+        }]
+    );
+
+    // errors are still errors
+    assert!(matches!(
+        Interp1DBuilder::new(array![0.0, 1.0])
+            .x(array![1.0, 0.0])
+            .build_checked(),
+        Err(BuilderError::Monotonic(_))
+    ));
+}
+
+#[test]
+fn interp_array_with_permutation() {
+    let data = array![[0.0, 1.0], [2.0, 0.5], [1.0, 3.0], [4.0, -1.0]];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(array![0.0, 1.0, 2.5, 3.0])
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let xs: Array1<f64> = array![2.7, -0.5, 1.25, 3.0, 0.0, 2.9, 0.3];
+    let mut perm: Vec<usize> = (0..xs.len()).collect();
+    perm.sort_by(|&a, &b| xs[a].total_cmp(&xs[b]));
+    let sorted_xs: Array1<f64> = perm.iter().map(|&idx| xs[idx]).collect();
+
+    let expect = interp.interp_array(&xs).unwrap();
+    // the permutation is reused for every frame
+    for _ in 0..3 {
+        let ys = interp
+            .interp_array_with_permutation(&sorted_xs, &perm)
+            .unwrap();
+        assert_eq!(ys, expect);
+    }
+
+    let interp = Interp1DBuilder::new(data.view()).build().unwrap();
+    assert!(matches!(
+        interp.interp_array_with_permutation(&array![0.5, 3.5], &[1, 0]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    let empty = interp
+        .interp_array_with_permutation(&Array1::<f64>::zeros(0), &[])
+        .unwrap();
+    assert_eq!(empty.shape(), &[0, 2]);
+}
+
+#[test]
+#[should_panic]
+fn interp_array_with_invalid_permutation() {
+    let interp = Interp1DBuilder::new(array![0.0, 1.0, 3.0]).build().unwrap();
+    let _ = interp.interp_array_with_permutation(&array![0.5, 1.0, 1.5], &[0, 2, 2]);
+}
+
+#[test]
+fn binary_round_trip() {
+    let linear = Interp1DBuilder::new(array![0.0, 1.0, 3.0, 2.0])
+        .x(array![-1.0, 0.5, 2.0, 4.0])
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    linear.write_binary(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let read = Interp1D::read_binary(&mut cursor, Linear::new().extrapolate(true)).unwrap();
+    assert_eq!(read, linear);
+    assert_eq!(read.interp_scalar(5.0).unwrap(), 1.5);
+
+    // multidimensional data with masked gaps and an open upper bound
+    let x = Array::linspace(0.0, 7.0, 8);
+    let data = Array::from_shape_fn((8, 2, 3), |(i, j, k)| (i * j) as f64 - k as f64 * 0.5);
+    let cubic = Interp1DBuilder::new(data)
+        .x(x)
+        .closed_upper(false)
+        .mask(array![true, true, true, false, false, true, true, true])
+        .unwrap()
+        .max_gap(1.5)
+        .output_transform(2.0, -1.0)
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let mut bytes = Vec::new();
+    cubic.write_binary(&mut bytes).unwrap();
+    let read = Interp1D::read_binary(bytes.as_slice(), CubicSpline::new()).unwrap();
+    assert_eq!(read, cubic);
+    assert!(matches!(
+        read.interp(3.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(read.interp(7.0).is_err());
+
+    let invalid_data = |err: std::io::Error| err.kind() == std::io::ErrorKind::InvalidData;
+    // another strategy or dimension
+    assert!(
+        Interp1D::<_, _, ndarray::Ix3, _>::read_binary(bytes.as_slice(), Linear::new())
+            .map_err(invalid_data)
+            .unwrap_err()
+    );
+    let mut bytes = Vec::new();
+    linear.write_binary(&mut bytes).unwrap();
+    assert!(
+        Interp1D::<_, _, ndarray::Ix2, _>::read_binary(bytes.as_slice(), Linear::new())
+            .map_err(invalid_data)
+            .unwrap_err()
+    );
+    // truncated or corrupt input
+    let truncated = Interp1D::<_, _, Ix1, _>::read_binary(&bytes[..bytes.len() - 1], Linear::new());
+    assert_eq!(
+        truncated.unwrap_err().kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+    // a corrupt data length reads until the end of the input
+    let mut corrupt = bytes.clone();
+    corrupt[20..28].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert_eq!(
+        Interp1D::<_, _, Ix1, _>::read_binary(corrupt.as_slice(), Linear::new())
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+    // the number of dimensions is checked before anything is allocated for it
+    let mut corrupt = bytes.clone();
+    corrupt[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(
+        Interp1D::<_, _, ndarray::IxDyn, _>::read_binary(corrupt.as_slice(), Linear::new())
+            .map_err(invalid_data)
+            .unwrap_err()
+    );
+    bytes[0] = b'X';
+    assert!(
+        Interp1D::<_, _, Ix1, _>::read_binary(bytes.as_slice(), Linear::new())
+            .map_err(invalid_data)
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn interp_array_add_scaled() {
+    let x = array![0.0, 1.0, 2.0, 4.0];
+    let linear = Interp1DBuilder::new(array![[0.0, 1.0], [2.0, 3.0], [1.0, 0.0], [4.0, 2.0]])
+        .x(x.clone())
+        .build()
+        .unwrap();
+    let cubic = Interp1DBuilder::new(array![[1.0, 0.0], [3.0, -1.0], [2.0, 2.0], [0.0, 5.0]])
+        .x(x)
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    let xs = array![0.0, 0.3, 1.5, 2.0, 3.7, 4.0];
+
+    let mut sum = Array::zeros((xs.len(), 2));
+    linear
+        .interp_array_add_scaled(&xs, 0.5, sum.view_mut())
+        .unwrap();
+    cubic
+        .interp_array_add_scaled(&xs, 0.5, sum.view_mut())
+        .unwrap();
+    let average = (linear.interp_array(&xs).unwrap() + cubic.interp_array(&xs).unwrap()) / 2.0;
+    assert_abs_diff_eq!(sum, average, epsilon = f64::EPSILON * 10.0);
+
+    // out of range queries are reported, the points before are accumulated
+    let mut sum = Array::ones((2, 2));
+    let result = linear.interp_array_add_scaled(&array![1.0, 5.0], 2.0, sum.view_mut());
+    assert!(matches!(result, Err(InterpolateError::OutOfBounds(_))));
+    assert_eq!(sum, array![[5.0, 7.0], [1.0, 1.0]]);
+}
+
+#[test]
+#[should_panic]
+fn interp_array_add_scaled_wrong_shape() {
+    let interp = Interp1DBuilder::new(array![0.0, 1.0, 3.0]).build().unwrap();
+    let mut out = Array1::zeros(2);
+    let _ = interp.interp_array_add_scaled(&array![0.5, 1.0, 1.5], 1.0, out.view_mut());
+}
+
+#[test]
+fn nan_policy() {
+    let x = array![0.0, 1.0, 2.0, 3.0, 4.0];
+    let data = array![
+        [0.0, 1.0, 4.0],
+        [1.0, 2.0, 3.0],
+        [4.0, f64::NAN, 2.0],
+        [2.0, 0.0, 1.0],
+        [1.0, 3.0, 0.0],
+    ];
+    let xs = array![0.5, 2.5, 3.5];
+
+    // propagate: only the series with NaN is affected by it
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .nan_policy(NanPolicy::Propagate)
+        .unwrap()
+        .build()
+        .unwrap();
+    let result = interp.interp_array(&xs).unwrap();
+    assert_eq!(result.column(0), array![0.5, 3.0, 1.5]);
+    assert_eq!(result.column(2), array![3.5, 1.5, 0.5]);
+    assert_eq!(result[[0, 1]], 1.5);
+    assert!(result[[1, 1]].is_nan());
+
+    // error: the position of the first NaN is reported
+    let result = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .nan_policy(NanPolicy::Error);
+    assert!(matches!(result, Err(BuilderError::ValueError(msg)) if msg.contains("(2, 1)")));
+    assert!(Interp1DBuilder::new(data.slice(s![.., ..;2]))
+        .nan_policy(NanPolicy::Error)
+        .is_ok());
+
+    // skip series: the series with NaN is removed, the others are not changed
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .nan_policy(NanPolicy::SkipSeries)
+        .unwrap()
+        .build()
+        .unwrap();
+    let expected = Interp1DBuilder::new(data.slice(s![.., ..;2]))
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert_eq!(interp.n_series(), 2);
+    assert_eq!(
+        interp.interp_array(&xs).unwrap(),
+        expected.interp_array(&xs).unwrap()
+    );
+
+    // the single series of 1d data can not be skipped
+    let result = Interp1DBuilder::new(data.column(1)).nan_policy(NanPolicy::SkipSeries);
+    assert!(matches!(result, Err(BuilderError::ValueError(_))));
+    let interp = Interp1DBuilder::new(data.column(0))
+        .nan_policy(NanPolicy::SkipSeries)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(2.5).unwrap(), 3.0);
+}
+
+#[test]
+fn cow_arrays() {
+    let x = array![0.0, 1.0, 2.0, 4.0];
+    let data = array![[0.0, 1.0], [2.0, 0.0], [1.0, 3.0], [3.0, 1.0]];
+    let expected = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap()
+        .interp_array(&array![0.5, 3.0])
+        .unwrap();
+
+    // borrowed: the interpolator uses the arrays without a copy
+    let borrowed: Interp1DCow<_, _, _> =
+        Interp1DBuilder::new_with_x(CowArray::from(data.view()), CowArray::from(x.view()))
+            .strategy(CubicSpline::new())
+            .build()
+            .unwrap();
+    assert_eq!(borrowed.data().as_ptr(), data.as_ptr());
+    assert_eq!(borrowed.x().as_ptr(), x.as_ptr());
+    assert_eq!(borrowed.interp_array(&array![0.5, 3.0]).unwrap(), expected);
+
+    // owned: the interpolator takes ownership of the arrays
+    let owned: Interp1DCow<_, _, _> =
+        Interp1DBuilder::new_with_x(CowArray::from(data.clone()), CowArray::from(x.clone()))
+            .strategy(CubicSpline::new())
+            .build()
+            .unwrap();
+    assert!(owned.data().as_ptr() != data.as_ptr());
+    assert_eq!(owned.interp_array(&array![0.5, 3.0]).unwrap(), expected);
+}
+
+#[test]
+fn two_point_linear() {
+    assert_eq!(
+        <Linear as Interp1DStrategyBuilder<OwnedRepr<f64>, OwnedRepr<f64>, Ix1>>::MINIMUM_DATA_LENGHT,
+        2
+    );
+    assert!(matches!(
+        Interp1DBuilder::new(array![1.0]).build(),
+        Err(BuilderError::NotEnoughData(_))
+    ));
+
+    // interior: the single segment between the two points
+    let interp = Interp1DBuilder::new(array![1.0, 5.0])
+        .x(array![2.0, 4.0])
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(2.0).unwrap(), 1.0);
+    assert_eq!(interp.interp_scalar(2.5).unwrap(), 2.0);
+    assert_eq!(interp.interp_scalar(4.0).unwrap(), 5.0);
+    assert!(matches!(
+        interp.interp_scalar(1.9),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        interp.interp_scalar(4.1),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+
+    // extrapolated: the line continues on both sides
+    let interp = Interp1DBuilder::new(array![[1.0, -1.0], [5.0, -3.0]])
+        .x(array![2.0, 4.0])
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp(0.0).unwrap(), array![-3.0, 1.0]);
+    assert_eq!(interp.interp(7.0).unwrap(), array![11.0, -6.0]);
+    assert_eq!(
+        interp.interp_array(&array![1.0, 3.0, 5.0]).unwrap(),
+        array![[-1.0, 0.0], [3.0, -2.0], [7.0, -4.0]]
+    );
+
+    // periodic: a single sample closed over one period gives two equal points,
+    // the line is constant and continues the period on both sides
+    let interp = Interp1DBuilder::new(array![3.0])
+        .x(array![1.0])
+        .close_period(2.0)
+        .unwrap()
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.x(), array![1.0, 3.0]);
+    for x in [-4.5, 0.0, 1.0, 2.2, 3.0, 10.0] {
+        assert_eq!(interp.interp_scalar(x).unwrap(), 3.0);
+    }
+}
+
+#[test]
+fn adaptive_samples() {
+    // flat for x < 5, a sine wave for x > 5
+    let x = Array::linspace(0.0, 10.0, 201);
+    let data = x.mapv(|x: f64| {
+        if x < 5.0 {
+            1.0
+        } else {
+            1.0 + (2.0 * std::f64::consts::PI * (x - 5.0)).sin()
+        }
+    });
+    let dense = Array::linspace(0.0, 10.0, 2001);
+    let tol = 1e-3;
+
+    macro_rules! check {
+        ($interp:expr) => {{
+            let interp = $interp;
+            let (sample_x, samples) = interp.adaptive_samples(tol).unwrap();
+            let flat = sample_x.iter().filter(|&&x| x < 4.5).count();
+            let curvy = sample_x.iter().filter(|&&x| x > 5.0).count();
+            assert!(flat <= 3, "{flat} samples in the flat region");
+            assert!(curvy > 20 * flat, "{curvy} samples in the curved region");
+            let coarse = interp.adaptive_samples(tol * 100.0).unwrap().0;
+            assert!(coarse.len() < sample_x.len());
+
+            // the linear interpolation of the samples is within the tolerance
+            let approx = Interp1DBuilder::new(samples)
+                .x(sample_x)
+                .build()
+                .unwrap()
+                .interp_array(&dense)
+                .unwrap();
+            let exact = interp.interp_array(&dense).unwrap();
+            assert_abs_diff_eq!(approx, exact, epsilon = tol);
+        }};
+    }
+    check!(Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap());
+    check!(Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap());
+
+    // the knots next to a masked gap are kept, the gap is not sampled
+    let data = array![
+        [0.0, 0.0],
+        [1.0, 2.0],
+        [9.0, 9.0],
+        [3.0, 6.0],
+        [4.0, 8.0],
+        [5.0, 10.0]
+    ];
+    let interp = Interp1DBuilder::new(data)
+        .mask(array![true, true, false, true, true, true])
+        .unwrap()
+        .max_gap(1.5)
+        .build()
+        .unwrap();
+    let (sample_x, samples) = interp.adaptive_samples(1e-12).unwrap();
+    assert_eq!(sample_x, array![0.0, 1.0, 3.0, 5.0]);
+    assert_eq!(
+        samples,
+        array![[0.0, 0.0], [1.0, 2.0], [3.0, 6.0], [5.0, 10.0]]
+    );
+
+    // the rounding of linear segments is not subdivided, a tolerance must be positive
+    let x = array![0.0, 0.3, 1.1, 2.7, 3.0];
+    let interp = Interp1DBuilder::new(array![1000.1, 2000.3, 1500.7, 1700.9, 300.3])
+        .x(x.view())
+        .build()
+        .unwrap();
+    assert_eq!(interp.adaptive_samples(1e-300).unwrap().0, x);
+    for tol in [0.0, -1.0, f64::NAN] {
+        assert!(matches!(
+            interp.adaptive_samples(tol),
+            Err(InterpolateError::Build(BuilderError::ValueError(_)))
+        ));
+    }
+}
+
+#[test]
+fn to_cumulative() {
+    // a non negative density with two peaks
+    let x = array![0.0, 0.5, 1.5, 2.0, 3.0, 4.5, 5.0];
+    let pdf = array![0.0, 2.0, 0.5, 0.0, 1.0, 3.0, 0.0];
+    let interp = Interp1DBuilder::new(pdf.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    let cdf = interp.to_cumulative();
+
+    // the endpoint is the full integral
+    let total = interp.cumulative_integral(&array![5.0]).unwrap()[0];
+    assert_abs_diff_eq!(total, 6.125, epsilon = 1e-14);
+    assert_eq!(cdf.interp_scalar(5.0).unwrap(), total);
+    assert_eq!(cdf.interp_scalar(0.0).unwrap(), 0.0);
+
+    // monotonic rising and exact at the knots
+    assert!(cdf.data().windows(2).into_iter().all(|w| w[0] <= w[1]));
+    let dense = Array::linspace(0.0, 5.0, 101);
+    let values = cdf.interp_array(&dense).unwrap();
+    assert!(values.windows(2).into_iter().all(|w| w[0] <= w[1]));
+    assert_abs_diff_eq!(
+        cdf.data(),
+        interp.cumulative_integral(&x).unwrap(),
+        epsilon = 1e-14
+    );
+
+    // inverse sampling of the normalized density
+    let normalized = Interp1DBuilder::new(&pdf / total)
+        .x(x.view())
+        .build()
+        .unwrap()
+        .to_cumulative();
+    let index = normalized
+        .value_to_index(normalized.interp_scalar(4.0).unwrap())
+        .unwrap();
+    assert_abs_diff_eq!(index, 5.0 - 1.0 / 3.0, epsilon = 1e-12);
+    assert!(normalized.interp_scalar(5.1).is_err());
+
+    // masked gaps are kept and not integrated
+    let interp = Interp1DBuilder::new(array![[1.0, 2.0], [1.0, 2.0], [9.0, 9.0], [1.0, 0.0]])
+        .mask(array![true, true, false, true])
+        .unwrap()
+        .max_gap(1.5)
+        .build()
+        .unwrap();
+    let cdf = interp.to_cumulative();
+    assert_eq!(cdf.data(), array![[0.0, 0.0], [1.0, 2.0], [1.0, 2.0]]);
+    assert_eq!(
+        interp.cumulative_integral(&array![3.0]).unwrap(),
+        array![[1.0, 2.0]]
+    );
+    assert!(matches!(
+        cdf.interp(2.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn output_transform() {
+    let x = array![0.0, 0.5, 2.0, 3.0];
+    let data = array![[1.0, -2.0], [3.0, 0.5], [2.0, 4.0], [-1.0, 1.0]];
+    let query = array![-1.0, 0.0, 0.25, 1.5, 3.0, 4.5];
+    let (scale, offset) = (0.5, -3.0);
+
+    let raw = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let transformed = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(Linear::new().extrapolate(true))
+        .output_transform(scale, offset)
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(
+        transformed.interp_array(&query).unwrap(),
+        raw.interp_array(&query).unwrap() * scale + offset,
+        epsilon = 1e-14
+    );
+
+    let raw = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let transformed = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().extrapolate(true))
+        .output_transform(scale, offset)
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(
+        transformed.interp_array(&query).unwrap(),
+        raw.interp_array(&query).unwrap() * scale + offset,
+        epsilon = 1e-13
+    );
+    // the data and the analysis methods are not transformed
+    assert_eq!(transformed.data(), data);
+    assert_eq!(
+        transformed.interp_derivative(4.5).unwrap(),
+        raw.interp_derivative(4.5).unwrap()
+    );
+    assert_eq!(transformed.residuals().unwrap(), raw.residuals().unwrap());
+}
+
+#[test]
+fn zeros() {
+    let x = array![0.0, 1.0, 2.0, 4.0, 5.0];
+    let data = array![1.0, -1.0, -2.0, 0.5, 2.0];
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    assert_eq!(interp.zeros(), vec![0.5, 3.6]);
+    for zero in interp.zeros() {
+        assert_abs_diff_eq!(interp.interp_scalar(zero).unwrap(), 0.0, epsilon = 1e-15);
+    }
+
+    // zeros at the knots are returned once, the masked gap has no crossing
+    let data = array![0.0, 2.0, 0.0, -1.0, f64::NAN, 1.0, 0.0];
+    let build = |closed_upper| {
+        Interp1DBuilder::new(data.view())
+            .mask(data.mapv(|y| !y.is_nan()))
+            .unwrap()
+            .max_gap(1.0)
+            .closed_upper(closed_upper)
+            .build()
+            .unwrap()
+    };
+    assert_eq!(build(true).zeros(), vec![0.0, 2.0, 6.0]);
+    assert_eq!(build(false).zeros(), vec![0.0, 2.0]);
+}
+
+#[test]
+fn build_in() {
+    // linear interpolation has no linear solve, the scratch stays empty
+    let mut scratch = Scratch::new();
+    let data = array![[1.0, 2.0], [3.0, 0.0], [2.0, -1.0]];
+    for _ in 0..2 {
+        let reused = Interp1DBuilder::new(data.clone())
+            .build_in(&mut scratch)
+            .unwrap();
+        assert_eq!(reused, Interp1DBuilder::new(data.clone()).build().unwrap());
+    }
+    assert_eq!(scratch.memory_bytes(), 0);
+}
+
+#[test]
+fn closed_upper() {
+    let data = array![1.0, 0.0, 1.5, 2.0];
+    let x = array![-1.0, 1.0, 1.5, 4.0];
+    let closed = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build()
+        .unwrap();
+    let open = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .closed_upper(false)
+        .build()
+        .unwrap();
+
+    // the last knot is the right end of the last segment in both cases
+    assert_eq!(closed.get_index_left_of(4.0), 2);
+    assert_eq!(open.get_index_left_of(4.0), 2);
+    assert!(closed.is_in_range(4.0));
+    assert!(!open.is_in_range(4.0));
+    assert_eq!(closed.interp_scalar(4.0).unwrap(), 2.0);
+    assert!(matches!(
+        open.interp_scalar(4.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+
+    // the lower bound stays closed
+    assert_eq!(open.interp_scalar(-1.0).unwrap(), 1.0);
+    assert_eq!(
+        open.interp_scalar(3.9999).unwrap(),
+        closed.interp_scalar(3.9999).unwrap()
+    );
+
+    // extrapolation still reaches the last knot
+    let open = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(Linear::new().extrapolate(true))
+        .closed_upper(false)
+        .build()
+        .unwrap();
+    assert_eq!(open.interp_scalar(4.0).unwrap(), 2.0);
+}
+
+#[test]
+fn strategy_name() {
+    let interp = Interp1D::builder(array![1.0, 2.0, 3.0]).build().unwrap();
+    assert_eq!(interp.strategy_name(), "Linear");
+}
+
+#[test]
+fn extrapolation_mode() {
+    let interp = Interp1D::builder(array![1.0, 2.0, 3.0]).build().unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::No);
+    let interp = Interp1D::builder(array![1.0, 2.0, 3.0])
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::Yes);
+
+    let data = Array::linspace(0.0, 1.0, 8);
+    let builder = || Interp1DBuilder::new(data.view());
+    let interp = builder()
+        .strategy(Nearest::new().extrapolate_low(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::Low);
+    let interp = builder()
+        .strategy(TensionSpline::new().extrapolate_high(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::High);
+    let interp = builder()
+        .strategy(LocalSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::Yes);
+    let interp = builder().strategy(Angular::new()).build().unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::No);
+    // a blend only extrapolates where both strategies do
+    let blend = Blend::new(
+        Linear::new().extrapolate(true),
+        CubicSpline::new().extrapolate_high(true),
+    );
+    let interp = builder().strategy(blend).build().unwrap();
+    assert_eq!(interp.extrapolation_mode(), ExtrapolateMode::High);
+}
+
+#[test]
+fn masked_interior_point() {
+    let interp = Interp1DBuilder::new(array![0.0, 1.0, 100.0, 3.0, 4.0])
+        .x(array![0.0, 1.0, 2.0, 3.0, 4.0])
+        .mask(array![true, true, false, true, true])
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(2.0).unwrap(), 2.0);
+    assert_eq!(interp.interp_scalar(1.5).unwrap(), 1.5);
+    assert_eq!(
+        interp.interp_array(&array![1.0, 2.5, 3.5]).unwrap(),
+        array![1.0, 2.5, 3.5]
+    );
+}
+
+#[test]
+fn masked_gap_too_wide() {
+    let interp = Interp1DBuilder::new(array![0.0, 1.0, 100.0, 3.0, 4.0])
+        .mask(array![true, true, false, true, true])
+        .unwrap()
+        .max_gap(1.5)
+        .build()
+        .unwrap();
+    assert!(matches!(
+        interp.interp_scalar(2.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        interp.interp_array(&array![0.5, 2.0]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    // the knots bounding the gap are still valid
+    assert_eq!(interp.interp_scalar(1.0).unwrap(), 1.0);
+    assert_eq!(interp.interp_scalar(3.0).unwrap(), 3.0);
+    assert_eq!(interp.interp_scalar(3.5).unwrap(), 3.5);
+}
+
+#[test]
+fn masked_gaps_unordered() {
+    // the second mask adds a gap left of the gaps of the first one, overlapping one of them
+    let interp = Interp1DBuilder::new(Array::linspace(0.0, 9.0, 10))
+        .mask(array![
+            true, true, false, true, true, true, false, true, true, true
+        ])
+        .unwrap()
+        .mask(array![true, true, false, true, true, true, true, true])
+        .unwrap()
+        .max_gap(1.5)
+        .build()
+        .unwrap();
+    for x in [2.0, 3.0, 3.5, 6.0] {
+        assert!(matches!(
+            interp.interp_scalar(x),
+            Err(InterpolateError::OutOfBounds(_))
+        ));
+    }
+    for x in [0.5, 1.0, 4.0, 4.5, 7.0, 8.5] {
+        assert_eq!(interp.interp_scalar(x).unwrap(), x);
+    }
+}
+
+#[test]
+fn mask_before_x() {
+    // the gaps are taken from the x axis of the valid knots which is set afterwards
+    let interp = Interp1DBuilder::new(array![0.0, 1.0, 100.0, 3.0, 4.0])
+        .mask(array![true, true, false, true, true])
+        .unwrap()
+        .x(array![0.0, 1.0, 10.0, 11.0])
+        .max_gap(5.0)
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(0.5).unwrap(), 0.5);
+    assert!(matches!(
+        interp.interp_scalar(5.0),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert_eq!(interp.interp_scalar(10.5).unwrap(), 3.5);
+
+    // masking twice keeps the gaps of the first mask
+    let interp = Interp1DBuilder::new(array![0.0, 1.0, 100.0, 3.0, 4.0, 100.0, 6.0])
+        .mask(array![true, true, false, true, true, true, true])
+        .unwrap()
+        .mask(array![true, true, true, true, false, true])
+        .unwrap()
+        .max_gap(1.5)
+        .build()
+        .unwrap();
+    for x in [2.0, 5.0] {
+        assert!(interp.interp_scalar(x).is_err());
+    }
+    assert_eq!(interp.interp_scalar(3.5).unwrap(), 3.5);
+
+    // an x axis of the knots before masking does not match the data
+    let builder = Interp1DBuilder::new(array![0.0, 1.0, 100.0, 3.0])
+        .mask(array![true, true, false, true])
+        .unwrap()
+        .x(array![0.0, 1.0, 2.0, 3.0]);
+    assert!(matches!(builder.build(), Err(BuilderError::ShapeError(_))));
+}
+
+#[test]
+fn mask_wrong_length() {
+    assert!(matches!(
+        Interp1DBuilder::new(array![0.0, 1.0, 2.0]).mask(array![true, false]),
+        Err(BuilderError::ShapeError(_))
+    ));
+}
+
+#[test]
+fn gaps_none_region() {
+    let x = array![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let data = array![
+        Some(0.0),
+        Some(2.0),
+        Some(4.0),
+        None,
+        None,
+        Some(1.0),
+        Some(0.0)
+    ];
+    let interp = Interp1DBuilder::new_with_gaps(x.clone(), data.clone())
+        .build()
+        .unwrap();
+    assert_eq!(interp.segments().len(), 2);
+    assert_eq!(interp.interp_scalar(1.5).unwrap(), 3.0);
+    assert_eq!(interp.interp_scalar(5.5).unwrap(), 0.5);
+    // the knots bounding the gap are still valid
+    assert_eq!(interp.interp_scalar(2.0).unwrap(), 4.0);
+    assert_eq!(interp.interp_scalar(5.0).unwrap(), 1.0);
+    for x in [2.5, 3.0, 4.5] {
+        assert!(matches!(
+            interp.interp_scalar(x),
+            Err(InterpolateError::OutOfBounds(_))
+        ));
+    }
+    assert!(matches!(
+        interp.interp_array(&array![0.5, 3.5]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        interp.interp_scalar(-0.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+
+    // extrapolation only happens at the outer ends
+    let interp = Interp1DBuilder::new_with_gaps(x, data)
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(
+        interp.interp_array(&array![-1.0, 0.5, 7.0]).unwrap(),
+        array![-2.0, 1.0, -1.0]
+    );
+    assert!(interp.interp_scalar(3.5).is_err());
+}
+
+#[test]
+fn gaps_isolated_knots() {
+    let x = array![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    // the knots 0 and 6 are isolated by the gaps at 1 and 5
+    let data = x.mapv(|x| (x != 1.0 && x != 5.0).then_some(x));
+    let interp = Interp1DBuilder::new_with_gaps(x, data)
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(interp.segments().len(), 1);
+    assert_eq!(interp.isolated_knots(), &[(0.0, 0.0), (6.0, 6.0)]);
+
+    // an isolated knot returns its value exactly at its x
+    assert_eq!(interp.interp_scalar(0.0).unwrap(), 0.0);
+    assert_eq!(interp.interp_scalar(6.0).unwrap(), 6.0);
+    assert_eq!(interp.interp_scalar(2.5).unwrap(), 2.5);
+    // everywhere else it is a gap, the segment is not extrapolated past it
+    for x in [-1.0, 0.5, 1.5, 4.5, 6.5] {
+        assert!(matches!(
+            interp.interp_scalar(x),
+            Err(InterpolateError::OutOfBounds(_))
+        ));
+    }
+
+    // only isolated knots
+    let interp =
+        Interp1DBuilder::new_with_gaps(array![0.0, 1.0, 2.0], array![Some(1.0), None, Some(2.0)])
+            .build()
+            .unwrap();
+    assert!(interp.segments().is_empty());
+    assert_eq!(interp.interp_scalar(2.0).unwrap(), 2.0);
+    assert!(interp.interp_scalar(1.0).is_err());
+}
+
+#[test]
+fn gaps_errors() {
+    let build = |data| Interp1DBuilder::new_with_gaps(array![0.0, 1.0, 2.0], data).build();
+    assert!(matches!(
+        build(array![None, None, None]),
+        Err(BuilderError::NotEnoughData(_))
+    ));
+    // a run of two knots is too short for a cubic spline
+    assert!(matches!(
+        Interp1DBuilder::new_with_gaps(array![0.0, 1.0, 2.0], array![Some(1.0), Some(2.0), None])
+            .strategy(CubicSpline::new())
+            .build(),
+        Err(BuilderError::NotEnoughData(_))
+    ));
+    assert!(matches!(
+        build(array![Some(1.0), Some(2.0)]),
+        Err(BuilderError::ShapeError(_))
+    ));
+}
+
+/// A unit-typed value which only supports the arithmetic needed by [`Linear`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(f64);
+
+impl Add for Meters {
+    type Output = Meters;
+    fn add(self, rhs: Self) -> Self::Output {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Meters {
+    type Output = Meters;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Meters(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f64> for Meters {
+    type Output = Meters;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Meters(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for Meters {
+    type Output = Meters;
+    fn div(self, rhs: f64) -> Self::Output {
+        Meters(self.0 / rhs)
+    }
+}
+
+impl Zero for Meters {
+    fn zero() -> Self {
+        Meters(0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+#[test]
+fn interp_unit_typed_data() {
+    let interp = Interp1DBuilder::new_with_x(
+        array![Meters(1.0), Meters(0.0), Meters(1.5)],
+        array![0.0, 1.0, 1.5],
+    )
+    .strategy(Linear::new().extrapolate(true))
+    .build()
+    .unwrap();
+    assert_eq!(interp.interp_scalar(0.5).unwrap(), Meters(0.5));
+    assert_eq!(interp.interp_scalar(2.0).unwrap(), Meters(3.0));
+    assert_eq!(
+        interp.interp_array(&array![-1.0, 1.25]).unwrap(),
+        array![Meters(2.0), Meters(0.75)]
+    );
+    let (value, slope) = interp.interp_value_and_slope(0.5).unwrap();
+    assert_eq!(value.into_scalar(), Meters(0.5));
+    assert_eq!(slope.into_scalar(), Meters(-1.0));
+}
+
+#[test]
+fn interp_wide_query() {
+    let interp = Interp1DBuilder::new(array![0.0f32, 1.0, 0.0])
+        .x(array![1e6f32, 1e6 + 1.0, 1e6 + 2.0])
+        .build()
+        .unwrap();
+    // not representable as f32, the closest f32 is 1e6 + 0.0625
+    let query = 1e6 + 0.03f64;
+    let precast = interp.interp_scalar(query as f32).unwrap();
+    let wide = interp.interp_wide(query).unwrap().into_scalar();
+    assert_abs_diff_eq!(wide, 0.03, epsilon = 1e-6);
+    assert!((precast - 0.03).abs() > 0.01);
+
+    let res = interp
+        .interp_array_wide(&array![1e6 + 0.03f64, 1e6 + 1.5])
+        .unwrap();
+    assert_abs_diff_eq!(res, array![0.03f32, 0.5], epsilon = 1e-6);
+    assert!(matches!(
+        interp.interp_wide(1e6 - 0.01f64),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn iter_interp() {
+    let data = array![
+        [0.1, 0.2, 0.3],
+        [2.0, 2.0, 3.0],
+        [10.0, 20.0, 30.0],
+        [20.0, 40.0, 60.0],
+    ];
+    let interp = Interp1DBuilder::new(data).build().unwrap();
+    let query = array![0.0, 0.5, 1.25, 3.0, 2.5];
+    let collected: Vec<_> = interp
+        .iter_interp(query.iter().copied())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let expect = interp.interp_array(&query).unwrap();
+    assert_eq!(collected.len(), query.len());
+    for (res, expect) in collected.iter().zip(expect.outer_iter()) {
+        assert_eq!(res, &expect);
+    }
+
+    let mut iter = interp.iter_interp([1.0, 4.0, 2.0]);
+    assert!(iter.next().unwrap().is_ok());
+    assert!(matches!(
+        iter.next().unwrap(),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn from_sorted_map() {
+    let map = BTreeMap::from([(5, 4.0), (-1, 1.0), (2, 1.0), (0, 0.0)]);
+    let interp = Interp1DBuilder::from_sorted_map(map).build().unwrap();
+    assert_eq!(interp.interp_scalar(-0.5).unwrap(), 0.5);
+    assert_eq!(interp.interp_scalar(1.0).unwrap(), 0.5);
+    assert_eq!(interp.interp_scalar(3.5).unwrap(), 2.5);
+    assert!(matches!(
+        interp.interp_scalar(5.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn from_map() {
+    let map = HashMap::from([(5, 4.0), (-1, 1.0), (2, 1.0), (0, 0.0)]);
+    let interp = Interp1DBuilder::from_map(map)
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_eq!(
+        interp.interp_array(&array![-0.5, 1.0, 3.5, 6.0]).unwrap(),
+        array![0.5, 0.5, 2.5, 5.0]
+    );
+}
+
+#[test]
+fn to_uniform() {
+    let data = array![[0.0, 1.0], [1.0, -1.0], [3.0, 2.0], [2.5, 0.0]];
+    let interp = Interp1DBuilder::new(data)
+        .x(array![0.0, 0.3, 1.7, 3.0])
+        .build()
+        .unwrap();
+    let resampled = interp.to_uniform(0.25, 3.0, 12).unwrap();
+    assert_eq!(resampled.shape(), &[12, 2]);
+    assert_eq!(resampled.slice(s![0, ..]), interp.interp(0.25).unwrap());
+    assert_eq!(resampled.slice(s![11, ..]), interp.interp(3.0).unwrap());
+    assert_abs_diff_eq!(
+        resampled.slice(s![4, ..]),
+        interp.interp(1.25).unwrap(),
+        epsilon = 1e-14
+    );
+
+    assert_eq!(interp.to_uniform(0.5, 0.5, 1).unwrap().shape(), &[1, 2]);
+    assert!(matches!(
+        interp.to_uniform(0.0, 3.5, 8),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn build_lazy() {
+    let lazy = Interp1DBuilder::new(array![1.0, 3.0, 2.0, 5.0])
+        .strategy(CubicSpline::new())
+        .build_lazy()
+        .unwrap();
+    assert!(!lazy.is_built());
+
+    // the first access builds the strategy, concurrent accesses share it
+    let interps: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| lazy.get().unwrap()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert!(lazy.is_built());
+    assert!(interps
+        .iter()
+        .all(|&interp| std::ptr::eq(interp, interps[0])));
+
+    // later accesses reuse it
+    assert!(std::ptr::eq(lazy.get().unwrap(), interps[0]));
+    let eager = Interp1DBuilder::new(array![1.0, 3.0, 2.0, 5.0])
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+    assert_eq!(
+        lazy.get().unwrap().interp_scalar(1.5).unwrap(),
+        eager.interp_scalar(1.5).unwrap()
+    );
+
+    // the data is validated immediately, the strategy on first access
+    assert!(matches!(
+        Interp1DBuilder::new(array![1.0, 3.0])
+            .x(array![1.0, 0.0])
+            .build_lazy(),
+        Err(BuilderError::Monotonic(_))
+    ));
+    let lazy = Interp1DBuilder::new(array![1.0, 3.0, 2.0, 5.0])
+        .strategy(CubicSpline::new().breaks(&[1]))
+        .build_lazy()
+        .unwrap();
+    assert!(matches!(lazy.get(), Err(BuilderError::NotEnoughData(_))));
+    assert!(matches!(lazy.get(), Err(BuilderError::NotEnoughData(_))));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn build_par_chunks() {
+    let data = Array::from_shape_fn((7, 5, 3), |(i, j, k)| {
+        ((i * 7 + j * 3 + k) as f64 * 0.37).sin() + j as f64
+    });
+    let x = array![0.0, 0.5, 1.5, 2.0, 3.5, 4.0, 6.0];
+    let query = Array::linspace(-0.5, 6.5, 29);
+    let serial = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap()
+        .interp_array(&query)
+        .unwrap();
+
+    for chunk_len in [1, 2, 5, 8] {
+        let chunked = Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(CubicSpline::new().extrapolate(true))
+            .build_par_chunks(chunk_len)
+            .unwrap();
+        assert_eq!(chunked.chunks().len(), 5usize.div_ceil(chunk_len));
+        assert_eq!(chunked.interp_array(&query).unwrap(), serial);
+    }
+
+    // individual boundary conditions are split into the chunks with the data
+    use ndarray_interp::interp1d::cubic_spline::{BoundaryCondition, RowBoundary, SingleBoundary};
+    let query = Array::linspace(0.0, 6.0, 25);
+    let bounds = Array::from_shape_fn((1, 5, 3), |(_, j, k)| RowBoundary::Mixed {
+        left: SingleBoundary::FirstDeriv(j as f64),
+        right: SingleBoundary::SecondDeriv(k as f64 - 1.0),
+    });
+    let strategy = CubicSpline::new().boundary(BoundaryCondition::Individual(bounds));
+    let serial = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(strategy.clone())
+        .build()
+        .unwrap()
+        .interp_array(&query)
+        .unwrap();
+    for chunk_len in [1, 2, 5] {
+        let chunked = Interp1DBuilder::new(data.view())
+            .x(x.view())
+            .strategy(strategy.clone())
+            .build_par_chunks(chunk_len)
+            .unwrap();
+        assert_eq!(chunked.interp_array(&query).unwrap(), serial);
+    }
+
+    let chunked = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .build_par_chunks(2)
+        .unwrap();
+    assert!(matches!(
+        chunked.interp_array(&array![7.0]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        Interp1DBuilder::new(data.view()).build_par_chunks(0),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        Interp1DBuilder::new(array![1.0, 2.0]).build_par_chunks(1),
+        Err(BuilderError::ShapeError(_))
+    ));
+}
@@ -5,9 +5,9 @@
 use std::iter::repeat;
 
 use approx::assert_abs_diff_eq;
-use ndarray::{array, Array, Array2};
+use ndarray::{array, s, stack, Array, Array2, Axis};
 use ndarray_interp::{
-    interp2d::{Interp2D, Interp2DBuilder},
+    interp2d::{Bilinear, Interp2D, Interp2DBuilder},
     BuilderError, InterpolateError,
 };
 
@@ -272,6 +272,87 @@ fn interp_array_with_unmatched_axis() {
     let _res = interp.interp_array(&qx, &qy);
 }
 
+#[test]
+fn interp_grid() {
+    let data = Array::from_shape_fn((4, 3, 2), |(i, j, k)| (i * i + 3 * j) as f64 - k as f64);
+    let interp = Interp2DBuilder::new(data).build().unwrap();
+    let qx = array![0.0, 0.5, 2.25, 3.0];
+    let qy = array![1.5, 0.0, 2.0];
+
+    let grid = interp.interp_grid(&qx, &qy).unwrap();
+    assert_eq!(grid.shape(), &[4, 3, 2]);
+    for (i, &x) in qx.iter().enumerate() {
+        for (j, &y) in qy.iter().enumerate() {
+            assert_eq!(grid.slice(s![i, j, ..]), interp.interp(x, y).unwrap());
+        }
+    }
+
+    // the buffer is reused for a second query
+    let mut buffer = Array::zeros((4, 3, 2));
+    interp
+        .interp_grid_into(&qx, &qy, buffer.view_mut())
+        .unwrap();
+    assert_eq!(buffer, grid);
+    let qx = qx.mapv(|x| 3.0 - x);
+    interp
+        .interp_grid_into(&qx, &qy, buffer.view_mut())
+        .unwrap();
+    assert_eq!(buffer, interp.interp_grid(&qx, &qy).unwrap());
+
+    assert!(matches!(
+        interp.interp_grid_into(&array![0.0, 4.0], &qy, buffer.slice_mut(s![..2, .., ..])),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+#[should_panic(expected = "wrong buffer shape")]
+fn interp_grid_into_wrong_shape() {
+    let data = Array::linspace(0.0, 8.0, 9).into_shape((3, 3)).unwrap();
+    let interp = Interp2D::builder(data).build().unwrap();
+    let mut buffer = Array::zeros((2, 2));
+    let _res =
+        interp.interp_grid_into(&array![0.0, 1.0], &array![0.0, 1.0, 2.0], buffer.view_mut());
+}
+
+#[test]
+fn interp_along() {
+    // bilinear in each cell, so the interpolation is exact
+    let surface = |x: f64, y: f64| 2.0 * x - 3.0 * y + 0.5 * x * y + 1.0;
+    let x = Array::linspace(0.0, 3.0, 4);
+    let y = Array::linspace(0.0, 6.0, 7);
+    let data = Array::from_shape_fn((4, 7), |(i, j)| surface(x[i], y[j]));
+    let interp = Interp2DBuilder::new(data).x(x).y(y).build().unwrap();
+
+    // the main diagonal
+    let t = Array::linspace(0.0, 1.0, 21);
+    let path = stack![Axis(1), t.mapv(|t| 3.0 * t), t.mapv(|t| 6.0 * t)];
+    let profile = interp.interp_along(&path).unwrap();
+    let expect = t.mapv(|t| surface(3.0 * t, 6.0 * t));
+    assert_abs_diff_eq!(profile, expect, epsilon = 1e-12);
+
+    // a curved path over multidimensional data
+    let data = Array::from_shape_fn((4, 7, 2), |(i, j, k)| {
+        surface(i as f64, j as f64) * (k as f64 + 1.0)
+    });
+    let interp = Interp2D::builder(data).build().unwrap();
+    let path = stack![Axis(1), t.mapv(|t| 3.0 * t * t), t.mapv(|t| 6.0 * t.sqrt())];
+    let profile = interp.interp_along(&path).unwrap();
+    assert_eq!(profile.shape(), &[21, 2]);
+    for (point, row) in path.rows().into_iter().zip(profile.rows()) {
+        assert_abs_diff_eq!(
+            row,
+            interp.interp(point[0], point[1]).unwrap(),
+            epsilon = f64::EPSILON
+        );
+    }
+
+    assert!(matches!(
+        interp.interp_along(&array![[0.0, 0.0], [3.5, 0.0]]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
 #[test]
 fn builder_errors() {
     assert!(matches!(
@@ -323,3 +404,246 @@ fn builder_errors() {
         Err(BuilderError::Monotonic(_))
     ));
 }
+
+#[test]
+fn smooth_normals() {
+    let x = array![0.0, 1.0, 3.0];
+    let y = array![0.0, 2.0, 3.0, 4.0];
+    let data = array![
+        [1.0, 3.0, 2.0, 5.0],
+        [4.0, -1.0, 0.0, 2.0],
+        [2.0, 6.0, 1.0, 3.0],
+    ];
+    let interp = Interp2D::builder(data.clone())
+        .x(x.clone())
+        .y(y.clone())
+        .strategy(Bilinear::new().smooth_normals(true))
+        .build()
+        .unwrap();
+
+    // values at the nodes are preserved
+    for (i, &xi) in x.iter().enumerate() {
+        for (j, &yj) in y.iter().enumerate() {
+            assert_eq!(interp.interp_scalar(xi, yj).unwrap(), data[[i, j]]);
+        }
+    }
+
+    // gradient magnitude is continuous across the cell boundaries x = 1 and y = 2
+    let h = 1e-6;
+    let grad = |x: f64, y: f64| {
+        let dx = (interp.interp_scalar(x + h, y).unwrap()
+            - interp.interp_scalar(x - h, y).unwrap())
+            / (2.0 * h);
+        let dy = (interp.interp_scalar(x, y + h).unwrap()
+            - interp.interp_scalar(x, y - h).unwrap())
+            / (2.0 * h);
+        (dx * dx + dy * dy).sqrt()
+    };
+    let eps = 1e-3;
+    for y in [0.5, 1.3, 2.5, 3.7] {
+        assert_abs_diff_eq!(grad(1.0 - eps, y), grad(1.0 + eps, y), epsilon = 1e-2);
+    }
+    for x in [0.4, 1.5, 2.8] {
+        assert_abs_diff_eq!(grad(x, 2.0 - eps), grad(x, 2.0 + eps), epsilon = 1e-2);
+    }
+
+    // plain bilinear has a jump in the gradient
+    let plain = Interp2D::builder(data).x(x).y(y).build().unwrap();
+    let dx_left = (plain.interp_scalar(1.0, 0.5).unwrap()
+        - plain.interp_scalar(1.0 - eps, 0.5).unwrap())
+        / eps;
+    let dx_right = (plain.interp_scalar(1.0 + eps, 0.5).unwrap()
+        - plain.interp_scalar(1.0, 0.5).unwrap())
+        / eps;
+    assert!((dx_left - dx_right).abs() > 1.0);
+}
+
+#[test]
+fn from_triples() {
+    let x = array![0.0, 0.5, 2.0];
+    let y = array![-1.0, 0.0, 1.0, 3.0];
+    let data = Array2::from_shape_fn((3, 4), |(i, j)| x[i] * 2.0 + y[j] * y[j]);
+    let mut triples: Vec<[f64; 3]> = Vec::new();
+    for (i, &x) in x.iter().enumerate() {
+        for (j, &y) in y.iter().enumerate() {
+            triples.push([x, y, data[[i, j]]]);
+        }
+    }
+    // the order of the rows does not matter
+    triples.reverse();
+    triples.swap(1, 7);
+    let xyz = Array2::from(triples);
+    let interp = Interp2DBuilder::from_triples(&xyz)
+        .unwrap()
+        .build()
+        .unwrap();
+    let expect = Interp2D::builder(data).x(x).y(y).build().unwrap();
+    for (qx, qy) in [(0.0, -1.0), (0.25, 0.5), (1.7, 2.2), (2.0, 3.0)] {
+        assert_eq!(
+            interp.interp_scalar(qx, qy).unwrap(),
+            expect.interp_scalar(qx, qy).unwrap()
+        );
+    }
+
+    let scattered = array![
+        [0.0, 0.0, 1.0],
+        [1.0, 0.2, 2.0],
+        [0.3, 1.0, 0.5],
+        [0.9, 0.8, 1.5],
+    ];
+    assert!(matches!(
+        Interp2DBuilder::from_triples(&scattered),
+        Err(BuilderError::ValueError(_))
+    ));
+    let duplicate = array![
+        [0.0, 0.0, 1.0],
+        [1.0, 0.0, 2.0],
+        [0.0, 1.0, 0.5],
+        [0.0, 1.0, 0.5],
+    ];
+    assert!(matches!(
+        Interp2DBuilder::from_triples(&duplicate),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        Interp2DBuilder::from_triples(&array![[0.0, f64::NAN, 1.0]]),
+        Err(BuilderError::ValueError(_))
+    ));
+    assert!(matches!(
+        Interp2DBuilder::from_triples(&array![[0.0, 1.0], [1.0, 0.0]]),
+        Err(BuilderError::ShapeError(_))
+    ));
+}
+
+#[test]
+fn line_interpolators() {
+    let x = array![0.0, 1.0, 3.0];
+    let y = array![-1.0, 0.0, 2.0, 2.5];
+    let data = array![
+        [1.0, 2.0, 0.0, 5.0],
+        [3.0, -1.0, 4.0, 2.0],
+        [0.5, 1.5, 2.5, 3.5]
+    ];
+    let interp = Interp2DBuilder::new(data.view())
+        .x(x.view())
+        .y(y.view())
+        .build()
+        .unwrap();
+
+    let ys = Array::linspace(-1.0, 2.5, 15);
+    for (x_index, &x) in x.iter().enumerate() {
+        let line = interp.row_interpolator(x_index).unwrap();
+        let values = line.interp_array(&ys).unwrap();
+        for (&y, &value) in ys.iter().zip(&values) {
+            assert_eq!(value, interp.interp_scalar(x, y).unwrap());
+        }
+    }
+    let xs = Array::linspace(0.0, 3.0, 13);
+    for (y_index, &y) in y.iter().enumerate() {
+        let line = interp.col_interpolator(y_index).unwrap();
+        let values = line.interp_array(&xs).unwrap();
+        for (&x, &value) in xs.iter().zip(&values) {
+            assert_abs_diff_eq!(value, interp.interp_scalar(x, y).unwrap(), epsilon = 1e-14);
+        }
+        assert!(line.interp_scalar(3.5).is_err());
+    }
+
+    // the lines extrapolate with the surface
+    let interp = Interp2DBuilder::new(data.view())
+        .x(x.view())
+        .y(y.view())
+        .strategy(Bilinear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let line = interp.col_interpolator(2).unwrap();
+    assert_abs_diff_eq!(
+        line.interp_scalar(3.5).unwrap(),
+        interp.interp_scalar(3.5, 2.0).unwrap(),
+        epsilon = 1e-14
+    );
+
+    // multidimensional data
+    let data = Array::from_shape_fn((3, 4, 2), |(i, j, k)| (i * 4 + j) as f64 * (k as f64 + 1.0));
+    let interp = Interp2DBuilder::new(data)
+        .x(x.view())
+        .y(y.view())
+        .build()
+        .unwrap();
+    let line = interp.col_interpolator(1).unwrap();
+    assert_abs_diff_eq!(
+        line.interp(2.0).unwrap(),
+        interp.interp(2.0, 0.0).unwrap(),
+        epsilon = 1e-14
+    );
+
+    let interp = Interp2DBuilder::new(array![[1.0, 2.0], [3.0, 4.0]])
+        .strategy(Bilinear::new().smooth_normals(true))
+        .build()
+        .unwrap();
+    assert!(matches!(
+        interp.row_interpolator(0),
+        Err(BuilderError::ValueError(_))
+    ));
+}
+
+#[test]
+fn scanline_x() {
+    let x = array![0.0, 1.0, 3.0];
+    let y = array![0.0, 0.5, 2.0, 3.0];
+    let data = Array::from_shape_fn((3, 4, 2), |(i, j, k)| {
+        ((i * 7 + j * 3) % 5) as f64 - k as f64 * (i + j) as f64
+    });
+    let ys = Array::linspace(0.0, 3.0, 13);
+
+    for strategy in [
+        Bilinear::new(),
+        Bilinear::new().smooth_normals(true),
+        Bilinear::new().extrapolate(true),
+    ] {
+        let interp = Interp2DBuilder::new(data.view())
+            .x(x.view())
+            .y(y.view())
+            .strategy(strategy)
+            .build()
+            .unwrap();
+        for qx in [0.0, 0.3, 1.0, 2.2, 3.0] {
+            let scanline = interp.scanline_x(qx).unwrap();
+            let values = scanline.interp_array(&ys).unwrap();
+            assert_eq!(values.shape(), &[13, 2]);
+            for (&qy, value) in ys.iter().zip(values.rows()) {
+                assert_abs_diff_eq!(value, interp.interp(qx, qy).unwrap(), epsilon = 1e-14);
+            }
+        }
+    }
+
+    let interp = Interp2DBuilder::new(data.view())
+        .x(x.view())
+        .y(y.view())
+        .build()
+        .unwrap();
+    assert!(matches!(
+        interp.scanline_x(3.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        interp
+            .scanline_x(1.0)
+            .unwrap()
+            .interp_array(&array![1.0, 3.5]),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+
+    let interp = Interp2DBuilder::new(data.view())
+        .x(x.view())
+        .y(y.view())
+        .strategy(Bilinear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let mut target = Array::zeros(2);
+    interp
+        .scanline_x(-1.0)
+        .unwrap()
+        .interp_into(4.0, target.view_mut())
+        .unwrap();
+    assert_abs_diff_eq!(target, interp.interp(-1.0, 4.0).unwrap(), epsilon = 1e-14);
+}
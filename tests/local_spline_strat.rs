@@ -0,0 +1,129 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array, Array1};
+use ndarray_interp::interp1d::cubic_spline::CubicSpline;
+use ndarray_interp::interp1d::{Interp1DBuilder, LocalSpline};
+use ndarray_interp::{BuilderError, InterpolateError};
+
+fn smooth_signal() -> (Array1<f64>, Array1<f64>) {
+    let x = Array::linspace(0.0, 20.0, 401);
+    let data = x.mapv(|x: f64| x.sin() + 0.5 * (0.3 * x).cos());
+    (x, data)
+}
+
+#[test]
+fn accuracy_matches_global_spline() {
+    let (x, data) = smooth_signal();
+    let local = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(LocalSpline::new().window(24).overlap(6))
+        .build()
+        .unwrap();
+    let global = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(CubicSpline::new())
+        .build()
+        .unwrap();
+
+    let query = Array::linspace(0.0, 20.0, 4001);
+    let expect = query.mapv(|x: f64| x.sin() + 0.5 * (0.3 * x).cos());
+    let local_err = (&local.interp_array(&query).unwrap() - &expect)
+        .mapv(f64::abs)
+        .fold(0.0, |a: f64, &b| a.max(b));
+    let global_err = (&global.interp_array(&query).unwrap() - &expect)
+        .mapv(f64::abs)
+        .fold(0.0, |a: f64, &b| a.max(b));
+    assert!(local_err < 1e-6, "local error {local_err}");
+    assert!(local_err < 2.0 * global_err + 1e-9);
+
+    // the knots are interpolated exactly
+    assert_abs_diff_eq!(local.interp_array(&x).unwrap(), data, epsilon = 1e-12);
+}
+
+#[test]
+fn continuous_at_window_seams() {
+    let (x, data) = smooth_signal();
+    let interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(LocalSpline::new().window(8).overlap(3))
+        .build()
+        .unwrap();
+
+    // knots where a blend starts or ends
+    let h = 1e-7;
+    for knot in [5, 7, 10, 12, 15, 17] {
+        let xk = knot as f64 * 0.05;
+        let left = interp.interp_scalar(xk - h).unwrap();
+        let right = interp.interp_scalar(xk + h).unwrap();
+        assert_abs_diff_eq!(left, right, epsilon = 1e-6);
+        let slope_left = (interp.interp_scalar(xk).unwrap() - left) / h;
+        let slope_right = (right - interp.interp_scalar(xk).unwrap()) / h;
+        assert_abs_diff_eq!(slope_left, slope_right, epsilon = 1e-4);
+    }
+}
+
+#[test]
+fn single_window_is_cubic_spline() {
+    let data = array![[1.0, 0.0], [3.0, 1.0], [2.0, 4.0], [5.0, 2.0], [4.0, 3.0]];
+    let local = Interp1DBuilder::new(data.view())
+        .strategy(LocalSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let global = Interp1DBuilder::new(data.view())
+        .strategy(CubicSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let query = Array::linspace(-1.0, 5.0, 25);
+    assert_abs_diff_eq!(
+        local.interp_array(&query).unwrap(),
+        global.interp_array(&query).unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn extrapolate() {
+    let (x, data) = smooth_signal();
+    let interp = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(LocalSpline::new())
+        .build()
+        .unwrap();
+    assert!(matches!(
+        interp.interp_scalar(-0.1),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        interp.interp_scalar(20.1),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    let interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(LocalSpline::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(
+        interp.interp_scalar(20.01).unwrap(),
+        20.01f64.sin() + 0.5 * (0.3 * 20.01f64).cos(),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn builder_errors() {
+    let (x, data) = smooth_signal();
+    for (window, overlap) in [(8, 1), (8, 5), (3, 2)] {
+        assert!(matches!(
+            Interp1DBuilder::new(data.view())
+                .x(x.view())
+                .strategy(LocalSpline::new().window(window).overlap(overlap))
+                .build(),
+            Err(BuilderError::ValueError(_))
+        ));
+    }
+    assert!(matches!(
+        Interp1DBuilder::new(array![1.0, 2.0])
+            .strategy(LocalSpline::new())
+            .build(),
+        Err(BuilderError::NotEnoughData(_))
+    ));
+}
@@ -0,0 +1,67 @@
+use ndarray::{array, Array};
+use ndarray_interp::interp1d::{Interp1DBuilder, Nearest};
+use ndarray_interp::InterpolateError;
+
+#[test]
+fn sparse_integer_keys() {
+    let keys = array![2, 3, 7, 20];
+    let rows = array![[0.0, 1.0], [1.0, 2.0], [2.0, 4.0], [3.0, 8.0]];
+    let lookup = Interp1DBuilder::new_with_x(rows.view(), keys)
+        .strategy(Nearest::new())
+        .build()
+        .unwrap();
+
+    // present keys return their own row
+    for (key, row) in [2, 3, 7, 20].into_iter().zip(rows.rows()) {
+        assert_eq!(lookup.interp(key).unwrap(), row);
+    }
+    // missing keys snap to the closest key, ties to the left
+    assert_eq!(lookup.interp(4).unwrap(), rows.row(1));
+    assert_eq!(lookup.interp(5).unwrap(), rows.row(1));
+    assert_eq!(lookup.interp(6).unwrap(), rows.row(2));
+    assert_eq!(lookup.interp(13).unwrap(), rows.row(2));
+    assert_eq!(lookup.interp(14).unwrap(), rows.row(3));
+    assert_eq!(
+        lookup.interp_array(&array![4, 14, 19]).unwrap(),
+        array![[1.0, 2.0], [3.0, 8.0], [3.0, 8.0]]
+    );
+    assert!(matches!(
+        lookup.interp(1),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        lookup.interp(21),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}
+
+#[test]
+fn extrapolate_unsigned_keys() {
+    let keys = array![3u32, 5, 9];
+    let data = array![30.0, 50.0, 90.0];
+    let lookup = Interp1DBuilder::new_with_x(data, keys)
+        .strategy(Nearest::new().extrapolate(true))
+        .build()
+        .unwrap();
+    let query = Array::from_iter(0u32..12);
+    assert_eq!(
+        lookup.interp_array(&query).unwrap(),
+        array![30.0, 30.0, 30.0, 30.0, 30.0, 50.0, 50.0, 50.0, 90.0, 90.0, 90.0, 90.0]
+    );
+}
+
+#[test]
+fn float_axis() {
+    let x = array![0.0, 0.5, 2.0];
+    let data = array![1.0, -1.0, 4.0];
+    let interp = Interp1DBuilder::new(data)
+        .x(x)
+        .strategy(Nearest::new())
+        .build()
+        .unwrap();
+    assert_eq!(interp.interp_scalar(0.2).unwrap(), 1.0);
+    assert_eq!(interp.interp_scalar(0.25).unwrap(), 1.0);
+    assert_eq!(interp.interp_scalar(0.3).unwrap(), -1.0);
+    assert_eq!(interp.interp_scalar(1.3).unwrap(), 4.0);
+    assert_eq!(interp.interp_scalar(2.0).unwrap(), 4.0);
+}
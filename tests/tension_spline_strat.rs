@@ -0,0 +1,104 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, stack, Array, Array1, Axis};
+use ndarray_interp::interp1d::{Interp1DBuilder, Linear, TensionSpline};
+use ndarray_interp::{BuilderError, InterpolateError};
+
+/// Catmull-Rom spline on an uniform grid with unit spacing
+fn catmull_rom(p0: f64, p1: f64, p2: f64, p3: f64, t: f64) -> f64 {
+    0.5 * (2.0 * p1
+        + (-p0 + p2) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t.powi(2)
+        + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t.powi(3))
+}
+
+#[test]
+fn tension_1_is_linear() {
+    let data = array![0.0, 1.0, 3.0, 2.0, 4.0];
+    let x = array![-1.0, 0.5, 1.0, 2.5, 4.0];
+    let query = Array::linspace(-2.0, 5.0, 50);
+    let tension = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(TensionSpline::new().tension(1.0).extrapolate(true))
+        .build()
+        .unwrap();
+    let linear = Interp1DBuilder::new(data.view())
+        .x(x.view())
+        .strategy(Linear::new().extrapolate(true))
+        .build()
+        .unwrap();
+    assert_abs_diff_eq!(
+        tension.interp_array(&query).unwrap(),
+        linear.interp_array(&query).unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn tension_0_is_catmull_rom() {
+    let data = array![0.0, 1.0, 3.0, 2.0, 4.0];
+    let interp = Interp1DBuilder::new(data)
+        .strategy(TensionSpline::new())
+        .build()
+        .unwrap();
+    for t in [0.0, 0.1, 0.3, 0.5, 0.9] {
+        assert_abs_diff_eq!(
+            interp.interp_scalar(1.0 + t).unwrap(),
+            catmull_rom(0.0, 1.0, 3.0, 2.0, t),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            interp.interp_scalar(2.0 + t).unwrap(),
+            catmull_rom(1.0, 3.0, 2.0, 4.0, t),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn multidim() {
+    let col: Array1<f64> = array![0.0, 1.0, 3.0, 2.0, 4.0];
+    let data = stack![Axis(1), col, col.mapv(|v| -2.0 * v)];
+    let query = Array::linspace(0.0, 4.0, 17);
+    let interp = Interp1DBuilder::new(data)
+        .strategy(TensionSpline::new().tension(0.3))
+        .build()
+        .unwrap();
+    let single = Interp1DBuilder::new(col)
+        .strategy(TensionSpline::new().tension(0.3))
+        .build()
+        .unwrap();
+    let res = interp.interp_array(&query).unwrap();
+    let expect = single.interp_array(&query).unwrap();
+    assert_abs_diff_eq!(res.index_axis(Axis(1), 0), expect, epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        res.index_axis(Axis(1), 1),
+        expect.mapv(|v| -2.0 * v),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn tension_out_of_range() {
+    for tension in [-0.1, 1.1] {
+        let res = Interp1DBuilder::new(array![0.0, 1.0, 3.0])
+            .strategy(TensionSpline::new().tension(tension))
+            .build();
+        assert!(matches!(res, Err(BuilderError::ValueError(_))));
+    }
+}
+
+#[test]
+fn extrapolate_false() {
+    let interp = Interp1DBuilder::new(array![0.0, 1.0, 3.0])
+        .strategy(TensionSpline::new())
+        .build()
+        .unwrap();
+    assert!(matches!(
+        interp.interp_scalar(-0.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+    assert!(matches!(
+        interp.interp_scalar(2.5),
+        Err(InterpolateError::OutOfBounds(_))
+    ));
+}